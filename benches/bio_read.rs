@@ -0,0 +1,76 @@
+//! Benchmarks [`CoreUpcaller::BIO_read_ex_pooled`], reusing a per-thread
+//! buffer, against [`CoreUpcaller::BIO_read_ex`], allocating a new one on
+//! each call, over a mocked `core_dispatch` table reading from memory.
+//!
+//! Run with `cargo bench --bench bio_read`.
+
+use std::ffi::{c_int, c_void};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use openssl_provider_forge::bindings::{
+    generic_non_null_fn_ptr, OSSL_CORE_BIO, OSSL_DISPATCH, OSSL_FUNC_BIO_READ_EX,
+};
+use openssl_provider_forge::upcalls::traits::CoreUpcaller;
+use openssl_provider_forge::upcalls::CoreDispatch;
+
+/// An in-memory BIO, read by [`mock_bio_read_ex`].
+struct MemBio {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+unsafe extern "C" fn mock_bio_read_ex(
+    bio: *mut OSSL_CORE_BIO,
+    data: *mut c_void,
+    data_len: usize,
+    bytes_read: *mut usize,
+) -> c_int {
+    let bio = unsafe { &mut *bio.cast::<MemBio>() };
+    let available = &bio.data[bio.pos..];
+    let n = available.len().min(data_len);
+    unsafe {
+        std::ptr::copy_nonoverlapping(available.as_ptr(), data.cast(), n);
+        *bytes_read = n;
+    }
+    bio.pos += n;
+    // like BIO_read_ex(), fail when there is nothing to read
+    c_int::from(n > 0)
+}
+
+fn bench_bio_read(c: &mut Criterion) {
+    let table = [
+        OSSL_DISPATCH::new(OSSL_FUNC_BIO_READ_EX as c_int, unsafe {
+            Some(generic_non_null_fn_ptr!(mock_bio_read_ex))
+        }),
+        OSSL_DISPATCH::END,
+    ];
+    let core = CoreDispatch::try_from(table.as_ptr()).unwrap();
+
+    let mut group = c.benchmark_group("BIO_read_ex");
+    for size in [1024, 64 * 1024, 1024 * 1024] {
+        let mut bio = MemBio {
+            data: vec![42u8; size],
+            pos: 0,
+        };
+        let ptr: *mut OSSL_CORE_BIO = std::ptr::from_mut(&mut bio).cast();
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("per_call", size), &ptr, |b, &ptr| {
+            b.iter(|| {
+                unsafe { (*ptr.cast::<MemBio>()).pos = 0 };
+                core.BIO_read_ex(black_box(ptr)).unwrap()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("pooled", size), &ptr, |b, &ptr| {
+            b.iter(|| {
+                unsafe { (*ptr.cast::<MemBio>()).pos = 0 };
+                core.BIO_read_ex_pooled(black_box(ptr)).unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_bio_read);
+criterion_main!(benches);
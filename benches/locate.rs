@@ -0,0 +1,51 @@
+//! Benchmarks [`ossl_param_locate_const()`] over a large list of params,
+//! against a naive lookup building a [`CStr`] from every key.
+//!
+//! Run with `cargo bench --bench locate`.
+
+use std::ffi::{CStr, CString};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use openssl_provider_forge::osslparams::*;
+
+const LIST_LEN: usize = 1000;
+
+fn naive_locate(params: &[OSSL_PARAM], key: &CStr) -> Option<*const OSSL_PARAM> {
+    params
+        .iter()
+        .take_while(|p| !p.key.is_null())
+        .find(|p| unsafe { CStr::from_ptr(p.key) } == key)
+        .map(std::ptr::from_ref)
+}
+
+fn bench_locate(c: &mut Criterion) {
+    // long keys with a shared prefix are the worst case for re-scanning
+    let keys: Vec<CString> = (0..LIST_LEN)
+        .map(|i| CString::new(format!("an-arbitrary-long-param-key-{i}")).unwrap())
+        .collect();
+    let list: Vec<OSSL_PARAM> = keys
+        .iter()
+        .map(|key| OSSL_PARAM {
+            key: key.as_ptr(),
+            data_type: OSSL_PARAM_INTEGER,
+            data: std::ptr::null_mut(),
+            data_size: 0,
+            return_size: OSSL_PARAM_UNMODIFIED,
+        })
+        .chain([OSSL_PARAM_END])
+        .collect();
+    let last = keys.last().unwrap().as_c_str();
+
+    let mut group = c.benchmark_group("locate");
+    group.bench_function("ossl_param_locate_const", |b| {
+        b.iter(|| ossl_param_locate_const(black_box(list.as_ptr()), black_box(last)).is_some())
+    });
+    group.bench_function("naive", |b| {
+        b.iter(|| naive_locate(black_box(&list), black_box(last)).is_some())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_locate);
+criterion_main!(benches);
@@ -1,7 +1,7 @@
 use std::env;
 use std::path::PathBuf;
 
-fn generate_bindings() {
+fn generate_bindings() -> String {
     // Tell cargo to invalidate the built crate whenever the wrapper changes
     println!("cargo:rerun-if-changed=include/wrapper.h");
 
@@ -37,6 +37,70 @@ fn generate_bindings() {
     bindings
         .write_to_file(out_path.join("bindings.rs"))
         .expect("Couldn't write bindings!");
+
+    bindings.to_string()
+}
+
+/// Emits a `ossl_param_type = "<name>"` cfg for every `OSSL_PARAM_<NAME>` data
+/// type defined by the OpenSSL headers we are building against.
+///
+/// This lets `src/osslparams.rs` conditionally handle data types that are only
+/// defined by newer OpenSSL versions, e.g. with
+/// `#[cfg(ossl_param_type = "real")]` for `OSSL_PARAM_REAL`.
+fn detect_param_data_types(bindings: &str) {
+    println!("cargo:rustc-check-cfg=cfg(ossl_param_type, values(any()))");
+
+    for line in bindings.lines() {
+        let Some(rest) = line.trim().strip_prefix("pub const OSSL_PARAM_") else {
+            continue;
+        };
+        let Some((name, _value)) = rest.split_once(": u32 =") else {
+            continue;
+        };
+        println!(
+            "cargo:rustc-cfg=ossl_param_type=\"{}\"",
+            name.to_lowercase()
+        );
+    }
+}
+
+/// Emits the `ossl_sigalg_dtls` cfg if the OpenSSL headers we are building
+/// against define the `OSSL_CAPABILITY_TLS_SIGALG_{MIN,MAX}_DTLS` keys, which
+/// are not available in OpenSSL 3.2.
+fn detect_sigalg_dtls(bindings: &str) {
+    println!("cargo:rustc-check-cfg=cfg(ossl_sigalg_dtls)");
+
+    let has_key = |key: &str| {
+        bindings
+            .lines()
+            .any(|line| line.trim().starts_with(&format!("pub const {key}:")))
+    };
+    if has_key("OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS")
+        && has_key("OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS")
+    {
+        println!("cargo:rustc-cfg=ossl_sigalg_dtls");
+    }
+}
+
+/// Writes `$OUT_DIR/func_names.rs`, with the name and value of every
+/// `OSSL_FUNC_<NAME>` function id defined by the OpenSSL headers we are
+/// building against, for `bindings::OsslFuncId`.
+fn generate_func_names(bindings: &str) {
+    let mut entries = String::new();
+    for line in bindings.lines() {
+        let Some(rest) = line.trim().strip_prefix("pub const OSSL_FUNC_") else {
+            continue;
+        };
+        let Some((name, value)) = rest.split_once(": u32 = ") else {
+            continue;
+        };
+        let value = value.trim_end_matches(';');
+        entries.push_str(&format!("    (\"OSSL_FUNC_{name}\", {value}),\n"));
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::write(out_path.join("func_names.rs"), format!("&[\n{entries}]\n"))
+        .expect("Couldn't write func_names.rs!");
 }
 
 fn main() {
@@ -47,5 +111,8 @@ fn main() {
     // shared library.
     //println!("cargo:rustc-link-lib=bz2");
 
-    generate_bindings()
+    let bindings = generate_bindings();
+    detect_param_data_types(&bindings);
+    detect_sigalg_dtls(&bindings);
+    generate_func_names(&bindings);
 }
@@ -40,8 +40,13 @@ pub mod ffi_c_types {
 
 pub use ffi_c_types::*;
 
-// FIXME: we hardcode these here for now, rather than conditionally defining them in bindings
+// These keys are not defined by OpenSSL 3.2, so we hardcode them when they are
+// missing from the generated bindings, for source compatibility.
+// Note that `capabilities::tls_sigalg::as_params!` only emits them when they
+// are defined by the OpenSSL headers (see `ossl_sigalg_dtls` in `build.rs`).
+#[cfg(not(ossl_sigalg_dtls))]
 pub const OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS: &CStr = c"tls-min-dtls";
+#[cfg(not(ossl_sigalg_dtls))]
 pub const OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS: &CStr = c"tls-max-dtls";
 
 /// This is the value assigned to
@@ -98,6 +103,98 @@ impl Default for OSSL_DISPATCH {
     }
 }
 
+/// The `function_id` of an [`OSSL_DISPATCH`] entry, which can be rendered
+/// with its symbolic `OSSL_FUNC_*` name(s).
+///
+/// Function ids are only unique within the same kind of dispatch table (e.g.,
+/// `1` is both `OSSL_FUNC_CORE_GETTABLE_PARAMS` and `OSSL_FUNC_KEYMGMT_NEW`),
+/// so an id may have several names.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::bindings::{OsslFuncId, OSSL_FUNC_PROVIDER_TEARDOWN};
+///
+/// let id = OsslFuncId(OSSL_FUNC_PROVIDER_TEARDOWN as i32);
+/// assert!(id.names().any(|name| name == "OSSL_FUNC_PROVIDER_TEARDOWN"));
+/// assert!(id.to_string().starts_with("1024 ("));
+///
+/// assert_eq!(OsslFuncId(-1).to_string(), "-1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OsslFuncId(pub c_int);
+
+impl OsslFuncId {
+    /// The name and value of every `OSSL_FUNC_*` id defined by the OpenSSL
+    /// headers this crate has been built against.
+    const NAMES: &'static [(&'static str, u32)] =
+        include!(concat!(env!("OUT_DIR"), "/func_names.rs"));
+
+    /// Returns the names of the `OSSL_FUNC_*` constants with this value.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Self::NAMES
+            .iter()
+            .filter(move |&&(_, value)| i64::from(value) == i64::from(self.0))
+            .map(|&(name, _)| name)
+    }
+}
+
+impl std::fmt::Display for OsslFuncId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)?;
+        let names: Vec<_> = self.names().collect();
+        if !names.is_empty() {
+            write!(f, " ({})", names.join(" | "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders a human-readable dump of a dispatch `table`, one line per entry up
+/// to the END one, with its [function id][`OsslFuncId`] and whether its
+/// function pointer is set.
+///
+/// This is meant for debugging the wiring of a provider (e.g., "did I
+/// register everything?").
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::bindings::*;
+///
+/// unsafe extern "C" fn teardown(_provctx: *mut c_void) {}
+///
+/// let table = [
+///     OSSL_DISPATCH::new(OSSL_FUNC_PROVIDER_TEARDOWN as c_int, unsafe {
+///         Some(generic_non_null_fn_ptr!(teardown))
+///     }),
+///     OSSL_DISPATCH::END,
+/// ];
+/// let dump = dump_dispatch_table(&table);
+/// assert!(dump.contains("OSSL_FUNC_PROVIDER_TEARDOWN"));
+/// assert!(dump.ends_with("END\n"));
+/// ```
+pub fn dump_dispatch_table(table: &[OSSL_DISPATCH]) -> String {
+    let mut dump = String::new();
+    for (i, entry) in table.iter().enumerate() {
+        if entry.function_id == 0 {
+            dump.push_str(&format!("[{i}] END\n"));
+            return dump;
+        }
+        let function = if entry.function.is_some() {
+            "set"
+        } else {
+            "NULL"
+        };
+        dump.push_str(&format!(
+            "[{i}] {}: {function}\n",
+            OsslFuncId(entry.function_id)
+        ));
+    }
+    dump.push_str("(no END entry)\n");
+    dump
+}
+
 /// A convenience macro to quickly declare a OSSL_DISPATCH table entry
 #[macro_export]
 macro_rules! dispatch_table_entry {
@@ -127,6 +224,55 @@ impl OSSL_ALGORITHM {
         implementation: std::ptr::null(),
         algorithm_description: std::ptr::null(),
     };
+
+    /// Creates an algorithm entry with the given (colon-separated) `names`,
+    /// `properties` definition, and END-terminated `dispatch` table, without
+    /// a description.
+    ///
+    /// Use [`OSSL_ALGORITHM::with_description`] to set a description.
+    pub const fn new(
+        names: &'static CStr,
+        properties: &'static CStr,
+        dispatch: &'static [OSSL_DISPATCH],
+    ) -> Self {
+        Self {
+            algorithm_names: names.as_ptr(),
+            property_definition: properties.as_ptr(),
+            implementation: dispatch.as_ptr(),
+            algorithm_description: std::ptr::null(),
+        }
+    }
+
+    /// Sets the (human readable) description of this algorithm entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::bindings::{OSSL_ALGORITHM, OSSL_DISPATCH};
+    ///
+    /// static DISPATCH: [OSSL_DISPATCH; 1] = [OSSL_DISPATCH::END];
+    ///
+    /// let alg = OSSL_ALGORITHM::new(c"X25519MLKEM768", c"provider=forge", &DISPATCH)
+    ///     .with_description(c"Hybrid X25519 + ML-KEM-768 KEM");
+    /// assert_eq!(alg.description(), Some(c"Hybrid X25519 + ML-KEM-768 KEM"));
+    /// ```
+    pub const fn with_description(self, description: &'static CStr) -> Self {
+        Self {
+            algorithm_description: description.as_ptr(),
+            ..self
+        }
+    }
+
+    /// Returns the description of this algorithm entry, or `None` if it is
+    /// `NULL`.
+    pub fn description(&self) -> Option<&CStr> {
+        if self.algorithm_description.is_null() {
+            return None;
+        }
+        // SAFETY: a non-NULL description is a NUL-terminated string, which
+        // (as for any OSSL_ALGORITHM) must outlive the entry
+        Some(unsafe { CStr::from_ptr(self.algorithm_description) })
+    }
 }
 
 impl Default for OSSL_ALGORITHM {
@@ -134,3 +280,80 @@ impl Default for OSSL_ALGORITHM {
         Self::END
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::common::OurError;
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    static DISPATCH: [OSSL_DISPATCH; 1] = [OSSL_DISPATCH::END];
+
+    #[test]
+    fn test_algorithm_with_description() {
+        setup().expect("setup() failed");
+
+        let alg = OSSL_ALGORITHM::new(
+            c"ML-DSA-65:2.16.840.1.101.3.4.3.18",
+            c"x.author='QUBIP'",
+            &DISPATCH,
+        )
+        .with_description(c"ML-DSA-65 signatures");
+
+        assert_eq!(alg.description(), Some(c"ML-DSA-65 signatures"));
+        assert_eq!(
+            unsafe { CStr::from_ptr(alg.algorithm_names) },
+            c"ML-DSA-65:2.16.840.1.101.3.4.3.18"
+        );
+        assert_eq!(
+            unsafe { CStr::from_ptr(alg.property_definition) },
+            c"x.author='QUBIP'"
+        );
+        assert_eq!(alg.implementation, DISPATCH.as_ptr());
+    }
+
+    #[test]
+    fn test_dump_dispatch_table() {
+        setup().expect("setup() failed");
+
+        unsafe extern "C" fn teardown(_provctx: *mut c_void) {}
+
+        let table = [
+            OSSL_DISPATCH::new(OSSL_FUNC_PROVIDER_TEARDOWN as c_int, unsafe {
+                Some(generic_non_null_fn_ptr!(teardown))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_PROVIDER_GETTABLE_PARAMS as c_int, None),
+            OSSL_DISPATCH::END,
+            // past the END entry, so never dumped
+            OSSL_DISPATCH::new(OSSL_FUNC_PROVIDER_GET_PARAMS as c_int, None),
+        ];
+        let dump = dump_dispatch_table(&table);
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 3, "{dump}");
+        assert!(lines[0].starts_with("[0] 1024 ("), "{dump}");
+        assert!(lines[0].contains("OSSL_FUNC_PROVIDER_TEARDOWN"), "{dump}");
+        assert!(lines[0].ends_with(": set"), "{dump}");
+        assert!(
+            lines[1].contains("OSSL_FUNC_PROVIDER_GETTABLE_PARAMS"),
+            "{dump}"
+        );
+        assert!(lines[1].ends_with(": NULL"), "{dump}");
+        assert_eq!(lines[2], "[2] END");
+        assert!(!dump.contains("OSSL_FUNC_PROVIDER_GET_PARAMS"), "{dump}");
+
+        let dump = dump_dispatch_table(&table[..1]);
+        assert!(dump.ends_with("(no END entry)\n"), "{dump}");
+    }
+
+    #[test]
+    fn test_algorithm_without_description() {
+        setup().expect("setup() failed");
+
+        let alg = OSSL_ALGORITHM::new(c"ML-DSA-65", c"", &DISPATCH);
+        assert_eq!(alg.description(), None);
+        assert_eq!(OSSL_ALGORITHM::END.description(), None);
+    }
+}
@@ -9,24 +9,55 @@
 //! * [`tls_sigalg`]
 //!
 //! Refer to [provider-base(7ossl)](https://docs.openssl.org/master/man7/provider-base/#capabilities)
+//!
+//! The [`provider_params`] submodule provides helpers for the parameters
+//! exchanged between the provider and the core.
+//!
+//! The params arrays describing the capabilities can be collected in a
+//! [`Registry`], to be reported to the core through
+//! [`get_capabilities_handler`], or reported lazily, by implementing
+//! [`CapabilitySource`] and using [`report_capabilities`].
+
+pub mod provider_params;
+
+pub mod registry;
+pub use registry::{get_capabilities_handler, Registry};
 
 pub mod tls_group;
 
 pub use tls_group::as_params as tls_group_as_params;
+pub use tls_group::groups_as_params as tls_groups_as_params;
+pub use tls_group::validate_group_alg;
 pub use tls_group::TLSGroup;
 
 pub mod tls_sigalg;
 pub use tls_sigalg::as_params as tls_sigalg_as_params;
 pub use tls_sigalg::TLSSigAlg;
 
+pub use crate::bindings::OSSL_CIPHER_PARAM_TLS_VERSION;
 pub use crate::{DTLSVersion, TLSVersion};
 
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+
+use crate::ossl_callback::OSSLCallback;
+use crate::osslparams::{
+    contains_key, data_type_name, raw_params, OSSLParam, CONST_OSSL_PARAM, OSSL_PARAM,
+};
+
+/// The key used by [`optional_param`] for absent optional params, which are
+/// ignored by OpenSSL.
+///
+/// Note that it can appear multiple times in the same params array.
+#[doc(hidden)]
+pub const OPTIONAL_PARAM_IGNORED_KEY: &std::ffi::CStr = c"__ignored__";
+
 #[doc(hidden)]
 /// An internal macro to handle optional params
 #[macro_export]
 macro_rules! __hidden__optional_param {
     ($new_fn:ident, $param_key:ident, $cnst:expr) => {{
-        const IGNORED: &CStr = c"__ignored__";
+        const IGNORED: &CStr = $crate::capabilities::OPTIONAL_PARAM_IGNORED_KEY;
         match $cnst {
             //None => OSSLParam::new_const_utf8string(IGNORED, Some(IGNORED)),
             None => OSSLParam::new_const_utf8string(IGNORED, None),
@@ -38,3 +69,724 @@ macro_rules! __hidden__optional_param {
 /// An internal macro to handle optional params
 #[doc(hidden)]
 pub use __hidden__optional_param as optional_param;
+
+/// Returns the total length of `lists`, for [`concat_params`].
+#[doc(hidden)]
+pub const fn concat_params_len(lists: &[&[CONST_OSSL_PARAM]]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < lists.len() {
+        len += lists[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Concatenates `lists` at compile time (e.g., for
+/// [`tls_group::groups_as_params`]).
+///
+/// `N` must be the total length of `lists`, as returned by
+/// [`concat_params_len`].
+#[doc(hidden)]
+pub const fn concat_params<const N: usize>(lists: &[&[CONST_OSSL_PARAM]]) -> [CONST_OSSL_PARAM; N] {
+    let mut out = [CONST_OSSL_PARAM::END; N];
+    let mut n = 0;
+    let mut i = 0;
+    while i < lists.len() {
+        let mut j = 0;
+        while j < lists[i].len() {
+            out[n] = lists[i][j];
+            n += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(n == N, "N must be the total length of the lists");
+    out
+}
+
+/// Returns whether the OpenSSL bindings of this crate include the
+/// `OSSL_CAPABILITY_TLS_SIGALG_{MIN,MAX}_DTLS` keys, i.e. whether the
+/// [`tls_sigalg`] capability can advertise DTLS support.
+///
+/// This is fixed at build time (see [`tls_sigalg::DTLS_SUPPORTED`]), so it can
+/// also be used in `const` contexts.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::supports_sigalg_dtls;
+///
+/// if !supports_sigalg_dtls() {
+///     println!("DTLS sigalg params will not be advertised");
+/// }
+/// ```
+pub const fn supports_sigalg_dtls() -> bool {
+    tls_sigalg::DTLS_SUPPORTED
+}
+
+/// Checks whether a capability supporting protocol versions from `min` to
+/// `max` (as declared, e.g., by [`TLSGroup::MIN_TLS`] and [`TLSGroup::MAX_TLS`])
+/// applies to the `negotiated` protocol version.
+///
+/// The sentinel values are handled as follows:
+///
+/// * [`TLSVersion::None`] as `min` means "no lower bound";
+/// * [`TLSVersion::None`] as `max` means "no upper bound";
+/// * [`TLSVersion::Disabled`] as either `min` or `max` means the capability
+///   never applies;
+/// * if `negotiated` is one of the sentinels, the capability does not apply.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::{version_applies, TLSVersion};
+///
+/// assert!(version_applies(TLSVersion::TLSv1_3, TLSVersion::None, TLSVersion::TLSv1_3));
+/// assert!(!version_applies(TLSVersion::TLSv1_3, TLSVersion::None, TLSVersion::TLSv1_2));
+/// assert!(!version_applies(TLSVersion::Disabled, TLSVersion::None, TLSVersion::TLSv1_3));
+/// ```
+pub fn version_applies(min: TLSVersion, max: TLSVersion, negotiated: TLSVersion) -> bool {
+    use std::cmp::Ordering;
+
+    match (min, max, negotiated) {
+        (_, _, TLSVersion::None | TLSVersion::Disabled) => false,
+        (TLSVersion::Disabled, _, _) | (_, TLSVersion::Disabled, _) => false,
+        _ => {
+            let above_min = min == TLSVersion::None
+                || matches!(
+                    negotiated.partial_cmp(&min),
+                    Some(Ordering::Greater | Ordering::Equal)
+                );
+            let below_max = max == TLSVersion::None
+                || matches!(
+                    negotiated.partial_cmp(&max),
+                    Some(Ordering::Less | Ordering::Equal)
+                );
+            above_min && below_max
+        }
+    }
+}
+
+/// Returns the protocol version passed in the params starting at `head`
+/// (e.g., the params of a cipher context used by the TLS record layer), to be
+/// checked against the capabilities with [`version_applies`].
+///
+/// The version is looked up under the [`OSSL_CIPHER_PARAM_TLS_VERSION`] key
+/// (`"tls-version"`), and it can be either a signed or unsigned integer param
+/// holding the raw protocol codepoint (e.g., `0x0304` for TLS 1.3).
+///
+/// Returns `None` if the key is missing (or `head` is `NULL`), if its value
+/// cannot be read as an integer, or if it is not a known [`TLSVersion`].
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::{negotiated_version_from_params, TLSVersion};
+/// use openssl_provider_forge::osslparams::{OSSLParam, CONST_OSSL_PARAM, OSSL_PARAM};
+///
+/// let params = [
+///     OSSLParam::new_const_uint(c"tls-version", Some(&0x0303u32)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let version = negotiated_version_from_params(params.as_ptr() as *const OSSL_PARAM);
+/// assert_eq!(version, Some(TLSVersion::TLSv1_2));
+/// ```
+pub fn negotiated_version_from_params(head: *const OSSL_PARAM) -> Option<TLSVersion> {
+    // SAFETY: `raw_params()` only yields items with a non-NULL key.
+    let p = raw_params(head)
+        .find(|p| unsafe { CStr::from_ptr(p.key) }.eq(OSSL_CIPHER_PARAM_TLS_VERSION))?;
+    let param = OSSLParam::try_from(std::ptr::from_ref(p)).ok()?;
+    let codepoint = match param {
+        OSSLParam::Int(_) => param.get::<i64>()?,
+        OSSLParam::UInt(_) => i64::try_from(param.get::<u64>()?).ok()?,
+        _ => return None,
+    };
+    TLSVersion::try_from(i32::try_from(codepoint).ok()?).ok()
+}
+
+/// A source of capability params arrays, which are built lazily (i.e., one at
+/// a time) while they are reported to the core through
+/// [`report_capabilities`].
+///
+/// This allows a provider's `get_capabilities` to avoid building the arrays
+/// for, e.g., all its TLS groups upfront, when the core callback may stop the
+/// iteration early.
+pub trait CapabilitySource {
+    /// Returns the next properly END-terminated params array, or `None` when
+    /// there are no more arrays to report.
+    fn next_params(&mut self) -> Option<&[CONST_OSSL_PARAM]>;
+}
+
+/// Reports each params array yielded by `source` to the core `callback`, as
+/// expected from a provider's `get_capabilities` function.
+///
+/// The iteration stops as soon as `callback` returns `0`: the remaining
+/// arrays are never requested from `source`.
+///
+/// Returns `true` if every array was accepted by `callback`, `false`
+/// otherwise.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use openssl_provider_forge::capabilities::{report_capabilities, CapabilitySource};
+/// use openssl_provider_forge::ossl_callback::OSSLCallback;
+/// use openssl_provider_forge::osslparams::{OSSLParam, CONST_OSSL_PARAM};
+///
+/// static GROUP_IDS: [u32; 2] = [4587, 4588];
+///
+/// struct Groups {
+///     next: usize,
+///     current: [CONST_OSSL_PARAM; 2],
+/// }
+///
+/// impl CapabilitySource for Groups {
+///     fn next_params(&mut self) -> Option<&[CONST_OSSL_PARAM]> {
+///         let id = GROUP_IDS.get(self.next)?;
+///         self.next += 1;
+///         self.current = [
+///             OSSLParam::new_const_uint(c"tls-group-id", Some(id)),
+///             CONST_OSSL_PARAM::END,
+///         ];
+///         Some(&self.current)
+///     }
+/// }
+///
+/// fn get_capabilities(callback: &OSSLCallback) -> bool {
+///     let mut groups = Groups { next: 0, current: [CONST_OSSL_PARAM::END; 2] };
+///     report_capabilities(&mut groups, callback)
+/// }
+/// ```
+pub fn report_capabilities<S: CapabilitySource + ?Sized>(
+    source: &mut S,
+    callback: &OSSLCallback,
+) -> bool {
+    while let Some(params) = source.next_params() {
+        debug_assert!(
+            params.last().is_some_and(|p| p.key.is_null()),
+            "capability params must be END-terminated"
+        );
+        if callback
+            .call_checked(params.as_ptr() as *const OSSL_PARAM)
+            .is_err()
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Renders the properly END-terminated list of params starting at `head`
+/// (e.g., a capability params array) as a sorted map from each key to its
+/// rendered value.
+///
+/// The result is stable, so it is suitable for snapshot tests, e.g., to diff
+/// the capabilities advertised by a provider when building against different
+/// OpenSSL releases.
+///
+/// Values are rendered as follows:
+///
+/// * integers in decimal;
+/// * UTF-8 strings (or pointers) verbatim (lossily, if not valid UTF-8);
+/// * octet strings in lowercase hex;
+/// * `<NULL>` for params with `NULL` data;
+/// * `<OSSL_PARAM_...>` (i.e., the name of the data type) for values of a
+///   data type not supported by [`OSSLParam`][`crate::osslparams::OSSLParam`].
+///
+/// The placeholders of absent optional params (see [`optional_param`]) are
+/// omitted, and, as in OpenSSL, only the first item with a given key is
+/// considered.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::summarize_params;
+/// use openssl_provider_forge::osslparams::{OSSLParam, CONST_OSSL_PARAM, OSSL_PARAM};
+///
+/// let params = [
+///     OSSLParam::new_const_utf8string(c"tls-sigalg-name", Some(c"ed448")),
+///     OSSLParam::new_const_uint(c"tls-sigalg-code-point", Some(&0x0808u32)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let summary = summarize_params(params.as_ptr() as *const OSSL_PARAM);
+/// let lines: Vec<String> = summary.iter().map(|(k, v)| format!("{k}={v}")).collect();
+/// assert_eq!(lines, ["tls-sigalg-code-point=2056", "tls-sigalg-name=ed448"]);
+/// ```
+pub fn summarize_params(head: *const OSSL_PARAM) -> BTreeMap<String, String> {
+    let mut summary = BTreeMap::new();
+    for p in raw_params(head) {
+        // SAFETY: `raw_params()` only yields items with a non-NULL key.
+        let key = unsafe { CStr::from_ptr(p.key) };
+        if key == OPTIONAL_PARAM_IGNORED_KEY {
+            continue;
+        }
+        let value = match OSSLParam::try_from(std::ptr::from_ref(p)) {
+            Ok(param) => render_param_value(&param),
+            Err(_) => format!(
+                "<{}>",
+                data_type_name(p.data_type).unwrap_or("unknown data type")
+            ),
+        };
+        summary
+            .entry(key.to_string_lossy().into_owned())
+            .or_insert(value);
+    }
+    summary
+}
+
+/// Renders the value of `param` for [`summarize_params`].
+fn render_param_value(param: &OSSLParam) -> String {
+    // SAFETY: the pointer wrapped by an `OSSLParam` is never NULL
+    if unsafe { (*param.get_c_struct()).data }.is_null() {
+        return "<NULL>".to_string();
+    }
+    let value = match param {
+        OSSLParam::Int(_) => param.get::<i128>().map(|v| v.to_string()),
+        OSSLParam::UInt(_) => param.get::<u64>().map(|v| v.to_string()),
+        #[cfg(ossl_param_type = "real")]
+        OSSLParam::Real(_) => param.get::<f64>().map(|v| v.to_string()),
+        OSSLParam::Utf8Ptr(_) | OSSLParam::Utf8String(_) => param
+            .get::<&CStr>()
+            .map(|v| v.to_string_lossy().into_owned()),
+        OSSLParam::OctetString(_) | OSSLParam::OctetPtr(_) => param
+            .get::<&[u8]>()
+            .map(|v| v.iter().map(|b| format!("{b:02x}")).collect()),
+    };
+    value.unwrap_or_else(|| "<invalid>".to_string())
+}
+
+/// A dependency between the (optional) fields of a capability, as checked
+/// by [`validate_field_dependencies()`] (or, at compile time, by
+/// [`field_dependencies_hold()`]).
+///
+/// Each field is identified by the key of its param.
+///
+/// See [`tls_sigalg::FIELD_DEPENDENCIES`] and
+/// [`tls_group::FIELD_DEPENDENCIES`] for the dependencies among the fields of
+/// the "TLS-SIGALG" and "TLS-GROUP" capabilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldDependency {
+    /// If the `field` param is present, all the `requires` params must be
+    /// present too.
+    Requires {
+        /// The key of the dependent param.
+        field: &'static CStr,
+        /// The keys of the params required by `field`.
+        requires: &'static [&'static CStr],
+    },
+
+    /// Either all of these params are present, or none of them.
+    AllOrNone(&'static [&'static CStr]),
+}
+
+impl FieldDependency {
+    /// Returns `true` if the dependency holds among the fields of `params`, up
+    /// to its END item (if any).
+    const fn is_satisfied(&self, params: &[CONST_OSSL_PARAM]) -> bool {
+        match self {
+            Self::Requires { field, requires } => {
+                !contains_key(params, field) || count_present(params, requires) == requires.len()
+            }
+            Self::AllOrNone(keys) => {
+                let count = count_present(params, keys);
+                count == 0 || count == keys.len()
+            }
+        }
+    }
+}
+
+/// Returns how many of `keys` are present in `params`, up to its END item (if
+/// any).
+const fn count_present(params: &[CONST_OSSL_PARAM], keys: &[&CStr]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < keys.len() {
+        if contains_key(params, keys[i]) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+impl std::fmt::Display for FieldDependency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Requires { field, requires } => write!(f, "{field:?} requires {requires:?}"),
+            Self::AllOrNone(keys) => write!(f, "either all or none of {keys:?}"),
+        }
+    }
+}
+
+/// Checks that the capability params array `params` (such as the one
+/// generated by [`tls_sigalg::as_params`]) satisfies all the `dependencies`
+/// among its fields.
+///
+/// The absent optional fields of the arrays generated by this crate are not
+/// considered present.
+///
+/// # Errors
+///
+/// Returns an error if `params` is not END-terminated, or if any of the
+/// `dependencies` does not hold, listing all the violated ones.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::{validate_field_dependencies, FieldDependency};
+/// use openssl_provider_forge::osslparams::{OSSLParam, CONST_OSSL_PARAM};
+///
+/// const DEPENDENCIES: &[FieldDependency] = &[FieldDependency::Requires {
+///     field: c"hash-oid",
+///     requires: &[c"hash-name"],
+/// }];
+///
+/// let params = [
+///     OSSLParam::new_const_utf8string(c"hash-name", Some(c"SHA256")),
+///     OSSLParam::new_const_utf8string(c"hash-oid", Some(c"2.16.840.1.101.3.4.2.1")),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// assert!(validate_field_dependencies(&params, DEPENDENCIES).is_ok());
+///
+/// let params = [
+///     OSSLParam::new_const_utf8string(c"hash-oid", Some(c"2.16.840.1.101.3.4.2.1")),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// let err = validate_field_dependencies(&params, DEPENDENCIES).unwrap_err();
+/// assert!(err.to_string().contains(r#""hash-oid" requires ["hash-name"]"#));
+/// ```
+pub fn validate_field_dependencies(
+    params: &[CONST_OSSL_PARAM],
+    dependencies: &[FieldDependency],
+) -> Result<(), crate::OurError> {
+    if !params.last().is_some_and(|p| p.key.is_null()) {
+        return Err(anyhow::anyhow!(
+            "The capability params are not END-terminated"
+        ));
+    }
+    let violated: Vec<String> = dependencies
+        .iter()
+        .filter(|d| !d.is_satisfied(params))
+        .map(FieldDependency::to_string)
+        .collect();
+    if !violated.is_empty() {
+        return Err(anyhow::anyhow!(
+            "The capability params violate the field dependencies: {}",
+            violated.join("; ")
+        ));
+    }
+    Ok(())
+}
+
+/// Returns `true` if the capability params array `params` satisfies all the
+/// `dependencies` among its fields.
+///
+/// This is the `const` counterpart of [`validate_field_dependencies()`], used
+/// by [`tls_sigalg::as_params`] and [`tls_group::as_params`] to check their
+/// params at compile time: as it cannot report which dependencies are
+/// violated, call [`validate_field_dependencies()`] to find out.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::{field_dependencies_hold, FieldDependency};
+/// use openssl_provider_forge::osslparams::{OSSLParam, CONST_OSSL_PARAM};
+///
+/// const DEPENDENCIES: &[FieldDependency] = &[FieldDependency::Requires {
+///     field: c"hash-oid",
+///     requires: &[c"hash-name"],
+/// }];
+///
+/// const PARAMS: &[CONST_OSSL_PARAM] = &[
+///     OSSLParam::new_const_utf8string(c"hash-oid", Some(c"2.16.840.1.101.3.4.2.1")),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// const _: () = assert!(!field_dependencies_hold(PARAMS, DEPENDENCIES));
+/// ```
+pub const fn field_dependencies_hold(
+    params: &[CONST_OSSL_PARAM],
+    dependencies: &[FieldDependency],
+) -> bool {
+    let mut i = 0;
+    while i < dependencies.len() {
+        if !dependencies[i].is_satisfied(params) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::common::OurError;
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    #[test]
+    fn test_version_applies_bounds() {
+        setup().expect("setup() failed");
+
+        let (min, max) = (TLSVersion::TLSv1_1, TLSVersion::TLSv1_2);
+        assert!(!version_applies(min, max, TLSVersion::TLSv1_0));
+        assert!(version_applies(min, max, TLSVersion::TLSv1_1));
+        assert!(version_applies(min, max, TLSVersion::TLSv1_2));
+        assert!(!version_applies(min, max, TLSVersion::TLSv1_3));
+
+        let only = TLSVersion::TLSv1_3;
+        assert!(version_applies(only, only, TLSVersion::TLSv1_3));
+        assert!(!version_applies(only, only, TLSVersion::TLSv1_2));
+    }
+
+    #[test]
+    fn test_version_applies_no_bounds() {
+        setup().expect("setup() failed");
+
+        assert!(version_applies(
+            TLSVersion::TLSv1_2,
+            TLSVersion::None,
+            TLSVersion::TLSv1_3
+        ));
+        assert!(version_applies(
+            TLSVersion::None,
+            TLSVersion::TLSv1_2,
+            TLSVersion::TLSv1_0
+        ));
+        assert!(!version_applies(
+            TLSVersion::None,
+            TLSVersion::TLSv1_2,
+            TLSVersion::TLSv1_3
+        ));
+        assert!(version_applies(
+            TLSVersion::None,
+            TLSVersion::None,
+            TLSVersion::TLSv1_3
+        ));
+    }
+
+    #[test]
+    fn test_version_applies_sentinels() {
+        setup().expect("setup() failed");
+
+        let versions = [
+            TLSVersion::None,
+            TLSVersion::Disabled,
+            TLSVersion::TLSv1_0,
+            TLSVersion::TLSv1_3,
+        ];
+        for v in versions {
+            assert!(!version_applies(TLSVersion::Disabled, TLSVersion::None, v));
+            assert!(!version_applies(TLSVersion::None, TLSVersion::Disabled, v));
+            assert!(!version_applies(
+                TLSVersion::None,
+                TLSVersion::None,
+                TLSVersion::None
+            ));
+            assert!(!version_applies(v, TLSVersion::None, TLSVersion::Disabled));
+        }
+    }
+
+    #[test]
+    fn test_negotiated_version_from_params() {
+        setup().expect("setup() failed");
+
+        let params = [
+            OSSLParam::new_const_int(c"tls-version", Some(&0x0304i32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        let version = negotiated_version_from_params(params.as_ptr() as *const OSSL_PARAM);
+        assert_eq!(version, Some(TLSVersion::TLSv1_3));
+
+        let params = [
+            OSSLParam::new_const_utf8string(c"cipher", Some(c"AES-128-GCM")),
+            OSSLParam::new_const_uint(c"tls-version", Some(&0x0304u64)),
+            CONST_OSSL_PARAM::END,
+        ];
+        let version = negotiated_version_from_params(params.as_ptr() as *const OSSL_PARAM);
+        assert_eq!(version, Some(TLSVersion::TLSv1_3));
+    }
+
+    #[test]
+    fn test_negotiated_version_absent_or_invalid() {
+        setup().expect("setup() failed");
+
+        let params = [
+            OSSLParam::new_const_uint(c"other", Some(&0x0304u32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        let version = negotiated_version_from_params(params.as_ptr() as *const OSSL_PARAM);
+        assert_eq!(version, None);
+        assert_eq!(negotiated_version_from_params(std::ptr::null()), None);
+
+        // unknown codepoints and non-integer values are not versions
+        let params = [
+            OSSLParam::new_const_uint(c"tls-version", Some(&0x0305u32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        let version = negotiated_version_from_params(params.as_ptr() as *const OSSL_PARAM);
+        assert_eq!(version, None);
+        let params = [
+            OSSLParam::new_const_utf8string(c"tls-version", Some(c"TLSv1.3")),
+            CONST_OSSL_PARAM::END,
+        ];
+        let version = negotiated_version_from_params(params.as_ptr() as *const OSSL_PARAM);
+        assert_eq!(version, None);
+    }
+
+    #[test]
+    fn test_supports_sigalg_dtls() {
+        setup().expect("setup() failed");
+
+        assert_eq!(supports_sigalg_dtls(), cfg!(ossl_sigalg_dtls));
+        const SUPPORTED: bool = supports_sigalg_dtls();
+        assert_eq!(SUPPORTED, tls_sigalg::DTLS_SUPPORTED);
+    }
+
+    mod summary {
+        use super::*;
+        use crate::osslparams::OSSL_PARAM_UNMODIFIED;
+        use tls_sigalg::*;
+
+        // The sigalg of the module documentation of `tls_sigalg`
+        pub struct TLSSigAlgCap;
+
+        impl TLSSigAlg for TLSSigAlgCap {
+            const SIGALG_IANA_NAME: &CStr = c"xorhmacsha2sig";
+            const SIGALG_NAME: &CStr = Self::SIGALG_IANA_NAME;
+            const SIGALG_HASH_NAME: Option<&CStr> = Some(c"SHA256");
+            const SIGALG_OID: Option<&CStr> = Some(c"1.3.6.1.4.1.16604.998888.2");
+            const SIGALG_CODEPOINT: u32 = 0xFFFF;
+
+            const SECURITY_BITS: u32 = 128;
+            const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+            const MAX_TLS: TLSVersion = TLSVersion::TLSv1_3;
+            const MIN_DTLS: DTLSVersion = DTLSVersion::DTLSv1_2;
+            const MAX_DTLS: DTLSVersion = DTLSVersion::DTLSv1_2;
+        }
+
+        fn entry(key: &CStr, value: &str) -> (String, String) {
+            (key.to_str().unwrap().to_string(), value.to_string())
+        }
+
+        #[test]
+        fn test_summarize_sigalg_params() {
+            setup().expect("setup() failed");
+
+            let params = tls_sigalg::as_params!(TLSSigAlgCap);
+            let summary = summarize_params(params.as_ptr() as *const OSSL_PARAM);
+
+            let mut expected = BTreeMap::from([
+                entry(OSSL_CAPABILITY_TLS_SIGALG_IANA_NAME, "xorhmacsha2sig"),
+                entry(OSSL_CAPABILITY_TLS_SIGALG_NAME, "xorhmacsha2sig"),
+                entry(OSSL_CAPABILITY_TLS_SIGALG_HASH_NAME, "SHA256"),
+                entry(OSSL_CAPABILITY_TLS_SIGALG_OID, "1.3.6.1.4.1.16604.998888.2"),
+                entry(OSSL_CAPABILITY_TLS_SIGALG_CODE_POINT, "65535"),
+                entry(OSSL_CAPABILITY_TLS_SIGALG_SECURITY_BITS, "128"),
+                entry(OSSL_CAPABILITY_TLS_SIGALG_MIN_TLS, "772"),
+                entry(OSSL_CAPABILITY_TLS_SIGALG_MAX_TLS, "772"),
+            ]);
+            if supports_sigalg_dtls() {
+                expected.extend([
+                    entry(OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS, "65277"),
+                    entry(OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS, "65277"),
+                ]);
+            }
+            // the absent optional params are omitted
+            assert_eq!(summary, expected);
+        }
+
+        #[test]
+        fn test_summarize_params_rendering() {
+            setup().expect("setup() failed");
+
+            let value = 1u32;
+            let params = [
+                OSSLParam::new_const_octetstring(c"octets", Some(&[0x0a, 0x1b, -1])),
+                OSSLParam::new_const_int::<i32>(c"null", None),
+                CONST_OSSL_PARAM {
+                    key: c"unsupported".as_ptr(),
+                    data_type: 0xdead,
+                    data: std::ptr::from_ref(&value) as *mut std::ffi::c_void,
+                    data_size: size_of::<u32>(),
+                    return_size: OSSL_PARAM_UNMODIFIED,
+                },
+                OSSLParam::new_const_int(c"int", Some(&-5i64)),
+                // only the first item with a given key is considered
+                OSSLParam::new_const_int(c"int", Some(&6i64)),
+                CONST_OSSL_PARAM::END,
+            ];
+
+            let summary = summarize_params(params.as_ptr() as *const OSSL_PARAM);
+            assert_eq!(
+                summary,
+                BTreeMap::from([
+                    entry(c"int", "-5"),
+                    entry(c"null", "<NULL>"),
+                    entry(c"octets", "0a1bff"),
+                    entry(c"unsupported", "<unknown data type>"),
+                ])
+            );
+            assert!(summarize_params(std::ptr::null()).is_empty());
+        }
+    }
+
+    mod source {
+        use super::*;
+        use crate::osslparams::OSSLParam;
+        use crate::tests::common::{seen_ids_callback, SeenIds};
+
+        static IDS: [u32; 2] = [4587, 4588];
+
+        /// A source yielding one array per entry of `IDS`, counting how many
+        /// arrays were actually built.
+        struct CountingSource {
+            built: usize,
+            current: [CONST_OSSL_PARAM; 2],
+        }
+
+        impl CapabilitySource for CountingSource {
+            fn next_params(&mut self) -> Option<&[CONST_OSSL_PARAM]> {
+                let id = IDS.get(self.built)?;
+                self.built += 1;
+                self.current = [
+                    OSSLParam::new_const_uint(c"id", Some(id)),
+                    CONST_OSSL_PARAM::END,
+                ];
+                Some(&self.current)
+            }
+        }
+
+        fn run(accept: usize) -> (bool, usize, Vec<u64>) {
+            let mut seen = SeenIds::new(accept);
+            let cb = OSSLCallback::try_new(Some(seen_ids_callback), seen.as_arg()).unwrap();
+            let mut source = CountingSource {
+                built: 0,
+                current: [CONST_OSSL_PARAM::END; 2],
+            };
+            let ret = report_capabilities(&mut source, &cb);
+            (ret, source.built, seen.ids)
+        }
+
+        #[test]
+        fn test_report_all() {
+            setup().expect("setup() failed");
+
+            assert_eq!(run(usize::MAX), (true, 2, vec![4587, 4588]));
+        }
+
+        #[test]
+        fn test_callback_stops_early() {
+            setup().expect("setup() failed");
+
+            // the callback returns 0 on the first array: the second one is
+            // never built
+            assert_eq!(run(1), (false, 1, vec![4587]));
+        }
+    }
+}
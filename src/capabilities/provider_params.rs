@@ -0,0 +1,411 @@
+//! Helpers for the parameters exchanged between a provider and the OpenSSL
+//! core.
+//!
+//! The core params made available to a provider (e.g., during
+//! `OSSL_provider_init`) are exposed as a properly END-terminated array of
+//! [`OSSL_PARAM`]s, using the keys re-exported by this module (e.g.,
+//! [`OSSL_PROV_PARAM_CORE_VERSION`]).
+//!
+//! Conversely, the core queries the params of the provider (e.g., its
+//! [status][`OSSL_PROV_PARAM_STATUS`]) through its `get_params` function.
+//!
+//! Refer to [provider-base(7ossl)](https://docs.openssl.org/master/man7/provider-base/#core-functions)
+//!
+//! # Examples
+//!
+//! ```rust
+//! use openssl_provider_forge::capabilities::provider_params::*;
+//! use openssl_provider_forge::osslparams::{CONST_OSSL_PARAM, OSSLParam, OSSL_PARAM};
+//!
+//! let core_params = [
+//!     OSSLParam::new_const_utf8string(OSSL_PROV_PARAM_CORE_VERSION, Some(c"3.5.0")),
+//!     CONST_OSSL_PARAM::END,
+//! ];
+//! let head = core_params.as_ptr() as *const OSSL_PARAM;
+//!
+//! assert_eq!(
+//!     read_core_param_str(head, OSSL_PROV_PARAM_CORE_VERSION).as_deref(),
+//!     Some("3.5.0")
+//! );
+//! assert_eq!(read_core_param_str(head, OSSL_PROV_PARAM_CORE_PROV_NAME), None);
+//! ```
+
+pub use crate::bindings::{
+    OSSL_PROV_PARAM_BUILDINFO, OSSL_PROV_PARAM_CORE_MODULE_FILENAME,
+    OSSL_PROV_PARAM_CORE_PROV_NAME, OSSL_PROV_PARAM_CORE_VERSION, OSSL_PROV_PARAM_NAME,
+    OSSL_PROV_PARAM_STATUS, OSSL_PROV_PARAM_VERSION,
+};
+
+use std::ffi::CStr;
+
+use crate::osslparams::{
+    ossl_param_locate, ossl_param_locate_const, KeyType, OSSLParam, OSSLParamError, OSSL_PARAM,
+};
+
+/// The value of [`OSSL_PROV_PARAM_STATUS`] for a provider which is running.
+pub const STATUS_RUNNING: i32 = 1;
+
+/// The value of [`OSSL_PROV_PARAM_STATUS`] for a provider which is not
+/// running (e.g., after a failed self-test).
+pub const STATUS_NOT_RUNNING: i32 = 0;
+
+/// Returns the value of the string core param with the given `key`, from the
+/// core params starting at `head`.
+///
+/// Both `OSSL_PARAM_UTF8_PTR` (as used by the core) and
+/// `OSSL_PARAM_UTF8_STRING` params are supported.
+///
+/// Returns `None` if `head` is `NULL`, if the param is missing or `NULL`, or
+/// if its value is not valid UTF-8.
+pub fn read_core_param_str(head: *const OSSL_PARAM, key: &KeyType) -> Option<String> {
+    let value: &CStr = ossl_param_locate_const(head, key)?.get()?;
+    value.to_str().ok().map(String::from)
+}
+
+/// Returns the value of the integer core param with the given `key`, from the
+/// core params starting at `head`.
+///
+/// Returns `None` if `head` is `NULL`, or if the param is missing, `NULL`, or
+/// not an `OSSL_PARAM_INTEGER`.
+pub fn read_core_param_int(head: *const OSSL_PARAM, key: &KeyType) -> Option<i64> {
+    ossl_param_locate_const(head, key)?.get::<i64>()
+}
+
+/// Sets the [`OSSL_PROV_PARAM_STATUS`] param requested in the list starting
+/// at `head` (if any) to [`STATUS_RUNNING`] or [`STATUS_NOT_RUNNING`],
+/// according to `running`.
+///
+/// As for any responder, it is not an error if the status was not requested.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::provider_params::*;
+/// use openssl_provider_forge::osslparams::{OSSLParam, OSSL_PARAM, OSSL_PARAM_INTEGER};
+///
+/// let mut status = -1i32;
+/// let mut params = [
+///     OSSL_PARAM {
+///         key: OSSL_PROV_PARAM_STATUS.as_ptr(),
+///         data_type: OSSL_PARAM_INTEGER,
+///         data: std::ptr::from_mut(&mut status).cast(),
+///         data_size: size_of::<i32>(),
+///         return_size: 0,
+///     },
+///     OSSL_PARAM::END,
+/// ];
+///
+/// set_status(params.as_mut_ptr(), true).unwrap();
+/// assert_eq!(get_status(params.as_ptr()), Some(true));
+/// ```
+pub fn set_status(head: *mut OSSL_PARAM, running: bool) -> Result<(), OSSLParamError> {
+    match ossl_param_locate(head, OSSL_PROV_PARAM_STATUS) {
+        Some(mut p) => p.set(if running {
+            STATUS_RUNNING
+        } else {
+            STATUS_NOT_RUNNING
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the value of the [`OSSL_PROV_PARAM_STATUS`] param in the list
+/// starting at `head`, i.e. whether the provider is running.
+///
+/// Any value other than [`STATUS_NOT_RUNNING`] is considered as running.
+/// Returns `None` if the param is missing, `NULL`, or not an integer.
+pub fn get_status(head: *const OSSL_PARAM) -> Option<bool> {
+    read_core_param_int(head, OSSL_PROV_PARAM_STATUS).map(|v| v != i64::from(STATUS_NOT_RUNNING))
+}
+
+/// Answers the `get_params` query of the core, filling in the params
+/// requested in the list starting at `head`:
+///
+/// - [`OSSL_PROV_PARAM_NAME`] with `name`;
+/// - [`OSSL_PROV_PARAM_VERSION`] with `version`;
+/// - [`OSSL_PROV_PARAM_BUILDINFO`] with `buildinfo`;
+/// - [`OSSL_PROV_PARAM_STATUS`] according to `running` (as in [`set_status`]).
+///
+/// Requested params with any other key are left untouched, as are all params
+/// if `head` is `NULL`.
+///
+/// The string params are typically requested as `OSSL_PARAM_UTF8_PTR`, in
+/// which case they are set to point at the given strings: these must
+/// therefore outlive any use of the params by the caller (they usually are
+/// `&'static`).
+///
+/// # Errors
+///
+/// Returns the error of the first param which could not be set (e.g., an
+/// `OSSL_PARAM_UTF8_STRING` buffer too small for the value), leaving the
+/// following params untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::provider_params::*;
+/// use openssl_provider_forge::osslparams::OSSL_PARAM;
+/// use std::ffi::c_void;
+///
+/// // This is the body of the `get_params` function of the provider
+/// unsafe extern "C" fn get_params(_provctx: *mut c_void, params: *mut OSSL_PARAM) -> i32 {
+///     let ret = answer_get_params(
+///         params,
+///         c"Aurora",
+///         c"1.0.0",
+///         c"aurora-1.0.0",
+///         true,
+///     );
+///     i32::from(ret.is_ok())
+/// }
+/// ```
+pub fn answer_get_params(
+    head: *mut OSSL_PARAM,
+    name: &CStr,
+    version: &CStr,
+    buildinfo: &CStr,
+    running: bool,
+) -> Result<(), OSSLParamError> {
+    let Ok(head) = OSSLParam::try_from(head) else {
+        return Ok(());
+    };
+    for mut p in head {
+        let Some(key) = p.get_key() else {
+            continue;
+        };
+        let value = if key == OSSL_PROV_PARAM_NAME {
+            name
+        } else if key == OSSL_PROV_PARAM_VERSION {
+            version
+        } else if key == OSSL_PROV_PARAM_BUILDINFO {
+            buildinfo
+        } else if key == OSSL_PROV_PARAM_STATUS {
+            p.set(if running {
+                STATUS_RUNNING
+            } else {
+                STATUS_NOT_RUNNING
+            })?;
+            continue;
+        } else {
+            continue;
+        };
+        p.set(value as *const CStr)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::{OSSL_PARAM_UTF8_PTR, OSSL_PARAM_UTF8_STRING};
+    use crate::osslparams::{CONST_OSSL_PARAM, OSSL_PARAM_INTEGER, OSSL_PARAM_UNMODIFIED};
+    use crate::tests::common::OurError;
+    use std::ffi::{c_char, c_void};
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    /// Builds a `OSSL_PARAM_UTF8_PTR` param, as the ones passed by the core.
+    fn utf8_ptr_param(key: &'static CStr, value: &*const c_char) -> CONST_OSSL_PARAM {
+        CONST_OSSL_PARAM {
+            key: key.as_ptr(),
+            data_type: OSSL_PARAM_UTF8_PTR,
+            data: std::ptr::from_ref(value) as *mut c_void,
+            data_size: 0,
+            return_size: OSSL_PARAM_UNMODIFIED,
+        }
+    }
+
+    #[test]
+    fn test_read_core_version() {
+        setup().expect("setup() failed");
+
+        let version: *const c_char = c"3.5.0".as_ptr();
+        let prov_name: *const c_char = c"aurora".as_ptr();
+        let core_params = [
+            utf8_ptr_param(OSSL_PROV_PARAM_CORE_VERSION, &version),
+            utf8_ptr_param(OSSL_PROV_PARAM_CORE_PROV_NAME, &prov_name),
+            OSSLParam::new_const_int(c"some-int", Some(&42i32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        let head = core_params.as_ptr() as *const OSSL_PARAM;
+
+        assert_eq!(
+            read_core_param_str(head, OSSL_PROV_PARAM_CORE_VERSION).as_deref(),
+            Some("3.5.0")
+        );
+        assert_eq!(
+            read_core_param_str(head, OSSL_PROV_PARAM_CORE_PROV_NAME).as_deref(),
+            Some("aurora")
+        );
+        assert_eq!(
+            read_core_param_str(head, OSSL_PROV_PARAM_CORE_MODULE_FILENAME),
+            None
+        );
+        assert_eq!(read_core_param_int(head, c"some-int"), Some(42));
+
+        // wrong types
+        assert_eq!(read_core_param_str(head, c"some-int"), None);
+        assert_eq!(
+            read_core_param_int(head, OSSL_PROV_PARAM_CORE_VERSION),
+            None
+        );
+    }
+
+    #[test]
+    fn test_read_core_param_null() {
+        setup().expect("setup() failed");
+
+        assert_eq!(
+            read_core_param_str(std::ptr::null(), OSSL_PROV_PARAM_CORE_VERSION),
+            None
+        );
+        assert_eq!(read_core_param_int(std::ptr::null(), c"some-int"), None);
+    }
+
+    #[test]
+    fn test_set_and_get_status() {
+        setup().expect("setup() failed");
+
+        let mut status = -1i32;
+        let mut other = 42i32;
+        let mut params = [
+            OSSL_PARAM {
+                key: c"other".as_ptr(),
+                data_type: OSSL_PARAM_INTEGER,
+                data: std::ptr::from_mut(&mut other).cast(),
+                data_size: size_of::<i32>(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM {
+                key: OSSL_PROV_PARAM_STATUS.as_ptr(),
+                data_type: OSSL_PARAM_INTEGER,
+                data: std::ptr::from_mut(&mut status).cast(),
+                data_size: size_of::<i32>(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM::END,
+        ];
+
+        assert_eq!(set_status(params.as_mut_ptr(), true), Ok(()));
+        assert_eq!(get_status(params.as_ptr()), Some(true));
+        assert_eq!(params[1].return_size, size_of::<i32>());
+
+        assert_eq!(set_status(params.as_mut_ptr(), false), Ok(()));
+        assert_eq!(get_status(params.as_ptr()), Some(false));
+
+        // the other params are untouched
+        assert_eq!(params[0].return_size, OSSL_PARAM_UNMODIFIED);
+        assert_eq!(other, 42);
+        assert_eq!(status, STATUS_NOT_RUNNING);
+    }
+
+    #[test]
+    fn test_status_not_requested() {
+        setup().expect("setup() failed");
+
+        let mut params = [
+            OSSLParam::new_const_int(c"other", Some(&42i32)),
+            CONST_OSSL_PARAM::END,
+        ];
+
+        let head = params.as_mut_ptr() as *mut OSSL_PARAM;
+        assert_eq!(set_status(head, true), Ok(()));
+        assert_eq!(get_status(head), None);
+        assert_eq!(set_status(std::ptr::null_mut(), true), Ok(()));
+        assert_eq!(get_status(std::ptr::null()), None);
+    }
+
+    #[test]
+    fn test_answer_get_params() {
+        setup().expect("setup() failed");
+
+        let mut name: *const c_char = std::ptr::null();
+        let mut buildinfo = [0u8; 32];
+        let mut status = -1i32;
+        let mut other = 42i32;
+        let mut params = [
+            OSSL_PARAM {
+                key: OSSL_PROV_PARAM_NAME.as_ptr(),
+                data_type: OSSL_PARAM_UTF8_PTR,
+                data: std::ptr::from_mut(&mut name).cast(),
+                data_size: 0,
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM {
+                key: OSSL_PROV_PARAM_BUILDINFO.as_ptr(),
+                data_type: OSSL_PARAM_UTF8_STRING,
+                data: buildinfo.as_mut_ptr().cast(),
+                data_size: buildinfo.len(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM {
+                key: c"other".as_ptr(),
+                data_type: OSSL_PARAM_INTEGER,
+                data: std::ptr::from_mut(&mut other).cast(),
+                data_size: size_of::<i32>(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM {
+                key: OSSL_PROV_PARAM_STATUS.as_ptr(),
+                data_type: OSSL_PARAM_INTEGER,
+                data: std::ptr::from_mut(&mut status).cast(),
+                data_size: size_of::<i32>(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM::END,
+        ];
+
+        let ret = answer_get_params(
+            params.as_mut_ptr(),
+            c"aurora",
+            c"1.2.3",
+            c"aurora-1.2.3 (test)",
+            true,
+        );
+        assert_eq!(ret, Ok(()));
+
+        // the version was not requested
+        let head = params.as_ptr();
+        assert_eq!(
+            read_core_param_str(head, OSSL_PROV_PARAM_NAME).as_deref(),
+            Some("aurora")
+        );
+        assert_eq!(
+            read_core_param_str(head, OSSL_PROV_PARAM_BUILDINFO).as_deref(),
+            Some("aurora-1.2.3 (test)")
+        );
+        assert_eq!(get_status(head), Some(true));
+
+        // unknown keys are ignored
+        assert_eq!(params[2].return_size, OSSL_PARAM_UNMODIFIED);
+        assert_eq!(other, 42);
+        assert_eq!(status, STATUS_RUNNING);
+
+        assert_eq!(
+            answer_get_params(std::ptr::null_mut(), c"a", c"b", c"c", false),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_answer_get_params_error() {
+        setup().expect("setup() failed");
+
+        let mut version = [0u8; 2];
+        let mut params = [
+            OSSL_PARAM {
+                key: OSSL_PROV_PARAM_VERSION.as_ptr(),
+                data_type: OSSL_PARAM_UTF8_STRING,
+                data: version.as_mut_ptr().cast(),
+                data_size: version.len(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM::END,
+        ];
+
+        let ret = answer_get_params(params.as_mut_ptr(), c"a", c"1.2.3", c"c", true);
+        assert!(ret.is_err());
+    }
+}
@@ -0,0 +1,221 @@
+//! A registry of the capabilities offered by a provider, and the
+//! `get_capabilities` provider function built on top of it.
+//!
+//! Refer to [provider-base(7ossl)](https://docs.openssl.org/master/man7/provider-base/#capabilities)
+//!
+//! # Examples
+//!
+//! ```rust
+//! use openssl_provider_forge::capabilities::{get_capabilities_handler, tls_group, Registry};
+//! use openssl_provider_forge::bindings::OSSL_CALLBACK;
+//! use std::ffi::{c_char, c_int, c_void, CStr};
+//! use std::sync::LazyLock;
+//!
+//! # pub struct X25519MLKEM768Group;
+//! # impl tls_group::TLSGroup for X25519MLKEM768Group {
+//! #     const IANA_GROUP_NAME: &'static CStr = c"X25519MLKEM768";
+//! #     const IANA_GROUP_ID: u32 = 4588;
+//! #     const GROUP_NAME_INTERNAL: &'static CStr = c"X25519MLKEM768";
+//! #     const GROUP_ALG: &'static CStr = c"X25519MLKEM768";
+//! #     const SECURITY_BITS: u32 = 192;
+//! #     const MIN_TLS: tls_group::TLSVersion = tls_group::TLSVersion::TLSv1_3;
+//! #     const MAX_TLS: tls_group::TLSVersion = tls_group::TLSVersion::None;
+//! #     const IS_KEM: bool = true;
+//! # }
+//! static REGISTRY: LazyLock<Registry> = LazyLock::new(|| {
+//!     let mut registry = Registry::new();
+//!     registry.register(
+//!         tls_group::CAPABILITY_NAME,
+//!         tls_group::as_params!(X25519MLKEM768Group),
+//!     );
+//!     registry
+//! });
+//!
+//! // This is the function to add to the provider dispatch table
+//! // (`OSSL_FUNC_PROVIDER_GET_CAPABILITIES`)
+//! unsafe extern "C" fn get_capabilities(
+//!     _provctx: *mut c_void,
+//!     capability: *const c_char,
+//!     cb: OSSL_CALLBACK,
+//!     arg: *mut c_void,
+//! ) -> c_int {
+//!     if capability.is_null() {
+//!         return 0;
+//!     }
+//!     let capability = unsafe { CStr::from_ptr(capability) };
+//!     get_capabilities_handler(capability, cb, arg, &REGISTRY)
+//! }
+//! ```
+
+use std::ffi::{c_int, c_void, CStr};
+
+use crate::bindings::OSSL_CALLBACK;
+use crate::ossl_callback::OSSLCallback;
+use crate::osslparams::{CONST_OSSL_PARAM, OSSL_PARAM};
+
+/// The params arrays describing the capabilities of a provider, grouped by
+/// capability name (e.g., [`tls_group::CAPABILITY_NAME`][`super::tls_group::CAPABILITY_NAME`]).
+#[derive(Debug, Default)]
+pub struct Registry {
+    entries: Vec<(&'static CStr, Vec<&'static [CONST_OSSL_PARAM]>)>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an END-terminated params array (e.g., as returned by
+    /// [`tls_group::as_params`][`super::tls_group::as_params`]) under the
+    /// `capability` name.
+    ///
+    /// The arrays of each capability are reported in registration order.
+    pub fn register(
+        &mut self,
+        capability: &'static CStr,
+        params: &'static [CONST_OSSL_PARAM],
+    ) -> &mut Self {
+        debug_assert!(
+            params.last().is_some_and(|p| p.key.is_null()),
+            "capability params must be END-terminated"
+        );
+        match self
+            .entries
+            .iter_mut()
+            .find(|(name, _)| *name == capability)
+        {
+            Some((_, arrays)) => arrays.push(params),
+            None => self.entries.push((capability, vec![params])),
+        }
+        self
+    }
+
+    /// Returns the params arrays registered under the `capability` name
+    /// (compared case-insensitively, as OpenSSL does), if any.
+    pub fn get(&self, capability: &CStr) -> Option<&[&'static [CONST_OSSL_PARAM]]> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name.to_bytes().eq_ignore_ascii_case(capability.to_bytes()))
+            .map(|(_, arrays)| arrays.as_slice())
+    }
+}
+
+/// Implements the `get_capabilities` provider function on top of `registry`.
+///
+/// Each params array registered under the `capability` name is passed to the
+/// core callback `cb` (along with `arg`), stopping as soon as the callback
+/// returns `0`.
+///
+/// # Return value
+///
+/// Following the OpenSSL conventions, returns `1` if every array has been
+/// reported, and `0` if `cb` is `NULL`, if `capability` is unknown, or if the
+/// callback stopped the iteration.
+pub fn get_capabilities_handler(
+    capability: &CStr,
+    cb: OSSL_CALLBACK,
+    arg: *mut c_void,
+    registry: &Registry,
+) -> c_int {
+    const ERROR_RET: c_int = 0;
+
+    let callback = match OSSLCallback::try_new(cb, arg) {
+        Ok(callback) => callback,
+        Err(e) => {
+            log::error!("Invalid get_capabilities callback: {e:?}");
+            return ERROR_RET;
+        }
+    };
+    let Some(arrays) = registry.get(capability) else {
+        log::debug!("Unknown capability {capability:?}");
+        return ERROR_RET;
+    };
+    for params in arrays {
+        if callback
+            .call_checked(params.as_ptr() as *const OSSL_PARAM)
+            .is_err()
+        {
+            log::debug!("The callback stopped the {capability:?} capability iteration");
+            return ERROR_RET;
+        }
+    }
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::osslparams::OSSLParam;
+    use crate::tests::common::{seen_ids_callback, OurError, SeenIds};
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    const GROUP_A: &[CONST_OSSL_PARAM] = &[
+        OSSLParam::new_const_uint(c"id", Some(&1u32)),
+        CONST_OSSL_PARAM::END,
+    ];
+    const GROUP_B: &[CONST_OSSL_PARAM] = &[
+        OSSLParam::new_const_uint(c"id", Some(&2u32)),
+        CONST_OSSL_PARAM::END,
+    ];
+    const SIGALG: &[CONST_OSSL_PARAM] = &[
+        OSSLParam::new_const_uint(c"id", Some(&3u32)),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry
+            .register(c"TLS-GROUP", GROUP_A)
+            .register(c"TLS-SIGALG", SIGALG)
+            .register(c"TLS-GROUP", GROUP_B);
+        registry
+    }
+
+    fn run(capability: &CStr, accept: usize) -> (c_int, Vec<u64>) {
+        let mut seen = SeenIds::new(accept);
+        let ret = get_capabilities_handler(
+            capability,
+            Some(seen_ids_callback),
+            seen.as_arg(),
+            &registry(),
+        );
+        (ret, seen.ids)
+    }
+
+    #[test]
+    fn test_known_capabilities() {
+        setup().expect("setup() failed");
+
+        assert_eq!(run(c"TLS-GROUP", usize::MAX), (1, vec![1, 2]));
+        assert_eq!(run(c"TLS-SIGALG", usize::MAX), (1, vec![3]));
+        // capability names are case-insensitive
+        assert_eq!(run(c"tls-group", usize::MAX), (1, vec![1, 2]));
+    }
+
+    #[test]
+    fn test_unknown_capability() {
+        setup().expect("setup() failed");
+
+        assert_eq!(run(c"TLS-UNKNOWN", usize::MAX), (0, vec![]));
+        assert_eq!(run(c"", usize::MAX), (0, vec![]));
+    }
+
+    #[test]
+    fn test_callback_stop() {
+        setup().expect("setup() failed");
+
+        assert_eq!(run(c"TLS-GROUP", 1), (0, vec![1]));
+    }
+
+    #[test]
+    fn test_null_callback() {
+        setup().expect("setup() failed");
+
+        let ret = get_capabilities_handler(c"TLS-GROUP", None, std::ptr::null_mut(), &registry());
+        assert_eq!(ret, 0);
+    }
+}
@@ -21,7 +21,7 @@
 //!
 //! impl TLSGroup for X25519MLKEM768Group {
 //!     const IANA_GROUP_NAME: &'static CStr = c"X25519MLKEM768";
-//!     const IANA_GROUP_ID: u32 = 0x4588;
+//!     const IANA_GROUP_ID: u32 = 0x11EC;
 //!     const GROUP_NAME_INTERNAL: &'static CStr = c"X25519MLKEM768";
 //!     const GROUP_ALG: &'static CStr = c"X25519MLKEM768";
 //!     const SECURITY_BITS: u32 = 192;
@@ -48,11 +48,19 @@ pub use crate::bindings::{
     OSSL_CAPABILITY_TLS_GROUP_SECURITY_BITS,
 };
 
-pub use super::{DTLSVersion, TLSVersion};
+pub use super::{DTLSVersion, FieldDependency, TLSVersion};
+
+use crate::osslparams::{ossl_param_locate_const, CONST_OSSL_PARAM, OSSL_PARAM};
+use crate::OurError;
+use anyhow::anyhow;
 
 #[cfg(doc)]
 use crate::osslparams::*;
 
+/// The name of the "TLS-GROUP" capability, as passed to the provider's
+/// `get_capabilities` function (see [`Registry`][`super::Registry`]).
+pub const CAPABILITY_NAME: &CStr = c"TLS-GROUP";
+
 /// The "TLS-GROUP" capability can be queried by `libssl` to discover the list of
 /// TLS groups that a provider can support.
 ///
@@ -97,6 +105,103 @@ pub trait TLSGroup {
     const IS_KEM: bool = false;
 }
 
+/// The dependencies among the fields of [`TLSGroup`], checked by
+/// [`as_params`] at compile time (see
+/// [`field_dependencies_hold()`][`super::field_dependencies_hold`]).
+///
+/// The bounds of the supported (D)TLS versions are always given in pairs.
+/// As all the fields of [`TLSGroup`] are mandatory, these always hold for the
+/// params generated by [`as_params`]: they are mostly meant for params built
+/// otherwise, to be checked with
+/// [`validate_field_dependencies()`][`super::validate_field_dependencies`].
+pub const FIELD_DEPENDENCIES: &[FieldDependency] = &[
+    FieldDependency::AllOrNone(&[
+        OSSL_CAPABILITY_TLS_GROUP_MIN_TLS,
+        OSSL_CAPABILITY_TLS_GROUP_MAX_TLS,
+    ]),
+    FieldDependency::AllOrNone(&[
+        OSSL_CAPABILITY_TLS_GROUP_MIN_DTLS,
+        OSSL_CAPABILITY_TLS_GROUP_MAX_DTLS,
+    ]),
+];
+
+#[doc(hidden)]
+/// An internal macro generating the params of [`as_params`] as a `const`
+/// expression, shared by [`as_params`] and [`groups_as_params`].
+#[macro_export]
+macro_rules! __hidden__tls_group_params_array {
+    ($group_type:ty) => {{
+        use $crate::osslparams::*;
+        use $crate::capabilities::tls_group::*;
+
+        // This static assertion will cause a compile error if $group_type doesn't implement TLSGroup
+        const _: fn() = || {
+            // This function is never called, it only exists for type checking
+            fn assert_implements_tls_group<T: TLSGroup>() {}
+            assert_implements_tls_group::<$group_type>()
+        };
+
+        // Convert bool to const u32
+        const IS_KEM_AS_UINT: u32 = if <$group_type>::IS_KEM { 1 } else { 0 };
+
+        // Convert to const i32
+        const MIN_TLS: i32 = <$group_type>::MIN_TLS as i32;
+        const MAX_TLS: i32 = <$group_type>::MAX_TLS as i32;
+        const MIN_DTLS: i32 = <$group_type>::MIN_DTLS as i32;
+        const MAX_DTLS: i32 = <$group_type>::MAX_DTLS as i32;
+
+        // Now create the parameter list
+        const OSSL_PARAM_ARRAY: &[CONST_OSSL_PARAM] = &$crate::ossl_params![
+            // IANA group name
+            OSSLParam::new_const_utf8string(
+                OSSL_CAPABILITY_TLS_GROUP_NAME,
+                Some(<$group_type>::IANA_GROUP_NAME)
+            ),
+            // group name according to the provider
+            OSSLParam::new_const_utf8string(
+                OSSL_CAPABILITY_TLS_GROUP_NAME_INTERNAL,
+                Some(<$group_type>::GROUP_NAME_INTERNAL),
+            ),
+            // keymgmt algorithm name
+            OSSLParam::new_const_utf8string(OSSL_CAPABILITY_TLS_GROUP_ALG, Some(<$group_type>::GROUP_ALG)),
+            // IANA group ID
+            OSSLParam::new_const_uint(OSSL_CAPABILITY_TLS_GROUP_ID, Some(&<$group_type>::IANA_GROUP_ID)),
+            // number of bits of security
+            OSSLParam::new_const_uint(
+                OSSL_CAPABILITY_TLS_GROUP_SECURITY_BITS,
+                Some(&<$group_type>::SECURITY_BITS),
+            ),
+            // min TLS version
+            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MIN_TLS, Some(&MIN_TLS)),
+            // min TLS version
+            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MAX_TLS, Some(&MAX_TLS)),
+            // min DTLS
+            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MIN_DTLS, Some(&MIN_DTLS)),
+            // max DTLS
+            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MAX_DTLS, Some(&MAX_DTLS)),
+            // is KEM
+            OSSLParam::new_const_uint(OSSL_CAPABILITY_TLS_GROUP_IS_KEM, Some(&IS_KEM_AS_UINT)),
+        ];
+        const _: () = assert!(
+            !has_duplicate_keys(OSSL_PARAM_ARRAY, None),
+            "duplicate keys in the TLS-GROUP capability params",
+        );
+        const _: () = assert!(
+            $crate::capabilities::field_dependencies_hold(
+                OSSL_PARAM_ARRAY,
+                $crate::capabilities::tls_group::FIELD_DEPENDENCIES,
+            ),
+            "the TLS-GROUP capability params violate the FIELD_DEPENDENCIES",
+        );
+        OSSL_PARAM_ARRAY
+    }};
+}
+
+/// An internal macro generating the params of [`as_params`] as a `const`
+/// expression.
+#[doc(hidden)]
+pub use __hidden__tls_group_params_array as group_params_array;
+
 /// Converts a type implementing [`TLSGroup`] into an OpenSSL parameter array.
 ///
 /// This macro generates a constant array of [`CONST_OSSL_PARAM`] values that represent
@@ -151,63 +256,786 @@ pub trait TLSGroup {
 ///
 /// The generated parameter array is properly terminated with a
 /// [`CONST_OSSL_PARAM::END`] marker as required by OpenSSL.
+///
+/// This is a `const` expression, so it can initialize a `static` (or
+/// `const`) item. The uniqueness of the keys of the array, and the
+/// [`FIELD_DEPENDENCIES`], are checked at compile time.
 #[macro_export]
 macro_rules! capability_tls_group_as_params {
     ($group_type:ty) => {{
-        use $crate::osslparams::*;
-        use $crate::capabilities::tls_group::*;
+        const OSSL_PARAM_ARRAY: &[$crate::osslparams::CONST_OSSL_PARAM] =
+            $crate::capabilities::tls_group::group_params_array!($group_type);
+        OSSL_PARAM_ARRAY
+    }};
+}
+pub use capability_tls_group_as_params as as_params;
 
-        // This static assertion will cause a compile error if $group_type doesn't implement TLSGroup
-        const _: fn() = || {
-            // This function is never called, it only exists for type checking
-            fn assert_implements_tls_group<T: TLSGroup>() {}
-            assert_implements_tls_group::<$group_type>()
-        };
+/// Converts a list of types implementing [`TLSGroup`] into a single OpenSSL
+/// parameter array, built at compile time.
+///
+/// The result is the concatenation of the arrays generated by [`as_params`]
+/// for each group, in order: each group is therefore terminated by its own
+/// [`CONST_OSSL_PARAM::END`] item.
+/// Like [`as_params`], this is a `const` expression, so it can initialize
+/// a `static` (or `const`) item; since the array is `'static` and immutable,
+/// it can be shared by all threads without any synchronization.
+///
+/// As the core expects one params array per group, split it on the END items
+/// (e.g., with [`slice::split_inclusive`]) before reporting the groups.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::tls_group;
+/// use openssl_provider_forge::osslparams::{CONST_OSSL_PARAM, OSSL_PARAM};
+/// use tls_group::*;
+///
+/// pub struct X25519Group;
+///
+/// impl TLSGroup for X25519Group {
+///     const IANA_GROUP_NAME: &'static CStr = c"x25519";
+///     const IANA_GROUP_ID: u32 = 29;
+///     const GROUP_NAME_INTERNAL: &'static CStr = c"X25519";
+///     const GROUP_ALG: &'static CStr = c"X25519";
+///     const SECURITY_BITS: u32 = 128;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+/// }
+///
+/// pub struct X448Group;
+///
+/// impl TLSGroup for X448Group {
+///     const IANA_GROUP_NAME: &'static CStr = c"x448";
+///     const IANA_GROUP_ID: u32 = 30;
+///     const GROUP_NAME_INTERNAL: &'static CStr = c"X448";
+///     const GROUP_ALG: &'static CStr = c"X448";
+///     const SECURITY_BITS: u32 = 192;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+/// }
+///
+/// static GROUPS: &[CONST_OSSL_PARAM] = tls_group::groups_as_params!(X25519Group, X448Group);
+///
+/// let ids: Vec<_> = GROUPS
+///     .split_inclusive(|p| p.key.is_null())
+///     .map(|group| tls_group::group_id(group.as_ptr() as *const OSSL_PARAM))
+///     .collect();
+/// assert_eq!(ids, [Some(29), Some(30)]);
+/// ```
+#[macro_export]
+macro_rules! capability_tls_groups_as_params {
+    ($($group_type:ty),+ $(,)?) => {{
+        const GROUPS: &[&[$crate::osslparams::CONST_OSSL_PARAM]] = &[
+            $($crate::capabilities::tls_group::group_params_array!($group_type)),+
+        ];
+        const LEN: usize = $crate::capabilities::concat_params_len(GROUPS);
+        const OSSL_PARAM_ARRAY: &[$crate::osslparams::CONST_OSSL_PARAM] =
+            &$crate::capabilities::concat_params::<LEN>(GROUPS);
+        OSSL_PARAM_ARRAY
+    }};
+}
+pub use capability_tls_groups_as_params as groups_as_params;
 
-        // Convert bool to const u32
-        const IS_KEM_AS_UINT: u32 = if <$group_type>::IS_KEM { 1 } else { 0 };
+/// Defines a Rust enum of the TLS groups supported by a provider, together
+/// with a [`TLSGroup`] type for each of its variants.
+///
+/// Each variant is declared as `Variant => GroupType { ... }`, where the body
+/// is the [`TLSGroup`] implementation of `GroupType` (i.e., its associated
+/// constants).
+/// Besides the group types and the enum (which derives `Debug`, `Clone`,
+/// `Copy`, `PartialEq`, `Eq` and `Hash`), this generates the following
+/// associated items of the enum:
+///
+/// * `ALL`: all the variants, in declaration order;
+/// * `from_codepoint(u32) -> Option<Self>`: the variant with the given
+///   [`TLSGroup::IANA_GROUP_ID`], if any;
+/// * `codepoint(self) -> u32`: the [`TLSGroup::IANA_GROUP_ID`] of the variant;
+/// * `iana_name(self) -> &'static CStr`: its [`TLSGroup::IANA_GROUP_NAME`];
+/// * `internal_name(self) -> &'static CStr`: its
+///   [`TLSGroup::GROUP_NAME_INTERNAL`];
+/// * `as_params(self) -> &'static [CONST_OSSL_PARAM]`: the params generated by
+///   [`as_params`] for its group type.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::tls_group;
+/// use tls_group::*;
+///
+/// tls_group::group_enum! {
+///     pub enum Groups {
+///         X25519MLKEM768 => X25519MLKEM768Group {
+///             const IANA_GROUP_NAME: &'static CStr = c"X25519MLKEM768";
+///             const IANA_GROUP_ID: u32 = 4588;
+///             const GROUP_NAME_INTERNAL: &'static CStr = c"X25519MLKEM768";
+///             const GROUP_ALG: &'static CStr = c"X25519MLKEM768";
+///             const SECURITY_BITS: u32 = 192;
+///             const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+///             const IS_KEM: bool = true;
+///         },
+///         X25519 => X25519Group {
+///             const IANA_GROUP_NAME: &'static CStr = c"x25519";
+///             const IANA_GROUP_ID: u32 = 29;
+///             const GROUP_NAME_INTERNAL: &'static CStr = c"X25519";
+///             const GROUP_ALG: &'static CStr = c"X25519";
+///             const SECURITY_BITS: u32 = 128;
+///             const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+///         },
+///     }
+/// }
+///
+/// assert_eq!(Groups::from_codepoint(29), Some(Groups::X25519));
+/// assert_eq!(Groups::X25519MLKEM768.internal_name(), c"X25519MLKEM768");
+/// for group in Groups::ALL {
+///     // e.g., register `group.as_params()` in a capabilities `Registry`
+///     assert!(!group.as_params().is_empty());
+/// }
+/// ```
+#[macro_export]
+macro_rules! capability_tls_group_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $group_type:ident { $($group_body:tt)* }
+            ),* $(,)?
+        }
+    ) => {
+        $(
+            $(#[$variant_meta])*
+            $vis struct $group_type;
 
-        // Convert to const i32
-        const MIN_TLS: i32 = <$group_type>::MIN_TLS as i32;
-        const MAX_TLS: i32 = <$group_type>::MAX_TLS as i32;
-        const MIN_DTLS: i32 = <$group_type>::MIN_DTLS as i32;
-        const MAX_DTLS: i32 = <$group_type>::MAX_DTLS as i32;
+            impl $crate::capabilities::tls_group::TLSGroup for $group_type {
+                $($group_body)*
+            }
+        )*
 
-        // Now create the parameter list
-        const OSSL_PARAM_ARRAY: &[CONST_OSSL_PARAM] = &[
-            // IANA group name
-            OSSLParam::new_const_utf8string(
-                OSSL_CAPABILITY_TLS_GROUP_NAME,
-                Some(<$group_type>::IANA_GROUP_NAME)
-            ),
-            // group name according to the provider
-            OSSLParam::new_const_utf8string(
-                OSSL_CAPABILITY_TLS_GROUP_NAME_INTERNAL,
-                Some(<$group_type>::GROUP_NAME_INTERNAL),
-            ),
-            // keymgmt algorithm name
-            OSSLParam::new_const_utf8string(OSSL_CAPABILITY_TLS_GROUP_ALG, Some(<$group_type>::GROUP_ALG)),
-            // IANA group ID
-            OSSLParam::new_const_uint(OSSL_CAPABILITY_TLS_GROUP_ID, Some(&<$group_type>::IANA_GROUP_ID)),
-            // number of bits of security
-            OSSLParam::new_const_uint(
-                OSSL_CAPABILITY_TLS_GROUP_SECURITY_BITS,
-                Some(&<$group_type>::SECURITY_BITS),
-            ),
-            // min TLS version
-            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MIN_TLS, Some(&MIN_TLS)),
-            // min TLS version
-            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MAX_TLS, Some(&MAX_TLS)),
-            // min DTLS
-            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MIN_DTLS, Some(&MIN_DTLS)),
-            // max DTLS
-            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MAX_DTLS, Some(&MAX_DTLS)),
-            // is KEM
-            OSSLParam::new_const_uint(OSSL_CAPABILITY_TLS_GROUP_IS_KEM, Some(&IS_KEM_AS_UINT)),
-            // IMPORTANT: always terminate a params array!!!
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// All the TLS groups, in declaration order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Returns the TLS group with the given IANA codepoint, if any.
+            pub fn from_codepoint(codepoint: u32) -> Option<Self> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|group| group.codepoint() == codepoint)
+            }
+
+            /// Returns the IANA codepoint of the TLS group.
+            pub const fn codepoint(self) -> u32 {
+                match self {
+                    $(Self::$variant => <$group_type as $crate::capabilities::tls_group::TLSGroup>::IANA_GROUP_ID,)*
+                }
+            }
+
+            /// Returns the IANA name of the TLS group.
+            pub const fn iana_name(self) -> &'static ::std::ffi::CStr {
+                match self {
+                    $(Self::$variant => <$group_type as $crate::capabilities::tls_group::TLSGroup>::IANA_GROUP_NAME,)*
+                }
+            }
+
+            /// Returns the name of the TLS group according to the provider.
+            pub const fn internal_name(self) -> &'static ::std::ffi::CStr {
+                match self {
+                    $(Self::$variant => <$group_type as $crate::capabilities::tls_group::TLSGroup>::GROUP_NAME_INTERNAL,)*
+                }
+            }
+
+            /// Returns the "TLS-GROUP" capability params of the TLS group.
+            pub fn as_params(self) -> &'static [$crate::osslparams::CONST_OSSL_PARAM] {
+                match self {
+                    $(Self::$variant => $crate::capability_tls_group_as_params!($group_type),)*
+                }
+            }
+        }
+    };
+}
+pub use capability_tls_group_enum as group_enum;
+
+/// Reads back the [`TLSGroup::IS_KEM`] flag from a TLS group params array,
+/// such as the one generated by [`as_params`].
+///
+/// `head` must point to the first item of a properly END-terminated list of
+/// [`OSSL_PARAM`]s.
+///
+/// # Return value
+///
+/// * `Some(is_kem)` if the list contains an [`OSSL_CAPABILITY_TLS_GROUP_IS_KEM`]
+///   param with an integer value (any non-zero value is considered `true`);
+/// * `None` if `head` is `NULL`, or the param is missing or cannot be decoded.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::tls_group;
+/// use openssl_provider_forge::osslparams::OSSL_PARAM;
+/// use tls_group::*;
+///
+/// pub struct X25519Group;
+///
+/// impl TLSGroup for X25519Group {
+///     const IANA_GROUP_NAME: &'static CStr = c"x25519";
+///     const IANA_GROUP_ID: u32 = 29;
+///     const GROUP_NAME_INTERNAL: &'static CStr = c"X25519";
+///     const GROUP_ALG: &'static CStr = c"X25519";
+///     const SECURITY_BITS: u32 = 128;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+/// }
+///
+/// let params = tls_group::as_params!(X25519Group);
+/// assert_eq!(tls_group::is_kem(params.as_ptr() as *const OSSL_PARAM), Some(false));
+/// ```
+pub fn is_kem(head: *const OSSL_PARAM) -> Option<bool> {
+    let p = ossl_param_locate_const(head, OSSL_CAPABILITY_TLS_GROUP_IS_KEM)?;
+    p.get::<u64>()
+        .map(|v| v != 0)
+        .or_else(|| p.get::<i64>().map(|v| v != 0))
+}
+
+/// Reads back the [`TLSGroup::IANA_GROUP_ID`] from a TLS group params array,
+/// such as the one generated by [`as_params`].
+///
+/// `head` must point to the first item of a properly END-terminated list of
+/// [`OSSL_PARAM`]s.
+///
+/// Returns `None` if `head` is `NULL`, or if the
+/// [`OSSL_CAPABILITY_TLS_GROUP_ID`] param is missing or cannot be decoded as
+/// a `u32`.
+pub fn group_id(head: *const OSSL_PARAM) -> Option<u32> {
+    let p = ossl_param_locate_const(head, OSSL_CAPABILITY_TLS_GROUP_ID)?;
+    p.get::<u64>().and_then(|v| u32::try_from(v).ok())
+}
+
+/// Reads back the [`TLSGroup::SECURITY_BITS`] from a TLS group params array,
+/// such as the one generated by [`as_params`].
+///
+/// `head` must point to the first item of a properly END-terminated list of
+/// [`OSSL_PARAM`]s.
+///
+/// Returns `None` if `head` is `NULL`, or if the
+/// [`OSSL_CAPABILITY_TLS_GROUP_SECURITY_BITS`] param is missing or cannot be
+/// decoded as a `u32`.
+pub fn security_bits(head: *const OSSL_PARAM) -> Option<u32> {
+    let p = ossl_param_locate_const(head, OSSL_CAPABILITY_TLS_GROUP_SECURITY_BITS)?;
+    p.get::<u64>().and_then(|v| u32::try_from(v).ok())
+}
+
+/// Reads back the [`TLSGroup::IANA_GROUP_NAME`] from a TLS group params array,
+/// such as the one generated by [`as_params`].
+///
+/// `head` must point to the first item of a properly END-terminated list of
+/// [`OSSL_PARAM`]s.
+///
+/// Returns `None` if `head` is `NULL`, or if the
+/// [`OSSL_CAPABILITY_TLS_GROUP_NAME`] param is missing or is not a UTF-8 string.
+///
+/// The name is copied, so that the returned value does not borrow from the
+/// params array.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::tls_group;
+/// use openssl_provider_forge::osslparams::OSSL_PARAM;
+/// use tls_group::*;
+///
+/// pub struct X25519Group;
+///
+/// impl TLSGroup for X25519Group {
+///     const IANA_GROUP_NAME: &'static CStr = c"x25519";
+///     const IANA_GROUP_ID: u32 = 29;
+///     const GROUP_NAME_INTERNAL: &'static CStr = c"X25519";
+///     const GROUP_ALG: &'static CStr = c"X25519";
+///     const SECURITY_BITS: u32 = 128;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+/// }
+///
+/// let params = tls_group::as_params!(X25519Group);
+/// let head = params.as_ptr() as *const OSSL_PARAM;
+/// assert_eq!(tls_group::iana_name(head).as_deref(), Some(c"x25519"));
+/// assert_eq!(tls_group::group_id(head), Some(29));
+/// assert_eq!(tls_group::security_bits(head), Some(128));
+/// ```
+pub fn iana_name(head: *const OSSL_PARAM) -> Option<std::ffi::CString> {
+    let p = ossl_param_locate_const(head, OSSL_CAPABILITY_TLS_GROUP_NAME)?;
+    p.get::<&CStr>().map(CStr::to_owned)
+}
+
+/// A TLS group decoded from its params array, such as the one generated by
+/// [`as_params`], with one field for each of the [`TLSGroup`] constants.
+///
+/// See [`parse`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedTLSGroup {
+    /// The [`TLSGroup::IANA_GROUP_NAME`].
+    pub iana_name: std::ffi::CString,
+    /// The [`TLSGroup::GROUP_NAME_INTERNAL`].
+    pub group_name_internal: std::ffi::CString,
+    /// The [`TLSGroup::GROUP_ALG`].
+    pub group_alg: std::ffi::CString,
+    /// The [`TLSGroup::IANA_GROUP_ID`].
+    pub group_id: u32,
+    /// The [`TLSGroup::SECURITY_BITS`].
+    pub security_bits: u32,
+    /// The [`TLSGroup::MIN_TLS`].
+    pub min_tls: TLSVersion,
+    /// The [`TLSGroup::MAX_TLS`].
+    pub max_tls: TLSVersion,
+    /// The [`TLSGroup::MIN_DTLS`].
+    pub min_dtls: DTLSVersion,
+    /// The [`TLSGroup::MAX_DTLS`].
+    pub max_dtls: DTLSVersion,
+    /// The [`TLSGroup::IS_KEM`] flag.
+    pub is_kem: bool,
+}
+
+fn utf8_field(head: *const OSSL_PARAM, key: &CStr) -> Option<std::ffi::CString> {
+    let p = ossl_param_locate_const(head, key)?;
+    p.get::<&CStr>().map(CStr::to_owned)
+}
+
+fn version_field(head: *const OSSL_PARAM, key: &CStr) -> Option<i32> {
+    let p = ossl_param_locate_const(head, key)?;
+    p.get::<i32>()
+}
+
+/// Decodes all the fields of a TLS group params array, such as the one
+/// generated by [`as_params`], i.e. it is the inverse of [`as_params`].
+///
+/// `head` must point to the first item of a properly END-terminated list of
+/// [`OSSL_PARAM`]s.
+///
+/// Returns `None` if `head` is `NULL`, or if any of the TLS group params is
+/// missing or cannot be decoded (including versions which are not a known
+/// [`TLSVersion`] or [`DTLSVersion`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::tls_group;
+/// use openssl_provider_forge::osslparams::OSSL_PARAM;
+/// use tls_group::*;
+///
+/// pub struct X25519MLKEM768Group;
+///
+/// impl TLSGroup for X25519MLKEM768Group {
+///     const IANA_GROUP_NAME: &'static CStr = c"X25519MLKEM768";
+///     const IANA_GROUP_ID: u32 = 0x11EC;
+///     const GROUP_NAME_INTERNAL: &'static CStr = c"X25519MLKEM768";
+///     const GROUP_ALG: &'static CStr = c"X25519MLKEM768";
+///     const SECURITY_BITS: u32 = 192;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+///     const IS_KEM: bool = true;
+/// }
+///
+/// let params = tls_group::as_params!(X25519MLKEM768Group);
+/// let group = tls_group::parse(params.as_ptr() as *const OSSL_PARAM).unwrap();
+/// assert_eq!(group.iana_name.as_c_str(), c"X25519MLKEM768");
+/// assert_eq!(group.group_id, 0x11EC);
+/// assert_eq!(group.min_tls, TLSVersion::TLSv1_3);
+/// assert_eq!(group.max_dtls, DTLSVersion::Disabled);
+/// assert!(group.is_kem);
+/// ```
+pub fn parse(head: *const OSSL_PARAM) -> Option<ParsedTLSGroup> {
+    Some(ParsedTLSGroup {
+        iana_name: iana_name(head)?,
+        group_name_internal: utf8_field(head, OSSL_CAPABILITY_TLS_GROUP_NAME_INTERNAL)?,
+        group_alg: utf8_field(head, OSSL_CAPABILITY_TLS_GROUP_ALG)?,
+        group_id: group_id(head)?,
+        security_bits: security_bits(head)?,
+        min_tls: TLSVersion::try_from(version_field(head, OSSL_CAPABILITY_TLS_GROUP_MIN_TLS)?)
+            .ok()?,
+        max_tls: TLSVersion::try_from(version_field(head, OSSL_CAPABILITY_TLS_GROUP_MAX_TLS)?)
+            .ok()?,
+        min_dtls: DTLSVersion::try_from(version_field(head, OSSL_CAPABILITY_TLS_GROUP_MIN_DTLS)?)
+            .ok()?,
+        max_dtls: DTLSVersion::try_from(version_field(head, OSSL_CAPABILITY_TLS_GROUP_MAX_DTLS)?)
+            .ok()?,
+        is_kem: is_kem(head)?,
+    })
+}
+
+/// Checks that the [`TLSGroup::GROUP_ALG`] of a TLS group params array (such
+/// as the one generated by [`as_params`]) is among the keymgmt algorithms
+/// registered by the provider.
+///
+/// Each item of `registered_algs` is the `algorithm_names` of a keymgmt
+/// [`OSSL_ALGORITHM`][`crate::bindings::OSSL_ALGORITHM`], i.e. a
+/// colon-separated list of names, which are compared case-insensitively (as
+/// OpenSSL does).
+///
+/// Otherwise `libssl` fails to fetch the keymgmt of the group during the
+/// handshake, with no clear indication of the reason.
+///
+/// # Errors
+///
+/// Returns an error if `group_params` is not END-terminated, if it has no
+/// [`OSSL_CAPABILITY_TLS_GROUP_ALG`] string param, or if no registered
+/// algorithm has that name.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::{tls_group, validate_group_alg};
+/// use tls_group::*;
+///
+/// pub struct X25519Group;
+///
+/// impl TLSGroup for X25519Group {
+///     const IANA_GROUP_NAME: &'static CStr = c"x25519";
+///     const IANA_GROUP_ID: u32 = 29;
+///     const GROUP_NAME_INTERNAL: &'static CStr = c"X25519";
+///     const GROUP_ALG: &'static CStr = c"X25519";
+///     const SECURITY_BITS: u32 = 128;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+/// }
+///
+/// let params = tls_group::as_params!(X25519Group);
+/// assert!(validate_group_alg(params, &[c"X25519:1.3.101.110", c"X448"]).is_ok());
+/// assert!(validate_group_alg(params, &[c"X448"]).is_err());
+/// ```
+pub fn validate_group_alg(
+    group_params: &[CONST_OSSL_PARAM],
+    registered_algs: &[&CStr],
+) -> Result<(), OurError> {
+    if !group_params.last().is_some_and(|p| p.key.is_null()) {
+        return Err(anyhow!("The TLS group params are not END-terminated"));
+    }
+    let alg = ossl_param_locate_const(group_params.as_ptr().cast(), OSSL_CAPABILITY_TLS_GROUP_ALG)
+        .and_then(|p| p.get::<&CStr>())
+        .ok_or_else(|| anyhow!("The TLS group params have no {OSSL_CAPABILITY_TLS_GROUP_ALG:?}"))?;
+    let found = registered_algs
+        .iter()
+        .flat_map(|names| names.to_bytes().split(|&b| b == b':'))
+        .any(|name| name.eq_ignore_ascii_case(alg.to_bytes()));
+    if !found {
+        return Err(anyhow!(
+            "The TLS group algorithm {alg:?} is not among the registered keymgmt algorithms"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::osslparams::OSSLParam;
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    struct KEMGroup;
+
+    impl TLSGroup for KEMGroup {
+        const IANA_GROUP_NAME: &CStr = c"X25519MLKEM768";
+        const IANA_GROUP_ID: u32 = 0x11EC;
+        const GROUP_NAME_INTERNAL: &CStr = c"X25519MLKEM768";
+        const GROUP_ALG: &CStr = c"X25519MLKEM768";
+        const SECURITY_BITS: u32 = 192;
+        const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+        const IS_KEM: bool = true;
+    }
+
+    struct KEXGroup;
+
+    impl TLSGroup for KEXGroup {
+        const IANA_GROUP_NAME: &CStr = c"x25519";
+        const IANA_GROUP_ID: u32 = 29;
+        const GROUP_NAME_INTERNAL: &CStr = c"X25519";
+        const GROUP_ALG: &CStr = c"X25519";
+        const SECURITY_BITS: u32 = 128;
+        const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+    }
+
+    fn head(params: &[CONST_OSSL_PARAM]) -> *const OSSL_PARAM {
+        params.as_ptr() as *const OSSL_PARAM
+    }
+
+    group_enum! {
+        enum Groups {
+            X25519MLKEM768 => EnumX25519MLKEM768Group {
+                const IANA_GROUP_NAME: &CStr = c"X25519MLKEM768";
+                const IANA_GROUP_ID: u32 = 4588;
+                const GROUP_NAME_INTERNAL: &CStr = c"X25519MLKEM768";
+                const GROUP_ALG: &CStr = c"X25519MLKEM768";
+                const SECURITY_BITS: u32 = 192;
+                const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+                const IS_KEM: bool = true;
+            },
+            X25519 => EnumX25519Group {
+                const IANA_GROUP_NAME: &CStr = c"x25519";
+                const IANA_GROUP_ID: u32 = 29;
+                const GROUP_NAME_INTERNAL: &CStr = c"X25519";
+                const GROUP_ALG: &CStr = c"X25519";
+                const SECURITY_BITS: u32 = 128;
+                const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+            },
+        }
+    }
+
+    static KEM_GROUP_PARAMS: &[CONST_OSSL_PARAM] = as_params!(KEMGroup);
+
+    #[test]
+    fn test_field_dependencies() {
+        use crate::capabilities::{field_dependencies_hold, validate_field_dependencies};
+
+        setup().expect("setup() failed");
+
+        assert!(validate_field_dependencies(as_params!(KEXGroup), FIELD_DEPENDENCIES).is_ok());
+
+        // a max DTLS version without the min one
+        const PARAMS: &[CONST_OSSL_PARAM] = &[
+            OSSLParam::new_const_utf8string(OSSL_CAPABILITY_TLS_GROUP_NAME, Some(c"x25519")),
+            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MAX_DTLS, Some(&0)),
             CONST_OSSL_PARAM::END,
         ];
-        OSSL_PARAM_ARRAY
-    }};
+        const _: () = assert!(!field_dependencies_hold(PARAMS, FIELD_DEPENDENCIES));
+        let err = validate_field_dependencies(PARAMS, FIELD_DEPENDENCIES).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(r#"either all or none of ["tls-min-dtls", "tls-max-dtls"]"#),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_as_params_in_static() {
+        setup().expect("setup() failed");
+
+        assert_eq!(group_id(head(KEM_GROUP_PARAMS)), Some(0x11EC));
+        assert!(!crate::osslparams::has_duplicate_keys(
+            KEM_GROUP_PARAMS,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_is_kem() {
+        setup().expect("setup() failed");
+
+        let params = as_params!(KEMGroup);
+        assert_eq!(is_kem(head(params)), Some(true));
+    }
+
+    #[test]
+    fn test_is_not_kem() {
+        setup().expect("setup() failed");
+
+        let params = as_params!(KEXGroup);
+        assert_eq!(is_kem(head(params)), Some(false));
+    }
+
+    #[test]
+    fn test_is_kem_missing() {
+        setup().expect("setup() failed");
+
+        assert_eq!(is_kem(std::ptr::null()), None);
+
+        let params = [
+            OSSLParam::new_const_utf8string(OSSL_CAPABILITY_TLS_GROUP_NAME, Some(c"x25519")),
+            CONST_OSSL_PARAM::END,
+        ];
+        assert_eq!(is_kem(head(&params)), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        setup().expect("setup() failed");
+
+        let group = parse(head(as_params!(KEMGroup))).expect("parse() failed");
+        assert_eq!(
+            group,
+            ParsedTLSGroup {
+                iana_name: c"X25519MLKEM768".to_owned(),
+                group_name_internal: c"X25519MLKEM768".to_owned(),
+                group_alg: c"X25519MLKEM768".to_owned(),
+                group_id: 0x11EC,
+                security_bits: 192,
+                min_tls: TLSVersion::TLSv1_3,
+                max_tls: TLSVersion::None,
+                min_dtls: DTLSVersion::Disabled,
+                max_dtls: DTLSVersion::Disabled,
+                is_kem: true,
+            }
+        );
+
+        let group = parse(head(as_params!(KEXGroup))).expect("parse() failed");
+        assert_eq!(group.group_id, 29);
+        assert_eq!(group.group_alg.as_c_str(), c"X25519");
+        assert!(!group.is_kem);
+    }
+
+    #[test]
+    fn test_parse_incomplete() {
+        setup().expect("setup() failed");
+
+        assert_eq!(parse(std::ptr::null()), None);
+
+        // all the keys but the last one (i.e., IS_KEM)
+        let params = as_params!(KEMGroup);
+        let mut truncated = params[..params.len() - 2].to_vec();
+        truncated.push(CONST_OSSL_PARAM::END);
+        assert_eq!(parse(head(&truncated)), None);
+
+        // a version which is not a known TLSVersion
+        let mut params = as_params!(KEMGroup).to_vec();
+        let min_tls = params
+            .iter_mut()
+            .find(|p| {
+                !p.key.is_null()
+                    && unsafe { CStr::from_ptr(p.key) } == OSSL_CAPABILITY_TLS_GROUP_MIN_TLS
+            })
+            .unwrap();
+        *min_tls = OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_GROUP_MIN_TLS, Some(&0x0305i32));
+        assert_eq!(parse(head(&params)), None);
+    }
+
+    #[test]
+    fn test_group_fields() {
+        setup().expect("setup() failed");
+
+        let params = as_params!(KEMGroup);
+        let head = head(params);
+        assert_eq!(group_id(head), Some(4588));
+        assert_eq!(security_bits(head), Some(192));
+        assert_eq!(iana_name(head).as_deref(), Some(c"X25519MLKEM768"));
+    }
+
+    #[test]
+    fn test_group_fields_missing() {
+        setup().expect("setup() failed");
+
+        assert_eq!(group_id(std::ptr::null()), None);
+        assert_eq!(security_bits(std::ptr::null()), None);
+        assert_eq!(iana_name(std::ptr::null()), None);
+
+        let params = [
+            OSSLParam::new_const_utf8string(OSSL_CAPABILITY_TLS_GROUP_ALG, Some(c"X25519")),
+            CONST_OSSL_PARAM::END,
+        ];
+        assert_eq!(group_id(head(&params)), None);
+        assert_eq!(security_bits(head(&params)), None);
+        assert_eq!(iana_name(head(&params)), None);
+    }
+
+    #[test]
+    fn test_validate_group_alg_matching() {
+        setup().expect("setup() failed");
+
+        let params = as_params!(KEMGroup);
+        assert!(validate_group_alg(params, &[c"X25519MLKEM768"]).is_ok());
+        // any of the names of an algorithm, case-insensitively
+        assert!(validate_group_alg(params, &[c"X448", c"ml-kem-768:x25519mlkem768"]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_group_alg_missing() {
+        setup().expect("setup() failed");
+
+        let params = as_params!(KEMGroup);
+        let err = validate_group_alg(params, &[c"X25519", c"MLKEM768:ML-KEM-768"]).unwrap_err();
+        assert!(err.to_string().contains("X25519MLKEM768"), "{err}");
+        assert!(validate_group_alg(params, &[]).is_err());
+        // the names must match exactly, not as prefixes
+        assert!(validate_group_alg(params, &[c"X25519MLKEM"]).is_err());
+    }
+
+    #[test]
+    fn test_validate_group_alg_malformed() {
+        setup().expect("setup() failed");
+
+        let no_alg = [
+            OSSLParam::new_const_uint(OSSL_CAPABILITY_TLS_GROUP_ID, Some(&29u32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        assert!(validate_group_alg(&no_alg, &[c"X25519"]).is_err());
+
+        let no_end = [OSSLParam::new_const_utf8string(
+            OSSL_CAPABILITY_TLS_GROUP_ALG,
+            Some(c"X25519"),
+        )];
+        assert!(validate_group_alg(&no_end, &[c"X25519"]).is_err());
+    }
+
+    #[test]
+    fn test_group_enum_from_codepoint() {
+        setup().expect("setup() failed");
+
+        // X25519MLKEM768 is 4588 (0x11EC) in the IANA registry
+        assert_eq!(Groups::from_codepoint(0x11EC), Some(Groups::X25519MLKEM768));
+        assert_eq!(Groups::from_codepoint(29), Some(Groups::X25519));
+        assert_eq!(Groups::from_codepoint(0x4588), None);
+        assert_eq!(Groups::ALL, [Groups::X25519MLKEM768, Groups::X25519]);
+
+        for group in Groups::ALL {
+            assert_eq!(Groups::from_codepoint(group.codepoint()), Some(*group));
+        }
+        assert_eq!(Groups::X25519.iana_name(), c"x25519");
+        assert_eq!(Groups::X25519.internal_name(), c"X25519");
+    }
+
+    #[test]
+    fn test_group_enum_as_params() {
+        setup().expect("setup() failed");
+
+        let params = Groups::X25519MLKEM768.as_params();
+        assert_eq!(group_id(head(params)), Some(4588));
+        assert_eq!(is_kem(head(params)), Some(true));
+        assert_eq!(iana_name(head(params)).as_deref(), Some(c"X25519MLKEM768"));
+        assert_eq!(
+            security_bits(head(params)),
+            Some(EnumX25519MLKEM768Group::SECURITY_BITS)
+        );
+
+        let params = Groups::X25519.as_params();
+        assert_eq!(group_id(head(params)), Some(29));
+        assert_eq!(is_kem(head(params)), Some(false));
+    }
+
+    #[test]
+    fn test_groups_as_params() {
+        setup().expect("setup() failed");
+
+        struct X448Group;
+
+        impl TLSGroup for X448Group {
+            const IANA_GROUP_NAME: &CStr = c"x448";
+            const IANA_GROUP_ID: u32 = 30;
+            const GROUP_NAME_INTERNAL: &CStr = c"X448";
+            const GROUP_ALG: &CStr = c"X448";
+            const SECURITY_BITS: u32 = 224;
+            const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+        }
+
+        static GROUPS: &[CONST_OSSL_PARAM] = groups_as_params!(KEMGroup, KEXGroup, X448Group);
+
+        let single = as_params!(KEMGroup);
+        assert_eq!(GROUPS.len(), 3 * single.len());
+
+        let groups: Vec<_> = GROUPS.split_inclusive(|p| p.key.is_null()).collect();
+        assert_eq!(groups.len(), 3);
+        let ids: Vec<_> = groups.iter().map(|g| group_id(head(g))).collect();
+        assert_eq!(ids, [Some(4588), Some(29), Some(30)]);
+        assert_eq!(
+            groups.iter().map(|g| is_kem(head(g))).collect::<Vec<_>>(),
+            [Some(true), Some(false), Some(false)]
+        );
+        // each group is END-terminated
+        for group in groups {
+            assert_eq!(group.len(), single.len());
+            assert!(group.last().unwrap().key.is_null());
+            assert!(validate_group_alg(group, &[c"X25519MLKEM768", c"X25519", c"X448"]).is_ok());
+        }
+    }
 }
-pub use capability_tls_group_as_params as as_params;
@@ -82,11 +82,17 @@ pub use crate::bindings::{
     OSSL_CAPABILITY_TLS_SIGALG_SIG_OID,
 };
 
-pub use super::{DTLSVersion, TLSVersion};
+pub use super::{DTLSVersion, FieldDependency, TLSVersion};
+
+use crate::osslparams::{ossl_param_locate_const, OSSL_PARAM};
 
 #[cfg(doc)]
 use crate::osslparams::*;
 
+/// The name of the "TLS-SIGALG" capability, as passed to the provider's
+/// `get_capabilities` function (see [`Registry`][`super::Registry`]).
+pub const CAPABILITY_NAME: &CStr = c"TLS-SIGALG";
+
 /// The "TLS-SIGALG" capability can be queried by `libssl` to discover the list
 /// of TLS signature algorithms that a provider can support.
 ///
@@ -326,23 +332,101 @@ pub trait TLSSigAlg {
     const MAX_TLS: TLSVersion = TLSVersion::None;
 
     // There aren't any OSSL_CAPABILITY_TLS_SIGALG_{MAX,MIN}_DTLS constants in OpenSSL 3.2, so
-    // we currently don't generate any bindings for those constants, and they can't be used
-    // unless we manually defined them. But later versions of OpenSSL will have them, so the
-    // values are here for later.
+    // `as_params!` only emits the DTLS params when building against a newer version
+    // (see `DTLS_SUPPORTED`).
 
     /// This parameters can be used to describe the minimum DTLS version
     /// supported by the signature algorithm.
     ///
     /// We default to not use this signature algorithm at all with DTLS.
+    ///
+    /// > ℹ️ This is ignored when building against OpenSSL 3.2
+    /// > (see [`DTLS_SUPPORTED`]).
     const MIN_DTLS: DTLSVersion = DTLSVersion::Disabled;
 
     /// This parameters can be used to describe the minimum DTLS version
     /// supported by the signature algorithm.
     ///
     /// We default to not use this signature algorithm at all with DTLS.
+    ///
+    /// > ℹ️ This is ignored when building against OpenSSL 3.2
+    /// > (see [`DTLS_SUPPORTED`]).
     const MAX_DTLS: DTLSVersion = DTLSVersion::Disabled;
 }
 
+/// The dependencies among the optional fields of [`TLSSigAlg`], checked by
+/// [`as_params`] at compile time (see
+/// [`field_dependencies_hold()`][`super::field_dependencies_hold`]).
+///
+/// For params built otherwise, use
+/// [`validate_field_dependencies()`][`super::validate_field_dependencies`],
+/// which also lists the violated dependencies.
+///
+/// Each OID is registered with `OBJ_create()` using the matching name as its
+/// short name, so it cannot be given without that name.
+/// ([`TLSSigAlg::SIGALG_OID`] is not listed, as [`TLSSigAlg::SIGALG_NAME`] is
+/// always given.)
+pub const FIELD_DEPENDENCIES: &[FieldDependency] = &[
+    FieldDependency::Requires {
+        field: OSSL_CAPABILITY_TLS_SIGALG_SIG_OID,
+        requires: &[OSSL_CAPABILITY_TLS_SIGALG_SIG_NAME],
+    },
+    FieldDependency::Requires {
+        field: OSSL_CAPABILITY_TLS_SIGALG_HASH_OID,
+        requires: &[OSSL_CAPABILITY_TLS_SIGALG_HASH_NAME],
+    },
+    FieldDependency::Requires {
+        field: OSSL_CAPABILITY_TLS_SIGALG_KEYTYPE_OID,
+        requires: &[OSSL_CAPABILITY_TLS_SIGALG_KEYTYPE],
+    },
+];
+
+/// Whether the OpenSSL version this crate has been built against defines the
+/// [`OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS`] and
+/// [`OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS`] keys (i.e., it is newer than 3.2).
+///
+/// When `false`, [`as_params`] omits the DTLS params.
+pub const DTLS_SUPPORTED: bool = cfg!(ossl_sigalg_dtls);
+
+#[doc(hidden)]
+/// An internal macro to terminate the sigalg params array, appending the DTLS
+/// params only if they are supported by the OpenSSL headers.
+#[cfg(ossl_sigalg_dtls)]
+#[macro_export]
+macro_rules! __hidden__sigalg_params_array {
+    ([$($entry:expr),* $(,)?], $group_type:ty) => {{
+        const MIN_DTLS: i32 = <$group_type>::MIN_DTLS as i32;
+        const MAX_DTLS: i32 = <$group_type>::MAX_DTLS as i32;
+        &$crate::ossl_params![
+            $($entry,)*
+            // min DTLS
+            $crate::osslparams::OSSLParam::new_const_int(
+                $crate::capabilities::tls_sigalg::OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS,
+                Some(&MIN_DTLS),
+            ),
+            // max DTLS
+            $crate::osslparams::OSSLParam::new_const_int(
+                $crate::capabilities::tls_sigalg::OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS,
+                Some(&MAX_DTLS),
+            ),
+        ]
+    }};
+}
+
+#[doc(hidden)]
+/// An internal macro to terminate the sigalg params array, appending the DTLS
+/// params only if they are supported by the OpenSSL headers.
+#[cfg(not(ossl_sigalg_dtls))]
+#[macro_export]
+macro_rules! __hidden__sigalg_params_array {
+    ([$($entry:expr),* $(,)?], $group_type:ty) => {
+        &$crate::ossl_params![$($entry),*]
+    };
+}
+
+#[doc(hidden)]
+pub use __hidden__sigalg_params_array as sigalg_params_array;
+
 /// Converts a type implementing [`TLSSigAlg`] into an OpenSSL parameter array.
 ///
 /// This macro generates a constant array of [`CONST_OSSL_PARAM`] values that represent
@@ -405,6 +489,36 @@ pub trait TLSSigAlg {
 ///
 /// The generated parameter array is properly terminated with a
 /// [`CONST_OSSL_PARAM::END`] marker as required by OpenSSL.
+///
+/// The [`TLSSigAlg::MIN_DTLS`] and [`TLSSigAlg::MAX_DTLS`] params are only
+/// included if the OpenSSL version this crate has been built against supports
+/// them (see [`DTLS_SUPPORTED`]).
+///
+/// This is a `const` expression, so it can initialize a `static` (or
+/// `const`) item. The uniqueness of the keys of the array is checked at
+/// compile time.
+///
+/// The optional fields are checked to satisfy the [`FIELD_DEPENDENCIES`] at
+/// compile time, e.g., an OID cannot be given without the matching name:
+///
+/// ```compile_fail
+/// use openssl_provider_forge::capabilities::tls_sigalg;
+/// use tls_sigalg::*;
+///
+/// struct HashOidOnlySigAlg;
+///
+/// impl TLSSigAlg for HashOidOnlySigAlg {
+///     const SIGALG_IANA_NAME: &CStr = c"xorhmacsha2sig";
+///     const SIGALG_NAME: &CStr = Self::SIGALG_IANA_NAME;
+///     // ERROR: `SIGALG_HASH_OID` requires `SIGALG_HASH_NAME`
+///     const SIGALG_HASH_OID: Option<&CStr> = Some(c"2.16.840.1.101.3.4.2.1");
+///     const SIGALG_CODEPOINT: u32 = 0xFFFF;
+///     const SECURITY_BITS: u32 = 128;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+/// }
+///
+/// let params = tls_sigalg::as_params!(HashOidOnlySigAlg);
+/// ```
 #[macro_export]
 macro_rules! capability_tls_sigalg_as_params {
     ($group_type:ty) => {{
@@ -422,11 +536,9 @@ macro_rules! capability_tls_sigalg_as_params {
         // Convert to const i32
         const MIN_TLS: i32 = <$group_type>::MIN_TLS as i32;
         const MAX_TLS: i32 = <$group_type>::MAX_TLS as i32;
-        const MIN_DTLS: i32 = <$group_type>::MIN_DTLS as i32;
-        const MAX_DTLS: i32 = <$group_type>::MAX_DTLS as i32;
 
         // Now create the parameter list
-        const OSSL_PARAM_ARRAY: &[CONST_OSSL_PARAM] = &[
+        const OSSL_PARAM_ARRAY: &[CONST_OSSL_PARAM] = $crate::capabilities::tls_sigalg::sigalg_params_array!([
             // IANA name for the sigalg
             OSSLParam::new_const_utf8string(
                 OSSL_CAPABILITY_TLS_SIGALG_IANA_NAME,
@@ -435,11 +547,6 @@ macro_rules! capability_tls_sigalg_as_params {
             // IANA code point for the sigalg
             OSSLParam::new_const_uint(OSSL_CAPABILITY_TLS_SIGALG_CODE_POINT, Some(&<$group_type>::SIGALG_CODEPOINT)),
 
-            // A name for the full (possibly composite hash-and-signature) signature algorithm.
-            OSSLParam::new_const_utf8string(
-                OSSL_CAPABILITY_TLS_SIGALG_NAME,
-                Some(<$group_type>::SIGALG_NAME)
-            ),
             // A name for the full (possibly composite hash-and-signature) signature algorithm.
             OSSLParam::new_const_utf8string(
                 OSSL_CAPABILITY_TLS_SIGALG_NAME,
@@ -470,25 +577,150 @@ macro_rules! capability_tls_sigalg_as_params {
             OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_SIGALG_MIN_TLS, Some(&MIN_TLS)),
             // min TLS version
             OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_SIGALG_MAX_TLS, Some(&MAX_TLS)),
-            // min DTLS
-            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS, Some(&MIN_DTLS)),
-            // max DTLS
-            OSSLParam::new_const_int(OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS, Some(&MAX_DTLS)),
-            // IMPORTANT: always terminate a params array!!!
-            CONST_OSSL_PARAM::END,
-        ];
+            // DTLS versions and END are appended by `sigalg_params_array!`
+        ], $group_type);
+        // absent optional params all share the same (ignored) key
+        const _: () = assert!(
+            !has_duplicate_keys(
+                OSSL_PARAM_ARRAY,
+                Some($crate::capabilities::OPTIONAL_PARAM_IGNORED_KEY),
+            ),
+            "duplicate keys in the TLS-SIGALG capability params",
+        );
+        const _: () = assert!(
+            $crate::capabilities::field_dependencies_hold(
+                OSSL_PARAM_ARRAY,
+                $crate::capabilities::tls_sigalg::FIELD_DEPENDENCIES,
+            ),
+            "the TLS-SIGALG capability params violate the FIELD_DEPENDENCIES",
+        );
         OSSL_PARAM_ARRAY
     }};
 }
 pub use capability_tls_sigalg_as_params as as_params;
 
+/// A TLS signature algorithm decoded from its params array, such as the one
+/// generated by [`as_params`], with one field for each of the [`TLSSigAlg`]
+/// constants.
+///
+/// The optional fields are `None` if the matching param is absent.
+///
+/// See [`parse`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedTLSSigAlg {
+    /// The [`TLSSigAlg::SIGALG_IANA_NAME`].
+    pub iana_name: std::ffi::CString,
+    /// The [`TLSSigAlg::SIGALG_CODEPOINT`].
+    pub codepoint: u32,
+    /// The [`TLSSigAlg::SIGALG_NAME`].
+    pub name: std::ffi::CString,
+    /// The [`TLSSigAlg::SIGALG_OID`].
+    pub oid: Option<std::ffi::CString>,
+    /// The [`TLSSigAlg::SIGALG_SIG_NAME`].
+    pub sig_name: Option<std::ffi::CString>,
+    /// The [`TLSSigAlg::SIGALG_SIG_OID`].
+    pub sig_oid: Option<std::ffi::CString>,
+    /// The [`TLSSigAlg::SIGALG_HASH_NAME`].
+    pub hash_name: Option<std::ffi::CString>,
+    /// The [`TLSSigAlg::SIGALG_HASH_OID`].
+    pub hash_oid: Option<std::ffi::CString>,
+    /// The [`TLSSigAlg::SIGALG_KEYTYPE`].
+    pub keytype: Option<std::ffi::CString>,
+    /// The [`TLSSigAlg::SIGALG_KEYTYPE_OID`].
+    pub keytype_oid: Option<std::ffi::CString>,
+    /// The [`TLSSigAlg::SECURITY_BITS`].
+    pub security_bits: u32,
+    /// The [`TLSSigAlg::MIN_TLS`].
+    pub min_tls: TLSVersion,
+    /// The [`TLSSigAlg::MAX_TLS`].
+    pub max_tls: TLSVersion,
+    /// The [`TLSSigAlg::MIN_DTLS`], which is absent when [`as_params`] is
+    /// built against OpenSSL 3.2 (see [`DTLS_SUPPORTED`]).
+    pub min_dtls: Option<DTLSVersion>,
+    /// The [`TLSSigAlg::MAX_DTLS`], which is absent when [`as_params`] is
+    /// built against OpenSSL 3.2 (see [`DTLS_SUPPORTED`]).
+    pub max_dtls: Option<DTLSVersion>,
+}
+
+fn utf8_field(head: *const OSSL_PARAM, key: &CStr) -> Option<std::ffi::CString> {
+    let p = ossl_param_locate_const(head, key)?;
+    p.get::<&CStr>().map(CStr::to_owned)
+}
+
+fn uint_field(head: *const OSSL_PARAM, key: &CStr) -> Option<u32> {
+    let p = ossl_param_locate_const(head, key)?;
+    p.get::<u64>().and_then(|v| u32::try_from(v).ok())
+}
+
+fn int_field(head: *const OSSL_PARAM, key: &CStr) -> Option<i32> {
+    let p = ossl_param_locate_const(head, key)?;
+    p.get::<i32>()
+}
+
+/// Decodes all the fields of a TLS signature algorithm params array, such as
+/// the one generated by [`as_params`], i.e. it is the inverse of
+/// [`as_params`].
+///
+/// `head` must point to the first item of a properly END-terminated list of
+/// [`OSSL_PARAM`]s.
+///
+/// Returns `None` if `head` is `NULL`, or if any of the required params is
+/// missing or cannot be decoded (including versions which are not a known
+/// [`TLSVersion`] or [`DTLSVersion`]).
+/// An optional param which is present but cannot be decoded is treated as
+/// absent.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::capabilities::tls_sigalg;
+/// use openssl_provider_forge::osslparams::OSSL_PARAM;
+/// use tls_sigalg::*;
+///
+/// pub struct TLSSigAlgCap;
+///
+/// impl TLSSigAlg for TLSSigAlgCap {
+///     const SIGALG_IANA_NAME: &CStr = c"xorhmacsha2sig";
+///     const SIGALG_NAME: &CStr = Self::SIGALG_IANA_NAME;
+///     const SIGALG_HASH_NAME: Option<&CStr> = Some(c"SHA256");
+///     const SIGALG_CODEPOINT: u32 = 0xFFFF;
+///     const SECURITY_BITS: u32 = 128;
+///     const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+/// }
+///
+/// let params = tls_sigalg::as_params!(TLSSigAlgCap);
+/// let sigalg = tls_sigalg::parse(params.as_ptr() as *const OSSL_PARAM).unwrap();
+/// assert_eq!(sigalg.codepoint, 0xFFFF);
+/// assert_eq!(sigalg.hash_name.as_deref(), Some(c"SHA256"));
+/// assert_eq!(sigalg.oid, None);
+/// ```
+pub fn parse(head: *const OSSL_PARAM) -> Option<ParsedTLSSigAlg> {
+    let dtls = |key| DTLSVersion::try_from(int_field(head, key)?).ok();
+    Some(ParsedTLSSigAlg {
+        iana_name: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_IANA_NAME)?,
+        codepoint: uint_field(head, OSSL_CAPABILITY_TLS_SIGALG_CODE_POINT)?,
+        name: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_NAME)?,
+        oid: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_OID),
+        sig_name: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_SIG_NAME),
+        sig_oid: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_SIG_OID),
+        hash_name: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_HASH_NAME),
+        hash_oid: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_HASH_OID),
+        keytype: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_KEYTYPE),
+        keytype_oid: utf8_field(head, OSSL_CAPABILITY_TLS_SIGALG_KEYTYPE_OID),
+        security_bits: uint_field(head, OSSL_CAPABILITY_TLS_SIGALG_SECURITY_BITS)?,
+        min_tls: TLSVersion::try_from(int_field(head, OSSL_CAPABILITY_TLS_SIGALG_MIN_TLS)?).ok()?,
+        max_tls: TLSVersion::try_from(int_field(head, OSSL_CAPABILITY_TLS_SIGALG_MAX_TLS)?).ok()?,
+        min_dtls: dtls(OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS),
+        max_dtls: dtls(OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #![expect(unused_imports)]
     use crate as openssl_provider_forge;
     use crate::tests::common::OurError;
 
-    #[expect(dead_code)]
     fn setup() -> Result<(), OurError> {
         crate::tests::common::setup()
     }
@@ -527,4 +759,305 @@ mod tests {
 
         log::debug!("{params:#?}");
     }
+
+    mod dtls {
+        use super::*;
+        use crate::capabilities::tls_sigalg::{self, *};
+        use crate::osslparams::{list_keys, OSSLParam};
+
+        struct DTLSSigAlg;
+
+        impl TLSSigAlg for DTLSSigAlg {
+            const SIGALG_IANA_NAME: &CStr = c"xorhmacsha2sig";
+            const SIGALG_NAME: &CStr = Self::SIGALG_IANA_NAME;
+            const SIGALG_CODEPOINT: u32 = 0xFFFF;
+            const SECURITY_BITS: u32 = 128;
+            const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+            const MIN_DTLS: DTLSVersion = DTLSVersion::DTLSv1_2;
+            const MAX_DTLS: DTLSVersion = DTLSVersion::DTLSv1_2;
+        }
+
+        fn dtls_keys() -> Vec<&'static CStr> {
+            let params = tls_sigalg::as_params!(DTLSSigAlg);
+            list_keys(params.as_ptr().cast())
+                .into_iter()
+                .filter(|k| {
+                    *k == OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS
+                        || *k == OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_no_duplicate_keys() {
+            setup().expect("setup() failed");
+
+            let params = tls_sigalg::as_params!(DTLSSigAlg);
+            // only the placeholders of the absent optional params are repeated
+            let dups = crate::osslparams::find_duplicate_keys(params.as_ptr().cast());
+            assert_eq!(dups, [crate::capabilities::OPTIONAL_PARAM_IGNORED_KEY]);
+        }
+
+        static DTLS_SIGALG_PARAMS: &[crate::osslparams::CONST_OSSL_PARAM] =
+            tls_sigalg::as_params!(DTLSSigAlg);
+
+        #[test]
+        fn test_as_params_in_static() {
+            setup().expect("setup() failed");
+
+            assert_eq!(
+                list_keys(DTLS_SIGALG_PARAMS.as_ptr().cast()),
+                list_keys(tls_sigalg::as_params!(DTLSSigAlg).as_ptr().cast())
+            );
+        }
+
+        #[cfg(ossl_sigalg_dtls)]
+        #[test]
+        fn test_dtls_params_present() {
+            setup().expect("setup() failed");
+
+            const { assert!(DTLS_SUPPORTED) };
+            assert_eq!(
+                dtls_keys(),
+                vec![
+                    OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS,
+                    OSSL_CAPABILITY_TLS_SIGALG_MAX_DTLS
+                ]
+            );
+
+            let params = tls_sigalg::as_params!(DTLSSigAlg);
+            let min_dtls = params
+                .iter()
+                .filter_map(|p| OSSLParam::try_from(p).ok())
+                .find(|p| p.get_key() == Some(OSSL_CAPABILITY_TLS_SIGALG_MIN_DTLS))
+                .and_then(|p| p.get::<i32>());
+            assert_eq!(min_dtls, Some(DTLSVersion::DTLSv1_2 as i32));
+        }
+
+        #[cfg(not(ossl_sigalg_dtls))]
+        #[test]
+        fn test_dtls_params_absent() {
+            setup().expect("setup() failed");
+
+            const { assert!(!DTLS_SUPPORTED) };
+            assert!(dtls_keys().is_empty());
+
+            // the other params are still there, and the array is terminated
+            let params = tls_sigalg::as_params!(DTLSSigAlg);
+            let keys = list_keys(params.as_ptr().cast());
+            assert!(keys.contains(&OSSL_CAPABILITY_TLS_SIGALG_IANA_NAME));
+            assert!(keys.contains(&OSSL_CAPABILITY_TLS_SIGALG_MAX_TLS));
+            assert_eq!(keys.len(), params.len() - 1);
+        }
+    }
+
+    mod dependencies {
+        use super::*;
+        use crate::capabilities::tls_sigalg::{self, *};
+        use crate::capabilities::{field_dependencies_hold, validate_field_dependencies};
+        use crate::osslparams::{OSSLParam, CONST_OSSL_PARAM};
+
+        struct HashSigAlg;
+
+        impl TLSSigAlg for HashSigAlg {
+            const SIGALG_IANA_NAME: &CStr = c"xorhmacsha2sig";
+            const SIGALG_NAME: &CStr = Self::SIGALG_IANA_NAME;
+            const SIGALG_HASH_NAME: Option<&CStr> = Some(c"SHA256");
+            const SIGALG_HASH_OID: Option<&CStr> = Some(c"2.16.840.1.101.3.4.2.1");
+            const SIGALG_CODEPOINT: u32 = 0xFFFF;
+            const SECURITY_BITS: u32 = 128;
+            const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+        }
+
+        struct HashOidOnlySigAlg;
+
+        impl TLSSigAlg for HashOidOnlySigAlg {
+            const SIGALG_IANA_NAME: &CStr = c"xorhmacsha2sig";
+            const SIGALG_NAME: &CStr = Self::SIGALG_IANA_NAME;
+            const SIGALG_HASH_OID: Option<&CStr> = Some(c"2.16.840.1.101.3.4.2.1");
+            const SIGALG_KEYTYPE_OID: Option<&CStr> = Some(c"1.3.6.1.4.1.16604.998888.1");
+            const SIGALG_CODEPOINT: u32 = 0xFFFF;
+            const SECURITY_BITS: u32 = 128;
+            const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+        }
+
+        #[test]
+        fn test_satisfied_dependencies() {
+            setup().expect("setup() failed");
+
+            let params = tls_sigalg::as_params!(HashSigAlg);
+            assert!(validate_field_dependencies(params, FIELD_DEPENDENCIES).is_ok());
+
+            // all or none
+            let deps = [FieldDependency::AllOrNone(&[
+                OSSL_CAPABILITY_TLS_SIGALG_SIG_NAME,
+                OSSL_CAPABILITY_TLS_SIGALG_SIG_OID,
+            ])];
+            assert!(validate_field_dependencies(params, &deps).is_ok());
+        }
+
+        #[test]
+        fn test_violated_dependencies() {
+            setup().expect("setup() failed");
+
+            // `as_params!` would not compile
+            let params = [
+                OSSLParam::new_const_utf8string(
+                    OSSL_CAPABILITY_TLS_SIGALG_NAME,
+                    Some(HashOidOnlySigAlg::SIGALG_NAME),
+                ),
+                OSSLParam::new_const_utf8string(
+                    OSSL_CAPABILITY_TLS_SIGALG_HASH_OID,
+                    HashOidOnlySigAlg::SIGALG_HASH_OID,
+                ),
+                OSSLParam::new_const_utf8string(
+                    OSSL_CAPABILITY_TLS_SIGALG_KEYTYPE_OID,
+                    HashOidOnlySigAlg::SIGALG_KEYTYPE_OID,
+                ),
+                CONST_OSSL_PARAM::END,
+            ];
+            let err = validate_field_dependencies(&params, FIELD_DEPENDENCIES).unwrap_err();
+            let message = err.to_string();
+            assert!(
+                message.contains(r#""tls-sigalg-hash-oid" requires ["tls-sigalg-hash-name"]"#),
+                "{message}"
+            );
+            assert!(
+                message.contains(r#""tls-sigalg-keytype-oid" requires ["tls-sigalg-keytype"]"#),
+                "{message}"
+            );
+            assert!(!message.contains("tls-sigalg-sig-oid"), "{message}");
+
+            let deps = [FieldDependency::AllOrNone(&[
+                OSSL_CAPABILITY_TLS_SIGALG_NAME,
+                OSSL_CAPABILITY_TLS_SIGALG_SIG_NAME,
+            ])];
+            let err = validate_field_dependencies(&params, &deps).unwrap_err();
+            assert!(err.to_string().contains("either all or none of"), "{err}");
+        }
+
+        #[test]
+        fn test_dependencies_hold_at_compile_time() {
+            setup().expect("setup() failed");
+
+            const PARAMS: &[CONST_OSSL_PARAM] = tls_sigalg::as_params!(HashSigAlg);
+            const _: () = assert!(field_dependencies_hold(PARAMS, FIELD_DEPENDENCIES));
+
+            // `as_params!` would not compile for `HashOidOnlySigAlg`
+            const VIOLATING: &[CONST_OSSL_PARAM] = &[
+                OSSLParam::new_const_utf8string(
+                    OSSL_CAPABILITY_TLS_SIGALG_HASH_OID,
+                    HashOidOnlySigAlg::SIGALG_HASH_OID,
+                ),
+                CONST_OSSL_PARAM::END,
+            ];
+            const _: () = assert!(!field_dependencies_hold(VIOLATING, FIELD_DEPENDENCIES));
+            assert!(validate_field_dependencies(VIOLATING, FIELD_DEPENDENCIES).is_err());
+        }
+    }
+
+    mod parse {
+        use super::*;
+        use crate::capabilities::tls_sigalg::{self, *};
+        use crate::osslparams::CONST_OSSL_PARAM;
+
+        struct Ed448SigAlg;
+
+        impl TLSSigAlg for Ed448SigAlg {
+            const SIGALG_IANA_NAME: &CStr = c"ed448";
+            const SIGALG_CODEPOINT: u32 = 0x0808;
+            const SIGALG_NAME: &CStr = c"EDWARDS448";
+            const SECURITY_BITS: u32 = 192;
+            const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+        }
+
+        struct XorHmacSigAlg;
+
+        impl TLSSigAlg for XorHmacSigAlg {
+            const SIGALG_IANA_NAME: &CStr = c"xorhmacsha2sig";
+            const SIGALG_NAME: &CStr = Self::SIGALG_IANA_NAME;
+            const SIGALG_HASH_NAME: Option<&CStr> = Some(c"SHA256");
+            const SIGALG_OID: Option<&CStr> = Some(c"1.3.6.1.4.1.16604.998888.2");
+            const SIGALG_CODEPOINT: u32 = 0xFFFF;
+            const SECURITY_BITS: u32 = 128;
+            const MIN_TLS: TLSVersion = TLSVersion::TLSv1_3;
+            const MAX_TLS: TLSVersion = TLSVersion::TLSv1_3;
+            const MIN_DTLS: DTLSVersion = DTLSVersion::DTLSv1_2;
+            const MAX_DTLS: DTLSVersion = DTLSVersion::DTLSv1_2;
+        }
+
+        fn head(params: &[CONST_OSSL_PARAM]) -> *const OSSL_PARAM {
+            params.as_ptr() as *const OSSL_PARAM
+        }
+
+        #[test]
+        fn test_parse_minimal() {
+            setup().expect("setup() failed");
+
+            let sigalg = tls_sigalg::parse(head(tls_sigalg::as_params!(Ed448SigAlg)));
+            assert_eq!(
+                sigalg,
+                Some(ParsedTLSSigAlg {
+                    iana_name: c"ed448".to_owned(),
+                    codepoint: 0x0808,
+                    name: c"EDWARDS448".to_owned(),
+                    oid: None,
+                    sig_name: None,
+                    sig_oid: None,
+                    hash_name: None,
+                    hash_oid: None,
+                    keytype: None,
+                    keytype_oid: None,
+                    security_bits: 192,
+                    min_tls: TLSVersion::TLSv1_3,
+                    max_tls: TLSVersion::None,
+                    min_dtls: DTLS_SUPPORTED.then_some(DTLSVersion::Disabled),
+                    max_dtls: DTLS_SUPPORTED.then_some(DTLSVersion::Disabled),
+                })
+            );
+        }
+
+        #[test]
+        fn test_parse_with_optionals() {
+            setup().expect("setup() failed");
+
+            let sigalg = tls_sigalg::parse(head(tls_sigalg::as_params!(XorHmacSigAlg)));
+            assert_eq!(
+                sigalg,
+                Some(ParsedTLSSigAlg {
+                    iana_name: c"xorhmacsha2sig".to_owned(),
+                    codepoint: 0xFFFF,
+                    name: c"xorhmacsha2sig".to_owned(),
+                    oid: Some(c"1.3.6.1.4.1.16604.998888.2".to_owned()),
+                    sig_name: None,
+                    sig_oid: None,
+                    hash_name: Some(c"SHA256".to_owned()),
+                    hash_oid: None,
+                    keytype: None,
+                    keytype_oid: None,
+                    security_bits: 128,
+                    min_tls: TLSVersion::TLSv1_3,
+                    max_tls: TLSVersion::TLSv1_3,
+                    min_dtls: DTLS_SUPPORTED.then_some(DTLSVersion::DTLSv1_2),
+                    max_dtls: DTLS_SUPPORTED.then_some(DTLSVersion::DTLSv1_2),
+                })
+            );
+        }
+
+        #[test]
+        fn test_parse_missing_required() {
+            setup().expect("setup() failed");
+
+            assert_eq!(tls_sigalg::parse(std::ptr::null()), None);
+
+            let params = [
+                crate::osslparams::OSSLParam::new_const_utf8string(
+                    OSSL_CAPABILITY_TLS_SIGALG_IANA_NAME,
+                    Some(c"ed448"),
+                ),
+                CONST_OSSL_PARAM::END,
+            ];
+            assert_eq!(tls_sigalg::parse(head(&params)), None);
+        }
+    }
 }
@@ -0,0 +1,137 @@
+//! This module provides a mapping from the errors of this crate to _reason
+//! codes_, as reported by providers to the OpenSSL error queue
+//! (e.g., through the `core_set_error` upcall).
+//!
+//! The reason codes are defined by the provider itself: a provider using
+//! [`ProviderErrorCode`] should also expose [`ProviderErrorCode::reason_string`]
+//! through its `get_reason_strings` function.
+
+use std::ffi::CStr;
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::operations::signature::VerificationError;
+use crate::osslparams::OSSLParamError;
+use crate::OurError;
+
+/// Reason codes for the errors raised by a provider built with this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::error::ProviderErrorCode;
+///
+/// let reason: u32 = ProviderErrorCode::InvalidSignature.into();
+/// assert_eq!(ProviderErrorCode::try_from(reason).unwrap(), ProviderErrorCode::InvalidSignature);
+/// ```
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+pub enum ProviderErrorCode {
+    /// An internal error, which could not be classified more precisely.
+    InternalError = 1,
+    /// A signature did not verify.
+    InvalidSignature = 2,
+    /// The signature verification could not be completed.
+    VerificationFailed = 3,
+    /// A param was missing, malformed, or of an unexpected type.
+    InvalidParam = 4,
+}
+
+impl ProviderErrorCode {
+    /// Returns the human readable string associated with this reason code.
+    pub const fn reason_string(self) -> &'static CStr {
+        match self {
+            ProviderErrorCode::InternalError => c"internal error",
+            ProviderErrorCode::InvalidSignature => c"invalid signature",
+            ProviderErrorCode::VerificationFailed => c"signature verification failed",
+            ProviderErrorCode::InvalidParam => c"invalid parameter",
+        }
+    }
+}
+
+/// Converts an error into the [`ProviderErrorCode`] that best describes it.
+pub trait IntoProviderReason {
+    /// Returns the reason code for this error.
+    fn provider_reason(&self) -> ProviderErrorCode;
+}
+
+impl IntoProviderReason for VerificationError {
+    fn provider_reason(&self) -> ProviderErrorCode {
+        match self {
+            VerificationError::InvalidSignature => ProviderErrorCode::InvalidSignature,
+            VerificationError::GenericVerificationError => ProviderErrorCode::VerificationFailed,
+        }
+    }
+}
+
+impl IntoProviderReason for OSSLParamError {
+    fn provider_reason(&self) -> ProviderErrorCode {
+        ProviderErrorCode::InvalidParam
+    }
+}
+
+/// The conversion is best-effort: the chain of causes of the error is searched
+/// for known error types (i.e., [`VerificationError`] and [`OSSLParamError`]),
+/// falling back to [`ProviderErrorCode::InternalError`].
+impl IntoProviderReason for OurError {
+    fn provider_reason(&self) -> ProviderErrorCode {
+        self.chain()
+            .find_map(|cause| {
+                if let Some(e) = cause.downcast_ref::<VerificationError>() {
+                    Some(e.provider_reason())
+                } else {
+                    cause
+                        .downcast_ref::<OSSLParamError>()
+                        .map(IntoProviderReason::provider_reason)
+                }
+            })
+            .unwrap_or(ProviderErrorCode::InternalError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    #[test]
+    fn test_verification_error_reason() {
+        setup().expect("setup() failed");
+
+        let e = OurError::from(VerificationError::InvalidSignature);
+        assert_eq!(e.provider_reason(), ProviderErrorCode::InvalidSignature);
+
+        let e = OurError::from(VerificationError::GenericVerificationError);
+        assert_eq!(e.provider_reason(), ProviderErrorCode::VerificationFailed);
+    }
+
+    #[test]
+    fn test_verification_error_reason_with_context() {
+        setup().expect("setup() failed");
+
+        let e = OurError::from(VerificationError::InvalidSignature).context("verifying message");
+        assert_eq!(e.provider_reason(), ProviderErrorCode::InvalidSignature);
+    }
+
+    #[test]
+    fn test_unknown_error_reason() {
+        setup().expect("setup() failed");
+
+        let e = anyhow::anyhow!("something else went wrong");
+        assert_eq!(e.provider_reason(), ProviderErrorCode::InternalError);
+    }
+
+    #[test]
+    fn test_param_error_reason() {
+        setup().expect("setup() failed");
+
+        let e = OurError::from(OSSLParamError::NullData);
+        assert_eq!(e.provider_reason(), ProviderErrorCode::InvalidParam);
+
+        let e = OurError::from(OSSLParamError::NullData).context("setting the status param");
+        assert_eq!(e.provider_reason(), ProviderErrorCode::InvalidParam);
+    }
+}
@@ -5,6 +5,7 @@
 
 pub mod bindings;
 pub mod capabilities;
+pub mod error;
 pub mod operations;
 pub mod ossl_callback;
 pub mod osslparams;
@@ -194,6 +195,11 @@ impl PartialOrd for DTLSVersion {
 /// Before invoking this macro, an identifier `ERROR_RET` must be in scope, and
 /// the type of its value must be the same as (or coercible to) the return type
 /// of the function in which `handleResult!` is being invoked.
+/// The right value depends on the callback (e.g., `0` for most functions
+/// returning an `int`, and `NULL` for those returning a pointer): the failure
+/// values of the callbacks of each operation are available as constants, e.g.
+/// [`operations::keymgmt::ERROR_RET_INT`], to define `ERROR_RET` with.
+/// See also [`handleResultRet!`], which takes the value to return explicitly.
 #[macro_export]
 macro_rules! handleResult {
     ($e:expr) => {
@@ -207,5 +213,106 @@ macro_rules! handleResult {
     };
 }
 
+/// Same as [`handleResult!`], but returning the explicitly given `ret` value
+/// (instead of `ERROR_RET`) if the `Result` is `Err`.
+///
+/// This is meant for functions whose failure value differs from the one of
+/// the `ERROR_RET` in scope, or where defining `ERROR_RET` is not worth it.
+/// The failure values of the callbacks of each operation are available as
+/// constants, e.g. [`operations::keymgmt::ERROR_RET_PTR`] and
+/// [`operations::keymgmt::ERROR_RET_INT`].
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::handleResultRet;
+/// use openssl_provider_forge::operations::keymgmt::ERROR_RET_INT;
+/// use std::ffi::c_int;
+///
+/// extern "C" fn has(fail: c_int) -> c_int {
+///     let r: Result<(), String> = if fail != 0 { Err("no key".into()) } else { Ok(()) };
+///     handleResultRet!(ERROR_RET_INT, r);
+///     1
+/// }
+///
+/// extern "C" fn get_size(fail: c_int) -> c_int {
+///     let r: Result<c_int, String> = if fail != 0 { Err("no key".into()) } else { Ok(32) };
+///     handleResultRet!(-1, r)
+/// }
+///
+/// assert_eq!(has(0), 1);
+/// assert_eq!(has(1), ERROR_RET_INT);
+/// assert_eq!(get_size(0), 32);
+/// assert_eq!(get_size(1), -1);
+/// ```
+#[macro_export]
+macro_rules! handleResultRet {
+    ($ret:expr, $e:expr) => {
+        match ($e) {
+            Ok(r) => r,
+            Err(e) => {
+                log::error!("{:#?}", e);
+                return $ret;
+            }
+        }
+    };
+}
+
+/// Run the given body catching any panic, evaluating to the value of the body
+/// if it completes normally or returning `ERROR_RET` (which must already be
+/// defined) if it panics.
+///
+/// Unwinding across an `extern "C"` boundary is undefined behavior, so the
+/// body of every `extern "C"` function that will be directly called by
+/// OpenSSL should be wrapped with this macro.
+///
+/// If the body panics, this macro also calls [`log::error!`] to log the panic
+/// message.
+///
+/// As for [`handleResult!`], before invoking this macro an identifier
+/// `ERROR_RET` must be in scope, and the type of its value must be the same as
+/// (or coercible to) the return type of the function in which `ffi_guard!` is
+/// being invoked. The two macros can be combined, as `handleResult!` can be
+/// used inside the guarded body.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::{ffi_guard, handleResult};
+/// use std::ffi::c_int;
+///
+/// extern "C" fn get_params(fail: c_int) -> c_int {
+///     const ERROR_RET: c_int = 0;
+///     ffi_guard! {
+///         let r: Result<(), String> = Ok(());
+///         handleResult!(r);
+///         if fail != 0 {
+///             panic!("something went terribly wrong");
+///         }
+///         1
+///     }
+/// }
+///
+/// assert_eq!(get_params(0), 1);
+/// assert_eq!(get_params(1), 0);
+/// ```
+#[macro_export]
+macro_rules! ffi_guard {
+    ($($body:tt)*) => {
+        match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| { $($body)* })) {
+            Ok(r) => r,
+            Err(payload) => {
+                let msg = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("<non-string panic payload>");
+                log::error!("Caught panic at the FFI boundary: {}", msg);
+                return ERROR_RET;
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 pub(crate) mod tests;
@@ -26,6 +26,11 @@
 //! (Add examples here once the module is populated with functionality.)
 //!
 
+pub mod descriptor;
 pub mod keymgmt;
+pub mod properties;
 pub mod signature;
 pub mod transcoders;
+
+pub use descriptor::validate_against_descriptor;
+pub use properties::property_score;
@@ -0,0 +1,156 @@
+//! This submodule provides [`validate_against_descriptor()`], to check the
+//! values passed to an operation against the params descriptor it advertises
+//! (e.g., as returned by its `settable_ctx_params` function).
+//!
+//! Refer to [OSSL_PARAM(3ossl)] for the meaning of the descriptor arrays.
+//!
+//! [OSSL_PARAM(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM/
+
+use std::ffi::CStr;
+
+use crate::osslparams::{raw_params, OSSLParamError, CONST_OSSL_PARAM, OSSL_PARAM};
+
+/// Checks each item of the properly END-terminated list of [`OSSL_PARAM`]s
+/// starting at `values` against the `descriptor` entry with the same key.
+///
+/// Every value whose key is not described by `descriptor`
+/// ([`OSSLParamError::UnknownKey`]), or whose `data_type` differs from the one
+/// of its descriptor entry ([`OSSLParamError::DataTypeMismatch`]), is
+/// reported in the order of `values`.
+/// The items of `descriptor` past its END item (if any) are ignored, and a
+/// `NULL` `values` is an empty list.
+///
+/// This is meant to catch bugs in the code handing values to an operation
+/// (e.g., as a `debug_assert!()`): OpenSSL itself only recommends ignoring
+/// unknown keys.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::operations::validate_against_descriptor;
+/// use openssl_provider_forge::osslparams::*;
+///
+/// const SETTABLE: &[CONST_OSSL_PARAM] = &[
+///     OSSLParam::new_const_int::<i32>(c"bits", None),
+///     OSSLParam::new_const_utf8string(c"digest", None),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let values = [
+///     OSSLParam::new_const_int(c"bits", Some(&2048i32)),
+///     OSSLParam::new_const_utf8string(c"digest", Some(c"SHA256")),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// assert!(validate_against_descriptor(SETTABLE, values.as_ptr() as *const OSSL_PARAM).is_ok());
+///
+/// let values = [
+///     OSSLParam::new_const_uint(c"bits", Some(&2048u32)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// let errors = validate_against_descriptor(SETTABLE, values.as_ptr() as *const OSSL_PARAM)
+///     .unwrap_err();
+/// assert!(matches!(errors[..], [OSSLParamError::DataTypeMismatch { .. }]));
+/// ```
+pub fn validate_against_descriptor(
+    descriptor: &[CONST_OSSL_PARAM],
+    values: *const OSSL_PARAM,
+) -> Result<(), Vec<OSSLParamError>> {
+    let descriptor: Vec<(&CStr, u32)> = descriptor
+        .iter()
+        .take_while(|d| !d.key.is_null())
+        // SAFETY: we just checked that the key is not NULL
+        .map(|d| (unsafe { CStr::from_ptr(d.key) }, d.data_type))
+        .collect();
+
+    let errors: Vec<OSSLParamError> = raw_params(values)
+        .filter_map(|p| {
+            // SAFETY: `raw_params()` only yields items with a non-NULL key.
+            let key = unsafe { CStr::from_ptr(p.key) };
+            match descriptor.iter().find(|(k, _)| *k == key) {
+                None => Some(OSSLParamError::UnknownKey {
+                    key: key.to_string_lossy().into_owned(),
+                }),
+                Some(&(_, expected)) if expected != p.data_type => {
+                    Some(OSSLParamError::DataTypeMismatch {
+                        key: key.to_string_lossy().into_owned(),
+                        expected,
+                        got: p.data_type,
+                    })
+                }
+                Some(_) => None,
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::osslparams::OSSLParam;
+    use crate::tests::common::OurError;
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    const DESCRIPTOR: &[CONST_OSSL_PARAM] = &[
+        OSSLParam::new_const_int::<i32>(c"bits", None),
+        OSSLParam::new_const_uint::<u32>(c"rounds", None),
+        OSSLParam::new_const_utf8string(c"digest", None),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    #[test]
+    fn test_consistent_values() {
+        setup().expect("setup() failed");
+
+        let values = [
+            OSSLParam::new_const_utf8string(c"digest", Some(c"SHA256")),
+            OSSLParam::new_const_int(c"bits", Some(&2048i32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        let result = validate_against_descriptor(DESCRIPTOR, values.as_ptr().cast());
+        assert_eq!(result, Ok(()));
+
+        // an empty (or NULL) values list is always consistent
+        let result = validate_against_descriptor(DESCRIPTOR, std::ptr::null());
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_mismatch_and_unknown_key() {
+        setup().expect("setup() failed");
+
+        let values = [
+            OSSLParam::new_const_int(c"bits", Some(&2048i32)),
+            OSSLParam::new_const_int(c"rounds", Some(&10i32)),
+            OSSLParam::new_const_int(c"unknown", Some(&1i32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        let errors = validate_against_descriptor(DESCRIPTOR, values.as_ptr().cast())
+            .expect_err("values should not match the descriptor");
+        assert_eq!(
+            errors,
+            [
+                OSSLParamError::DataTypeMismatch {
+                    key: "rounds".to_string(),
+                    expected: crate::osslparams::OSSL_PARAM_UNSIGNED_INTEGER,
+                    got: crate::osslparams::OSSL_PARAM_INTEGER,
+                },
+                OSSLParamError::UnknownKey {
+                    key: "unknown".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "OSSL_PARAM \"rounds\" has type OSSL_PARAM_INTEGER, but OSSL_PARAM_UNSIGNED_INTEGER is expected"
+        );
+    }
+}
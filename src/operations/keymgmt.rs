@@ -17,6 +17,25 @@
 //! [provider(7ossl)#Operations]: https://docs.openssl.org/master/man7/provider/#operations
 //! [provider-keymgmt(7ossl)]: https://docs.openssl.org/master/man7/provider-keymgmt/
 
+use std::ffi::{c_int, c_void};
+
+/// The value keymgmt functions returning a pointer (`new`, `gen_init`, `gen`,
+/// `load`, `dup`) return on failure, i.e. the `ERROR_RET` to use with
+/// [`handleResult!`][`crate::handleResult`] (or the value to pass to
+/// [`handleResultRet!`][`crate::handleResultRet`]) in those functions.
+///
+/// Functions returning a `const` pointer (e.g., `gettable_params`,
+/// `query_operation_name`) return `std::ptr::null()` instead.
+pub const ERROR_RET_PTR: *mut c_void = std::ptr::null_mut();
+
+/// The value keymgmt functions returning an `int` (e.g., `has`, `validate`,
+/// `match`, `import`, `export`, `get_params`, `set_params`,
+/// `gen_set_params`, `gen_set_template`) return on failure, i.e. the
+/// `ERROR_RET` to use with [`handleResult!`][`crate::handleResult`] (or the
+/// value to pass to [`handleResultRet!`][`crate::handleResultRet`]) in those
+/// functions.
+pub const ERROR_RET_INT: c_int = 0;
+
 /// This submodule defines the `Selection` bitflags used in OpenSSL key management operations.
 ///
 /// # Purpose
@@ -29,6 +48,8 @@
 /// - Provides constants for common key selection options, such as `PRIVATE_KEY`, `PUBLIC_KEY`,
 ///   and `KEYPAIR`.
 /// - Implements a `TryFrom<u32>` conversion for safely handling raw OpenSSL flag values.
+/// - Implements [`FromStr`][`std::str::FromStr`] to parse flag names
+///   (e.g., `"PRIVATE_KEY | PUBLIC_KEY"`).
 ///
 /// # Examples
 ///
@@ -47,9 +68,11 @@
 /// ```
 pub mod selection {
     use crate::bindings;
-    use bitflags::bitflags;
+    use bitflags::{bitflags, Flags};
+    use std::ffi::c_int;
     use std::fmt::Debug;
     use std::result::Result::Ok;
+    use std::sync::OnceLock;
 
     bitflags! {
         /// Represents key selection flags used in OpenSSL key management operations.
@@ -111,4 +134,242 @@ pub mod selection {
             }
         }
     }
+
+    impl Selection {
+        /// Returns the names of all the known flags, in declaration order.
+        ///
+        /// Each name can be mapped back to its value with
+        /// [`Selection::from_name()`] (provided by [`bitflags`]).
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use openssl_provider_forge::operations::keymgmt::selection::Selection;
+        ///
+        /// for name in Selection::all_names() {
+        ///     assert!(Selection::from_name(name).is_some());
+        /// }
+        /// assert!(Selection::all_names().contains(&"KEYPAIR"));
+        /// ```
+        pub fn all_names() -> &'static [&'static str] {
+            static NAMES: OnceLock<Vec<&'static str>> = OnceLock::new();
+            NAMES.get_or_init(|| Self::FLAGS.iter().map(|f| f.name()).collect())
+        }
+
+        /// Returns the flags as the `int` which OpenSSL dispatch functions
+        /// receive and return selections as.
+        ///
+        /// All the flags defined by OpenSSL fit in the low bits of an `int`,
+        /// so the conversion is lossless for any valid selection, which is
+        /// never negative.
+        /// Unknown bits (e.g., from [`Selection::from_bits_retain()`]) are
+        /// reinterpreted as they are, so setting the most significant bit
+        /// yields a negative value.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use openssl_provider_forge::operations::keymgmt::selection::Selection;
+        ///
+        /// let selection = Selection::KEYPAIR;
+        /// let round_trip = Selection::from_c_int(selection.to_c_int()).unwrap();
+        /// assert_eq!(round_trip.bits(), selection.bits());
+        /// ```
+        pub fn to_c_int(self) -> c_int {
+            self.bits() as c_int
+        }
+
+        /// Converts the `int` which OpenSSL dispatch functions receive
+        /// selections as, through the [`TryFrom<u32>`] conversion.
+        ///
+        /// The value is reinterpreted as a `u32` as it is: as no flag defined
+        /// by OpenSSL uses the most significant bit, negative values are
+        /// always rejected, like any other value with unknown bits set.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use openssl_provider_forge::operations::keymgmt::selection::Selection;
+        ///
+        /// let selection = Selection::from_c_int(0x03).unwrap();
+        /// assert_eq!(selection.bits(), Selection::KEYPAIR.bits());
+        ///
+        /// assert!(Selection::from_c_int(-1).is_err());
+        /// ```
+        pub fn from_c_int(value: c_int) -> Result<Self, crate::OurError> {
+            Self::try_from(value as u32)
+        }
+    }
+
+    /// Renders the flags set in a [`Selection`] as their names separated by
+    /// `|` (e.g., `PRIVATE_KEY|PUBLIC_KEY`), as yielded by
+    /// [`Selection::iter_names()`] (provided by [`bitflags`]): composite flags
+    /// such as [`Selection::KEYPAIR`] are rendered as their components.
+    ///
+    /// Unknown bits are rendered as a hex value, and so is an empty
+    /// selection (`0x0`), so that the result can always be parsed back with
+    /// [`str::parse()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::operations::keymgmt::selection::Selection;
+    ///
+    /// let selection = Selection::KEYPAIR | Selection::DOMAIN_PARAMETERS;
+    /// assert_eq!(selection.to_string(), "PRIVATE_KEY|PUBLIC_KEY|DOMAIN_PARAMETERS");
+    ///
+    /// let names: Vec<_> = selection.iter_names().map(|(name, _)| name).collect();
+    /// assert_eq!(names, ["PRIVATE_KEY", "PUBLIC_KEY", "DOMAIN_PARAMETERS"]);
+    /// ```
+    impl std::fmt::Display for Selection {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.is_empty() {
+                return write!(f, "{:#x}", self.bits());
+            }
+            let mut first = true;
+            let mut separator = |f: &mut std::fmt::Formatter<'_>| {
+                if !std::mem::take(&mut first) {
+                    f.write_str("|")?;
+                }
+                Ok(())
+            };
+            let mut names = self.iter_names();
+            for (name, _) in names.by_ref() {
+                separator(f)?;
+                f.write_str(name)?;
+            }
+            let unknown = names.remaining();
+            if !unknown.is_empty() {
+                separator(f)?;
+                write!(f, "{:#x}", unknown.bits())?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Parses a [`Selection`] from flag names separated by `|`
+    /// (e.g., `"PRIVATE_KEY | PUBLIC_KEY"`), or from a hex value (e.g., `"0x3"`).
+    impl std::str::FromStr for Selection {
+        type Err = crate::OurError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            bitflags::parser::from_str(s)
+                .map_err(|e| anyhow::anyhow!("Invalid OSSL_KEYMGMT_SELECT flags {s:?}: {e}"))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::tests::common::OurError;
+
+        fn setup() -> Result<(), OurError> {
+            crate::tests::common::setup()
+        }
+
+        #[test]
+        fn test_from_name() {
+            setup().expect("setup() failed");
+
+            let expected = [
+                ("PRIVATE_KEY", Selection::PRIVATE_KEY),
+                ("PUBLIC_KEY", Selection::PUBLIC_KEY),
+                ("DOMAIN_PARAMETERS", Selection::DOMAIN_PARAMETERS),
+                ("OTHER_PARAMETERS", Selection::OTHER_PARAMETERS),
+                ("ALL_PARAMETERS", Selection::ALL_PARAMETERS),
+                ("KEYPAIR", Selection::KEYPAIR),
+                ("ALL", Selection::ALL),
+            ];
+            assert_eq!(Selection::all_names().len(), expected.len());
+            for (name, flag) in expected {
+                assert!(Selection::all_names().contains(&name));
+                let s = Selection::from_name(name).expect("known names should be mapped");
+                assert_eq!(s.bits(), flag.bits());
+            }
+        }
+
+        #[test]
+        fn test_from_name_unknown() {
+            setup().expect("setup() failed");
+
+            assert!(Selection::from_name("").is_none());
+            assert!(Selection::from_name("private_key").is_none());
+            assert!(Selection::from_name("NOT_A_FLAG").is_none());
+        }
+
+        #[test]
+        fn test_from_str() {
+            setup().expect("setup() failed");
+
+            let s: Selection = "PRIVATE_KEY | PUBLIC_KEY".parse().unwrap();
+            assert_eq!(s.bits(), Selection::KEYPAIR.bits());
+
+            assert!("PRIVATE_KEY | NOT_A_FLAG".parse::<Selection>().is_err());
+        }
+
+        #[test]
+        fn test_display() {
+            setup().expect("setup() failed");
+
+            assert_eq!(Selection::KEYPAIR.to_string(), "PRIVATE_KEY|PUBLIC_KEY");
+            assert_eq!(Selection::empty().to_string(), "0x0");
+            assert_eq!(
+                Selection::ALL.to_string(),
+                "PRIVATE_KEY|PUBLIC_KEY|DOMAIN_PARAMETERS|OTHER_PARAMETERS"
+            );
+            let unknown = Selection::PUBLIC_KEY | Selection::from_bits_retain(0x100);
+            assert_eq!(unknown.to_string(), "PUBLIC_KEY|0x100");
+
+            // the string form can be parsed back
+            for s in [
+                Selection::KEYPAIR,
+                Selection::empty(),
+                Selection::ALL,
+                unknown,
+            ] {
+                let parsed: Selection = s.to_string().parse().unwrap();
+                assert_eq!(parsed.bits(), s.bits());
+            }
+        }
+
+        #[test]
+        fn test_c_int_round_trip() {
+            setup().expect("setup() failed");
+
+            for name in Selection::all_names() {
+                let s = Selection::from_name(name).unwrap();
+                let round_trip = Selection::from_c_int(s.to_c_int()).unwrap();
+                assert_eq!(round_trip.bits(), s.bits());
+            }
+            assert_eq!(
+                Selection::from_c_int(Selection::KEYPAIR.to_c_int())
+                    .unwrap()
+                    .bits(),
+                Selection::KEYPAIR.bits()
+            );
+            assert_eq!(Selection::empty().to_c_int(), 0);
+            assert!(Selection::KEYPAIR.to_c_int() > 0);
+        }
+
+        #[test]
+        fn test_from_c_int_invalid() {
+            setup().expect("setup() failed");
+
+            assert!(Selection::from_c_int(-1).is_err());
+            assert!(Selection::from_c_int(c_int::MIN).is_err());
+            assert!(Selection::from_c_int(0x100).is_err());
+        }
+
+        #[test]
+        fn test_iter_names() {
+            setup().expect("setup() failed");
+
+            let names: Vec<_> = Selection::KEYPAIR
+                .iter_names()
+                .map(|(name, _)| name)
+                .collect();
+            assert_eq!(names, ["PRIVATE_KEY", "PUBLIC_KEY"]);
+            assert_eq!(Selection::empty().iter_names().count(), 0);
+        }
+    }
 }
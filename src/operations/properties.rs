@@ -0,0 +1,217 @@
+//! This module provides a simplified implementation of OpenSSL's
+//! [property query][property(7ossl)] matching, for providers which need to
+//! select among their own implementations.
+//!
+//! Only the following subset of the property syntax is supported:
+//!
+//! - definitions are comma-separated lists of `name=value` or `name` (short
+//!   for `name=yes`) items;
+//! - queries are comma-separated lists of clauses, each of which is either
+//!   _mandatory_ (`name=value`, `name!=value` or `name`) or _optional_ (a
+//!   mandatory clause prefixed by `?`).
+//!
+//! Names and unquoted values are compared case-insensitively, quoted values
+//! (using either `"` or `'`) are compared exactly.
+//! As in OpenSSL, a property which is missing from a definition is treated
+//! as having the value `no`.
+//!
+//! [property(7ossl)]: https://docs.openssl.org/master/man7/property/
+
+use std::ffi::CStr;
+
+/// A (possibly quoted) property value.
+#[derive(Debug, Clone, Copy)]
+enum Value<'a> {
+    Unquoted(&'a str),
+    Quoted(&'a str),
+}
+
+impl Value<'_> {
+    const NO: Value<'static> = Value::Unquoted("no");
+    const YES: Value<'static> = Value::Unquoted("yes");
+
+    fn parse(s: &str) -> Option<Value<'_>> {
+        let s = s.trim();
+        for q in ['"', '\''] {
+            if let Some(inner) = s.strip_prefix(q) {
+                return inner.strip_suffix(q).map(Value::Quoted);
+            }
+        }
+        if s.is_empty() {
+            return None;
+        }
+        Some(Value::Unquoted(s))
+    }
+
+    fn matches(self, other: Value<'_>) -> bool {
+        match (self, other) {
+            (Value::Unquoted(a), Value::Unquoted(b)) => a.eq_ignore_ascii_case(b),
+            (Value::Quoted(a), Value::Quoted(b))
+            | (Value::Quoted(a), Value::Unquoted(b))
+            | (Value::Unquoted(a), Value::Quoted(b)) => a == b,
+        }
+    }
+}
+
+/// Splits `name=value` (or just `name`) into its trimmed parts.
+fn parse_item(item: &str) -> Option<(&str, Value<'_>)> {
+    let (name, value) = match item.split_once('=') {
+        Some((name, value)) => (name.trim(), Value::parse(value)?),
+        None => (item.trim(), Value::YES),
+    };
+    if name.is_empty() {
+        return None;
+    }
+    Some((name, value))
+}
+
+fn parse_definition(definition: &str) -> Option<Vec<(&str, Value<'_>)>> {
+    definition
+        .split(',')
+        .filter(|item| !item.trim().is_empty())
+        .map(parse_item)
+        .collect()
+}
+
+/// Returns the _score_ of a property `definition` (i.e., the properties of an
+/// implementation) against a property `query`, or `None` if the definition
+/// does not satisfy the query.
+///
+/// Every satisfied clause of the query (either mandatory or optional) adds
+/// one point to the score, while an unsatisfied mandatory clause makes the
+/// whole match fail.
+/// When several implementations match a query, the one with the highest
+/// score should be preferred.
+///
+/// An empty query matches any definition with a score of `0`.
+/// `None` is also returned if either string is malformed (or not valid
+/// UTF-8).
+///
+/// See the [module documentation][`self`] for the supported syntax.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::operations::property_score;
+///
+/// let definition = c"provider=forge,fips=no,x.author='QUBIP'";
+///
+/// assert_eq!(property_score(c"provider=forge", definition), Some(1));
+/// assert_eq!(property_score(c"provider=default", definition), None);
+/// // optional clauses only contribute to the score
+/// assert_eq!(property_score(c"provider=forge,?x.author='QUBIP'", definition), Some(2));
+/// assert_eq!(property_score(c"provider=forge,?x.author='qubip'", definition), Some(1));
+/// ```
+pub fn property_score(query: &CStr, definition: &CStr) -> Option<i32> {
+    let query = query.to_str().ok()?;
+    let definition = parse_definition(definition.to_str().ok()?)?;
+
+    let mut score = 0;
+    for clause in query.split(',').filter(|c| !c.trim().is_empty()) {
+        let clause = clause.trim();
+        let (optional, clause) = match clause.strip_prefix('?') {
+            Some(rest) => (true, rest),
+            None => (false, clause),
+        };
+        let (name, wanted, negated) = match clause.split_once("!=") {
+            Some((name, value)) => (name.trim(), Value::parse(value)?, true),
+            None => {
+                let (name, value) = parse_item(clause)?;
+                (name, value, false)
+            }
+        };
+        if name.is_empty() {
+            return None;
+        }
+        let actual = definition
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map_or(Value::NO, |&(_, v)| v);
+
+        if actual.matches(wanted) != negated {
+            score += 1;
+        } else if !optional {
+            return None;
+        }
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::common::OurError;
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    const DEFINITION: &CStr = c"provider=forge, fips=yes, output=der";
+
+    #[test]
+    fn test_matching_query() {
+        setup().expect("setup() failed");
+
+        assert_eq!(property_score(c"provider=forge", DEFINITION), Some(1));
+        assert_eq!(
+            property_score(c"provider=forge,fips=yes,output=der", DEFINITION),
+            Some(3)
+        );
+        // names and unquoted values are case-insensitive
+        assert_eq!(property_score(c"Provider=FORGE", DEFINITION), Some(1));
+        // a bare name is short for name=yes
+        assert_eq!(property_score(c"fips", DEFINITION), Some(1));
+        assert_eq!(property_score(c"output!=pem", DEFINITION), Some(1));
+        // an empty query matches anything
+        assert_eq!(property_score(c"", DEFINITION), Some(0));
+    }
+
+    #[test]
+    fn test_mandatory_mismatch() {
+        setup().expect("setup() failed");
+
+        assert_eq!(property_score(c"provider=default", DEFINITION), None);
+        assert_eq!(property_score(c"provider=forge,fips=no", DEFINITION), None);
+        assert_eq!(property_score(c"output!=der", DEFINITION), None);
+        // a missing property is treated as `no`
+        assert_eq!(property_score(c"x.hw", DEFINITION), None);
+        assert_eq!(property_score(c"x.hw=no", DEFINITION), Some(1));
+    }
+
+    #[test]
+    fn test_optional_clauses() {
+        setup().expect("setup() failed");
+
+        // a satisfied optional clause contributes to the score...
+        assert_eq!(
+            property_score(c"provider=forge,?output=der", DEFINITION),
+            Some(2)
+        );
+        // ...while an unsatisfied one doesn't, without failing the match
+        assert_eq!(
+            property_score(c"provider=forge,?output=pem", DEFINITION),
+            Some(1)
+        );
+        assert_eq!(property_score(c"?x.hw", DEFINITION), Some(0));
+    }
+
+    #[test]
+    fn test_quoted_values() {
+        setup().expect("setup() failed");
+
+        let definition = c"x.author='QUBIP'";
+        assert_eq!(property_score(c"x.author=\"QUBIP\"", definition), Some(1));
+        assert_eq!(property_score(c"x.author=QUBIP", definition), Some(1));
+        assert_eq!(property_score(c"x.author='qubip'", definition), None);
+    }
+
+    #[test]
+    fn test_malformed() {
+        setup().expect("setup() failed");
+
+        assert_eq!(property_score(c"=forge", DEFINITION), None);
+        assert_eq!(property_score(c"provider=", DEFINITION), None);
+        assert_eq!(property_score(c"provider='forge", DEFINITION), None);
+        assert_eq!(property_score(c"provider=forge", c"=forge"), None);
+    }
+}
@@ -7,14 +7,43 @@
 //! - [provider-signature(7ossl)]
 //! - [provider(7ossl)]
 //!
+//! # Implementing the operation via RustCrypto traits
+//!
+//! A signature algorithm whose keys implement the RustCrypto [`Signer`] and
+//! [`Verifier`] traits (along with [`SignatureSize`]) can be offered without
+//! writing any `extern "C"` function, using the table returned by [`dispatch_table_for()`] as the
+//! `implementation` of its [`OSSL_ALGORITHM`][`crate::bindings::OSSL_ALGORITHM`].
+//!
 //! [provider(7ossl)]: https://docs.openssl.org/master/man7/provider/
 //! [provider(7ossl)#Operations]: https://docs.openssl.org/master/man7/provider/#operations
 //! [provider-signature(7ossl)]: https://docs.openssl.org/master/man7/provider-decoder/
 
 use std::error::Error;
+use std::ffi::{c_char, c_int, c_uchar, c_void};
+use std::marker::PhantomData;
 
 pub use crypto::signature::{SignatureEncoding, Signer, Verifier};
 
+use crate::bindings::{
+    OSSL_DISPATCH, OSSL_FUNC_SIGNATURE_FREECTX, OSSL_FUNC_SIGNATURE_NEWCTX,
+    OSSL_FUNC_SIGNATURE_SIGN, OSSL_FUNC_SIGNATURE_SIGN_INIT, OSSL_FUNC_SIGNATURE_VERIFY,
+    OSSL_FUNC_SIGNATURE_VERIFY_INIT, OSSL_PARAM,
+};
+use crate::{ffi_guard, handleResult, OurError};
+
+/// The value signature functions returning a pointer (`newctx`, `dupctx`)
+/// return on failure, i.e. the `ERROR_RET` to use with [`handleResult!`] (or
+/// the value to pass to [`handleResultRet!`][`crate::handleResultRet`]) in
+/// those functions.
+pub const ERROR_RET_PTR: *mut c_void = std::ptr::null_mut();
+
+/// The value signature functions returning an `int` (e.g., `sign_init`,
+/// `sign`, `verify_init`, `verify`, `get_ctx_params`, `set_ctx_params`)
+/// return on failure, i.e. the `ERROR_RET` to use with [`handleResult!`] (or
+/// the value to pass to [`handleResultRet!`][`crate::handleResultRet`]) in
+/// those functions.
+pub const ERROR_RET_INT: c_int = 0;
+
 #[derive(Debug)]
 pub enum VerificationError {
     InvalidSignature,
@@ -52,3 +81,572 @@ impl From<crypto::signature::Error> for VerificationError {
             })
     }
 }
+
+/// The error of the signature operation when producing a signature.
+///
+/// It keeps the [`crypto::signature::Error`] returned by the [`Signer`] as
+/// its [`source()`][`Error::source`], so that logging the error shows the
+/// actual cause of the failure.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::error::Error;
+/// use openssl_provider_forge::operations::signature::SigningError;
+///
+/// let err = SigningError::from(crypto::signature::Error::new());
+/// assert!(err.source().unwrap().is::<crypto::signature::Error>());
+/// assert_eq!(err.ossl_return_code(), 0);
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SigningError {
+    /// The [`Signer`] failed.
+    Signer(crypto::signature::Error),
+    /// The signature buffer has room for `available` bytes, but the
+    /// signature is `needed` bytes long.
+    BufferTooSmall {
+        /// The length of the signature.
+        needed: usize,
+        /// The size of the signature buffer.
+        available: usize,
+    },
+}
+
+impl SigningError {
+    /// Returns the value to return to OpenSSL from the `sign` function of the
+    /// operation when this error occurs.
+    pub const fn ossl_return_code(&self) -> c_int {
+        0
+    }
+}
+
+impl core::fmt::Display for SigningError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        match self {
+            SigningError::Signer(e) => write!(f, "error: signing failed: {e}"),
+            SigningError::BufferTooSmall { needed, available } => write!(
+                f,
+                "error: the signature buffer is too small ({available} < {needed})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SigningError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SigningError::Signer(e) => Some(e),
+            SigningError::BufferTooSmall { .. } => None,
+        }
+    }
+}
+
+impl From<crypto::signature::Error> for SigningError {
+    fn from(value: crypto::signature::Error) -> Self {
+        SigningError::Signer(value)
+    }
+}
+
+/// The size of the signatures produced by a key, needed by the `sign`
+/// function of [`dispatch_table_for()`] to answer the signature size queries
+/// of OpenSSL without signing.
+///
+/// This matters to stateful signature schemes (e.g., XMSS or LMS), where
+/// each signature consumes a one-time key.
+pub trait SignatureSize {
+    /// Returns the maximum length, in bytes, of an encoded signature by this
+    /// key.
+    fn max_signature_size(&self) -> usize;
+}
+
+/// Returns an END-terminated [`OSSL_DISPATCH`] table implementing the
+/// signature operation (`newctx`, `freectx`, `sign_init`, `sign`,
+/// `verify_init` and `verify`) by delegating to the [`Signer`] and
+/// [`Verifier`] implementations of `S`.
+///
+/// The key objects passed by OpenSSL to `sign_init` and `verify_init` (i.e.,
+/// the ones created by the key management of the algorithm) must be pointers
+/// to `S` values, e.g., as returned by [`Box::into_raw()`], which must
+/// outlive the operation contexts they are used with.
+///
+/// The generated functions take care of the OpenSSL conventions:
+///
+/// - `sign` reports the [maximum signature size][`SignatureSize`], without
+///   signing, when the signature buffer is `NULL`, and fails without signing
+///   if the buffer is smaller than that;
+/// - `verify` returns `0` both when the signature cannot be decoded as `Sig`
+///   and when it does not match the message;
+/// - the params passed to `sign_init` and `verify_init` are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::bindings::{OSSL_ALGORITHM, OSSL_DISPATCH};
+/// use openssl_provider_forge::operations::signature::*;
+/// # struct Key;
+/// # #[derive(Clone)]
+/// # struct Sig([u8; 1]);
+/// # impl TryFrom<&[u8]> for Sig {
+/// #     type Error = ();
+/// #     fn try_from(b: &[u8]) -> Result<Self, ()> { b.try_into().map(Sig).map_err(|_| ()) }
+/// # }
+/// # impl From<Sig> for [u8; 1] { fn from(s: Sig) -> Self { s.0 } }
+/// # impl SignatureEncoding for Sig { type Repr = [u8; 1]; }
+/// # impl Signer<Sig> for Key {
+/// #     fn try_sign(&self, _: &[u8]) -> Result<Sig, crypto::signature::Error> { Ok(Sig([0])) }
+/// # }
+/// # impl Verifier<Sig> for Key {
+/// #     fn verify(&self, _: &[u8], _: &Sig) -> Result<(), crypto::signature::Error> { Ok(()) }
+/// # }
+/// # impl SignatureSize for Key { fn max_signature_size(&self) -> usize { 1 } }
+///
+/// let table: &'static [OSSL_DISPATCH] = dispatch_table_for::<Key, Sig>();
+/// let algorithm = OSSL_ALGORITHM::new(c"TOY", c"provider=toy", table);
+/// ```
+pub fn dispatch_table_for<S, Sig>() -> &'static [OSSL_DISPATCH]
+where
+    S: Signer<Sig> + Verifier<Sig> + SignatureSize + 'static,
+    Sig: SignatureEncoding + 'static,
+{
+    DispatchTable::<S, Sig>::TABLE
+}
+
+/// The signature operation context of the functions generated by
+/// [`dispatch_table_for()`].
+struct SignatureCtx<S> {
+    key: *const S,
+}
+
+impl<S> SignatureCtx<S> {
+    /// Converts the `ctx` passed by OpenSSL back to a [`SignatureCtx`].
+    fn from_raw<'a>(ctx: *mut c_void) -> Result<&'a mut Self, OurError> {
+        // SAFETY: `ctx` has been created by `newctx()` for the same `S`.
+        unsafe { (ctx as *mut Self).as_mut() }
+            .ok_or_else(|| anyhow::anyhow!("NULL signature operation context"))
+    }
+
+    /// Stores the key passed to `sign_init` or `verify_init`, keeping the
+    /// previous one if `provkey` is `NULL` (as allowed by OpenSSL).
+    fn init(&mut self, provkey: *mut c_void) -> Result<(), OurError> {
+        if !provkey.is_null() {
+            self.key = provkey as *const S;
+        }
+        self.key().map(|_| ())
+    }
+
+    fn key(&self) -> Result<&S, OurError> {
+        // SAFETY: the key objects are `S` values outliving the context (see
+        // `dispatch_table_for()`).
+        unsafe { self.key.as_ref() }.ok_or_else(|| anyhow::anyhow!("No key has been set"))
+    }
+}
+
+/// Returns the `len` bytes at `ptr`, treating a `NULL` `ptr` as empty.
+fn bytes<'a>(ptr: *const c_uchar, len: usize) -> Result<&'a [u8], OurError> {
+    if ptr.is_null() {
+        if len != 0 {
+            return Err(anyhow::anyhow!("NULL buffer with length {len}"));
+        }
+        return Ok(&[]);
+    }
+    // SAFETY: OpenSSL passes buffers of (at least) `len` bytes.
+    Ok(unsafe { std::slice::from_raw_parts(ptr, len) })
+}
+
+// The signatures of the functions in the table, as specified by
+// provider-signature(7ossl).
+type GenericFn = unsafe extern "C" fn();
+type NewCtxFn = unsafe extern "C" fn(*mut c_void, *const c_char) -> *mut c_void;
+type FreeCtxFn = unsafe extern "C" fn(*mut c_void);
+type InitFn = unsafe extern "C" fn(*mut c_void, *mut c_void, *const OSSL_PARAM) -> c_int;
+type SignFn = unsafe extern "C" fn(
+    *mut c_void,
+    *mut c_uchar,
+    *mut usize,
+    usize,
+    *const c_uchar,
+    usize,
+) -> c_int;
+type VerifyFn =
+    unsafe extern "C" fn(*mut c_void, *const c_uchar, usize, *const c_uchar, usize) -> c_int;
+
+/// Holds the dispatch table returned by [`dispatch_table_for()`], as an
+/// associated constant (since statics cannot be generic).
+struct DispatchTable<S, Sig>(PhantomData<(S, Sig)>);
+
+impl<S, Sig> DispatchTable<S, Sig>
+where
+    S: Signer<Sig> + Verifier<Sig> + SignatureSize + 'static,
+    Sig: SignatureEncoding + 'static,
+{
+    const TABLE: &'static [OSSL_DISPATCH] = &[
+        OSSL_DISPATCH::new(
+            OSSL_FUNC_SIGNATURE_NEWCTX as c_int,
+            Some(unsafe { std::mem::transmute::<NewCtxFn, GenericFn>(Self::newctx) }),
+        ),
+        OSSL_DISPATCH::new(
+            OSSL_FUNC_SIGNATURE_FREECTX as c_int,
+            Some(unsafe { std::mem::transmute::<FreeCtxFn, GenericFn>(Self::freectx) }),
+        ),
+        OSSL_DISPATCH::new(
+            OSSL_FUNC_SIGNATURE_SIGN_INIT as c_int,
+            Some(unsafe { std::mem::transmute::<InitFn, GenericFn>(Self::init) }),
+        ),
+        OSSL_DISPATCH::new(
+            OSSL_FUNC_SIGNATURE_SIGN as c_int,
+            Some(unsafe { std::mem::transmute::<SignFn, GenericFn>(Self::sign) }),
+        ),
+        OSSL_DISPATCH::new(
+            OSSL_FUNC_SIGNATURE_VERIFY_INIT as c_int,
+            Some(unsafe { std::mem::transmute::<InitFn, GenericFn>(Self::init) }),
+        ),
+        OSSL_DISPATCH::new(
+            OSSL_FUNC_SIGNATURE_VERIFY as c_int,
+            Some(unsafe { std::mem::transmute::<VerifyFn, GenericFn>(Self::verify) }),
+        ),
+        OSSL_DISPATCH::END,
+    ];
+
+    unsafe extern "C" fn newctx(_provctx: *mut c_void, _propq: *const c_char) -> *mut c_void {
+        const ERROR_RET: *mut c_void = std::ptr::null_mut();
+        ffi_guard! {
+            let ctx = SignatureCtx::<S> {
+                key: std::ptr::null(),
+            };
+            Box::into_raw(Box::new(ctx)) as *mut c_void
+        }
+    }
+
+    unsafe extern "C" fn freectx(ctx: *mut c_void) {
+        const ERROR_RET: () = ();
+        ffi_guard! {
+            if !ctx.is_null() {
+                // SAFETY: `ctx` has been created by `newctx()`.
+                drop(unsafe { Box::from_raw(ctx as *mut SignatureCtx<S>) });
+            }
+        }
+    }
+
+    unsafe extern "C" fn init(
+        ctx: *mut c_void,
+        provkey: *mut c_void,
+        _params: *const OSSL_PARAM,
+    ) -> c_int {
+        const ERROR_RET: c_int = 0;
+        ffi_guard! {
+            let ctx = handleResult!(SignatureCtx::<S>::from_raw(ctx));
+            handleResult!(ctx.init(provkey));
+            1
+        }
+    }
+
+    unsafe extern "C" fn sign(
+        ctx: *mut c_void,
+        sig: *mut c_uchar,
+        siglen: *mut usize,
+        sigsize: usize,
+        tbs: *const c_uchar,
+        tbslen: usize,
+    ) -> c_int {
+        const ERROR_RET: c_int = 0;
+        ffi_guard! {
+            let ctx = handleResult!(SignatureCtx::<S>::from_raw(ctx));
+            let key = handleResult!(ctx.key());
+            if siglen.is_null() {
+                log::error!("NULL siglen");
+                return ERROR_RET;
+            }
+            let max_size = key.max_signature_size();
+            if sig.is_null() {
+                // SAFETY: we just checked that `siglen` is not NULL
+                unsafe { *siglen = max_size };
+                return 1;
+            }
+            if sigsize < max_size {
+                let e = SigningError::BufferTooSmall {
+                    needed: max_size,
+                    available: sigsize,
+                };
+                log::error!("{e}");
+                return e.ossl_return_code();
+            }
+            let tbs = handleResult!(bytes(tbs, tbslen));
+            let signature: Sig = handleResult!(key.try_sign(tbs).map_err(SigningError::from));
+            let encoded = signature.to_bytes();
+            let encoded = encoded.as_ref();
+            if sigsize < encoded.len() {
+                let e = SigningError::BufferTooSmall {
+                    needed: encoded.len(),
+                    available: sigsize,
+                };
+                log::error!("{e}");
+                return e.ossl_return_code();
+            }
+            // SAFETY: `sig` points at a buffer of (at least) `sigsize` bytes.
+            unsafe { std::ptr::copy_nonoverlapping(encoded.as_ptr(), sig, encoded.len()) };
+            // SAFETY: we just checked that `siglen` is not NULL
+            unsafe { *siglen = encoded.len() };
+            1
+        }
+    }
+
+    unsafe extern "C" fn verify(
+        ctx: *mut c_void,
+        sig: *const c_uchar,
+        siglen: usize,
+        tbs: *const c_uchar,
+        tbslen: usize,
+    ) -> c_int {
+        const ERROR_RET: c_int = 0;
+        ffi_guard! {
+            let ctx = handleResult!(SignatureCtx::<S>::from_raw(ctx));
+            let key = handleResult!(ctx.key());
+            let tbs = handleResult!(bytes(tbs, tbslen));
+            let Ok(signature) = Sig::try_from(handleResult!(bytes(sig, siglen))) else {
+                log::debug!("The signature could not be decoded");
+                return ERROR_RET;
+            };
+            match key.verify(tbs, &signature) {
+                Ok(()) => 1,
+                Err(e) => {
+                    log::debug!("Signature verification failed: {e}");
+                    ERROR_RET
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::common::OurError;
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    /// A toy key, "signing" a message with a keyed checksum, and counting
+    /// the signatures it made.
+    struct ToyKey {
+        seed: u8,
+        signed: std::cell::Cell<usize>,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct ToySignature([u8; 2]);
+
+    impl TryFrom<&[u8]> for ToySignature {
+        type Error = crypto::signature::Error;
+
+        fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+            bytes
+                .try_into()
+                .map(ToySignature)
+                .map_err(|_| crypto::signature::Error::new())
+        }
+    }
+
+    impl From<ToySignature> for [u8; 2] {
+        fn from(sig: ToySignature) -> Self {
+            sig.0
+        }
+    }
+
+    impl SignatureEncoding for ToySignature {
+        type Repr = [u8; 2];
+    }
+
+    impl ToyKey {
+        fn new(seed: u8) -> Self {
+            ToyKey {
+                seed,
+                signed: Default::default(),
+            }
+        }
+
+        fn checksum(&self, msg: &[u8]) -> ToySignature {
+            let sum = msg.iter().fold(self.seed, |acc, b| acc.wrapping_add(*b));
+            ToySignature([self.seed, sum])
+        }
+    }
+
+    impl Signer<ToySignature> for ToyKey {
+        fn try_sign(&self, msg: &[u8]) -> Result<ToySignature, crypto::signature::Error> {
+            self.signed.set(self.signed.get() + 1);
+            Ok(self.checksum(msg))
+        }
+    }
+
+    impl SignatureSize for ToyKey {
+        fn max_signature_size(&self) -> usize {
+            2
+        }
+    }
+
+    impl Verifier<ToySignature> for ToyKey {
+        fn verify(&self, msg: &[u8], sig: &ToySignature) -> Result<(), crypto::signature::Error> {
+            if self.checksum(msg) == *sig {
+                Ok(())
+            } else {
+                Err(crypto::signature::Error::new())
+            }
+        }
+    }
+
+    /// Looks up the function with `id` in `table`, as OpenSSL does.
+    fn lookup<F: Copy>(table: &[OSSL_DISPATCH], id: u32) -> F {
+        assert_eq!(size_of::<F>(), size_of::<GenericFn>());
+        let f = table
+            .iter()
+            .take_while(|d| d.function_id != 0)
+            .find(|d| d.function_id == id as c_int)
+            .and_then(|d| d.function)
+            .expect("missing dispatch table entry");
+        unsafe { std::mem::transmute_copy(&f) }
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        setup().expect("setup() failed");
+
+        let table = dispatch_table_for::<ToyKey, ToySignature>();
+        assert!(table.last().is_some_and(|d| d.function_id == 0));
+
+        let newctx: NewCtxFn = lookup(table, OSSL_FUNC_SIGNATURE_NEWCTX);
+        let freectx: FreeCtxFn = lookup(table, OSSL_FUNC_SIGNATURE_FREECTX);
+        let sign_init: InitFn = lookup(table, OSSL_FUNC_SIGNATURE_SIGN_INIT);
+        let sign: SignFn = lookup(table, OSSL_FUNC_SIGNATURE_SIGN);
+        let verify_init: InitFn = lookup(table, OSSL_FUNC_SIGNATURE_VERIFY_INIT);
+        let verify: VerifyFn = lookup(table, OSSL_FUNC_SIGNATURE_VERIFY);
+
+        let mut key = ToyKey::new(7);
+        let key_ptr = std::ptr::from_mut(&mut key).cast::<c_void>();
+        let msg = b"hello";
+
+        unsafe {
+            let ctx = newctx(std::ptr::null_mut(), std::ptr::null());
+            assert!(!ctx.is_null());
+
+            // signing without a key fails
+            let mut siglen = 0usize;
+            let ret = sign(
+                ctx,
+                std::ptr::null_mut(),
+                &mut siglen,
+                0,
+                msg.as_ptr(),
+                msg.len(),
+            );
+            assert_eq!(ret, 0);
+
+            assert_eq!(sign_init(ctx, key_ptr, std::ptr::null()), 1);
+
+            // a NULL signature buffer only reports the length, without signing
+            let ret = sign(
+                ctx,
+                std::ptr::null_mut(),
+                &mut siglen,
+                0,
+                msg.as_ptr(),
+                msg.len(),
+            );
+            assert_eq!(ret, 1);
+            assert_eq!(siglen, 2);
+            assert_eq!(key.signed.get(), 0);
+
+            // a too small buffer is rejected
+            let mut sig = [0u8; 4];
+            let ret = sign(
+                ctx,
+                sig.as_mut_ptr(),
+                &mut siglen,
+                1,
+                msg.as_ptr(),
+                msg.len(),
+            );
+            assert_eq!(ret, 0);
+            assert_eq!(key.signed.get(), 0);
+
+            let ret = sign(
+                ctx,
+                sig.as_mut_ptr(),
+                &mut siglen,
+                sig.len(),
+                msg.as_ptr(),
+                msg.len(),
+            );
+            assert_eq!(ret, 1);
+            assert_eq!(siglen, 2);
+            assert_eq!(&sig[..siglen], key.checksum(msg).0);
+            assert_eq!(key.signed.get(), 1);
+
+            // a NULL key keeps the previous one
+            assert_eq!(verify_init(ctx, std::ptr::null_mut(), std::ptr::null()), 1);
+            assert_eq!(
+                verify(ctx, sig.as_ptr(), siglen, msg.as_ptr(), msg.len()),
+                1
+            );
+
+            // a tampered message, or a malformed signature, fail verification
+            let other = b"hellp";
+            assert_eq!(
+                verify(ctx, sig.as_ptr(), siglen, other.as_ptr(), other.len()),
+                0
+            );
+            assert_eq!(verify(ctx, sig.as_ptr(), 1, msg.as_ptr(), msg.len()), 0);
+
+            freectx(ctx);
+        }
+    }
+
+    #[test]
+    fn test_verify_with_other_key() {
+        setup().expect("setup() failed");
+
+        let table = dispatch_table_for::<ToyKey, ToySignature>();
+        let newctx: NewCtxFn = lookup(table, OSSL_FUNC_SIGNATURE_NEWCTX);
+        let freectx: FreeCtxFn = lookup(table, OSSL_FUNC_SIGNATURE_FREECTX);
+        let verify_init: InitFn = lookup(table, OSSL_FUNC_SIGNATURE_VERIFY_INIT);
+        let verify: VerifyFn = lookup(table, OSSL_FUNC_SIGNATURE_VERIFY);
+
+        let msg = b"hello";
+        let sig = ToyKey::new(7).checksum(msg).0;
+        let mut other = ToyKey::new(8);
+
+        unsafe {
+            let ctx = newctx(std::ptr::null_mut(), std::ptr::null());
+            let key_ptr = std::ptr::from_mut(&mut other).cast::<c_void>();
+            assert_eq!(verify_init(ctx, key_ptr, std::ptr::null()), 1);
+            assert_eq!(
+                verify(ctx, sig.as_ptr(), sig.len(), msg.as_ptr(), msg.len()),
+                0
+            );
+            freectx(ctx);
+        }
+    }
+
+    #[test]
+    fn test_signing_error_source() {
+        setup().expect("setup() failed");
+
+        let cause = VerificationError::GenericVerificationError;
+        let err = SigningError::from(crypto::signature::Error::from_source(cause));
+        assert_eq!(err.ossl_return_code(), 0);
+
+        // the original error is the source, and its own cause is preserved
+        let source = err
+            .source()
+            .and_then(|e| e.downcast_ref::<crypto::signature::Error>())
+            .expect("the source should be the original error");
+        assert!(source.source().is_some_and(|e| e.is::<VerificationError>()));
+
+        let err = SigningError::BufferTooSmall {
+            needed: 2,
+            available: 1,
+        };
+        assert!(err.source().is_none());
+        assert!(err.to_string().contains("(1 < 2)"), "{err}");
+    }
+}
@@ -1,4 +1,5 @@
 //! This module provides utilities for [`decoder`][provider-decoder(7ossl)]
+//! and [`encoder`][provider-encoder(7ossl)]
 //! [Operations][provider(7ossl)#Operations] in the context of
 //! [OpenSSL Providers][provider(7ossl)].
 //!
@@ -10,14 +11,17 @@
 //! # References
 //!
 //! - [provider-decoder(7ossl)]
+//! - [provider-encoder(7ossl)]
 //! - [provider(7ossl)]
 //!
 //!
 //! [provider(7ossl)]: https://docs.openssl.org/master/man7/provider/
 //! [provider(7ossl)#Operations]: https://docs.openssl.org/master/man7/provider/#operations
 //! [provider-decoder(7ossl)]: https://docs.openssl.org/master/man7/provider-decoder/
+//! [provider-encoder(7ossl)]: https://docs.openssl.org/master/man7/provider-encoder/
 
 pub use crate::decoder_make_does_selection_fn as make_does_selection_fn;
+pub use crate::encoder_make_does_selection_fn as make_encoder_does_selection_fn;
 
 use super::keymgmt::selection::Selection;
 use crate::bindings::CStr;
@@ -40,8 +44,8 @@ pub trait DoesSelection {
     fn does_selection(selection: Selection) -> bool {
         log::trace!("Called!");
 
-        log::trace!("selection: {:#b}", selection);
-        log::trace!("we're offering: {:#b}", Self::SELECTION_MASK);
+        log::trace!("selection: {selection}");
+        log::trace!("we're offering: {}", Self::SELECTION_MASK);
 
         if selection.is_empty() {
             return Self::SUPPORT_GUESSING;
@@ -63,10 +67,10 @@ pub trait DoesSelection {
 }
 
 mod macros {
+    #[doc(hidden)]
     #[macro_export]
-    macro_rules! decoder_make_does_selection_fn {
-        ( $fn_name:ident, $decoder_type:ty ) => {
-            // based on oqsprov/oqs_decode_der2key.c:der2key_check_selection() in the OQS provider
+    macro_rules! __hidden__make_does_selection_fn {
+        ( $fn_name:ident, $transcoder_type:ty ) => {
             pub(super) unsafe extern "C" fn $fn_name(
                 vprovctx: *mut c_void,
                 selection: c_int,
@@ -76,18 +80,102 @@ mod macros {
 
                 const _: fn() = || {
                     fn assert_impl<T: DoesSelection>() {}
-                    assert_impl::<$decoder_type>();
+                    assert_impl::<$transcoder_type>();
                 };
 
                 let _provctx: &OpenSSLProvider<'_> = $crate::handleResult!(vprovctx.try_into());
 
-                let selection = $crate::handleResult!(Selection::try_from(selection as u32));
+                let selection = $crate::handleResult!(Selection::from_c_int(selection));
 
-                match <$decoder_type>::does_selection(selection) {
+                match <$transcoder_type>::does_selection(selection) {
                     true => return 1,
                     false => return 0,
                 }
             }
         };
     }
+
+    #[macro_export]
+    macro_rules! decoder_make_does_selection_fn {
+        ( $fn_name:ident, $decoder_type:ty ) => {
+            // based on oqsprov/oqs_decode_der2key.c:der2key_check_selection() in the OQS provider
+            $crate::__hidden__make_does_selection_fn!($fn_name, $decoder_type);
+        };
+    }
+
+    /// Generates the `extern "C"` `does_selection` function of an encoder
+    /// (i.e., its `OSSL_FUNC_ENCODER_DOES_SELECTION` dispatch table entry),
+    /// answering through the [`DoesSelection`][`super::DoesSelection`]
+    /// implementation of `$encoder_type`.
+    ///
+    /// This is the encoder counterpart of
+    /// [`make_does_selection_fn`][`super::make_does_selection_fn`], and it
+    /// has the same requirements: `c_void`, `c_int`, `DoesSelection`,
+    /// `Selection`, and the provider context type `OpenSSLProvider` (which
+    /// must implement `TryFrom<*mut c_void>` for `&OpenSSLProvider`) must be
+    /// in scope.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::bindings::{
+    ///     c_int, c_void, CStr, OSSL_DISPATCH, OSSL_FUNC_ENCODER_DOES_SELECTION,
+    /// };
+    /// use openssl_provider_forge::operations::keymgmt::selection::Selection;
+    /// use openssl_provider_forge::operations::transcoders::{DoesSelection, Encoder};
+    ///
+    /// // the provider context
+    /// pub struct OpenSSLProvider<'a>(std::marker::PhantomData<&'a ()>);
+    ///
+    /// impl<'a> TryFrom<*mut c_void> for &'a OpenSSLProvider<'a> {
+    ///     type Error = &'static str;
+    ///
+    ///     fn try_from(vprovctx: *mut c_void) -> Result<Self, Self::Error> {
+    ///         unsafe { (vprovctx as *const OpenSSLProvider).as_ref() }.ok_or("NULL provctx")
+    ///     }
+    /// }
+    ///
+    /// mod encoder {
+    ///     use super::*;
+    ///     use openssl_provider_forge::operations::transcoders;
+    ///
+    ///     pub struct PublicKeyEncoder;
+    ///
+    ///     impl DoesSelection for PublicKeyEncoder {
+    ///         const SELECTION_MASK: Selection = Selection::PUBLIC_KEY;
+    ///     }
+    ///
+    ///     transcoders::make_encoder_does_selection_fn!(does_selection, PublicKeyEncoder);
+    ///
+    ///     impl Encoder for PublicKeyEncoder {
+    ///         const PROPERTY_DEFINITION: &'static CStr = c"x.author='QUBIP',output=der";
+    ///         const DISPATCH_TABLE: &'static [OSSL_DISPATCH] = &[
+    ///             OSSL_DISPATCH::new(OSSL_FUNC_ENCODER_DOES_SELECTION as c_int, unsafe {
+    ///                 Some(openssl_provider_forge::bindings::generic_non_null_fn_ptr!(
+    ///                     does_selection
+    ///                 ))
+    ///             }),
+    ///             OSSL_DISPATCH::END,
+    ///         ];
+    ///     }
+    ///
+    ///     pub(super) fn call(provctx: &OpenSSLProvider, selection: Selection) -> c_int {
+    ///         let vprovctx = provctx as *const OpenSSLProvider as *mut c_void;
+    ///         unsafe { does_selection(vprovctx, selection.to_c_int()) }
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let provctx = OpenSSLProvider(std::marker::PhantomData);
+    ///     assert_eq!(encoder::call(&provctx, Selection::PUBLIC_KEY), 1);
+    ///     assert_eq!(encoder::call(&provctx, Selection::PRIVATE_KEY), 0);
+    ///     assert_eq!(encoder::PublicKeyEncoder::DISPATCH_TABLE.len(), 2);
+    /// }
+    /// ```
+    #[macro_export]
+    macro_rules! encoder_make_does_selection_fn {
+        ( $fn_name:ident, $encoder_type:ty ) => {
+            $crate::__hidden__make_does_selection_fn!($fn_name, $encoder_type);
+        };
+    }
 }
@@ -5,6 +5,22 @@ use std::ffi::{c_int, c_void};
 
 type InnerCB = unsafe extern "C" fn(params: *const OSSL_PARAM, arg: *mut c_void) -> c_int;
 
+/// The signal returned by [`OSSLCallback::call_checked`] when the callback
+/// asks to stop (i.e., it returns `0`).
+///
+/// This is not necessarily a failure: e.g., a core callback enumerating
+/// capabilities may stop once it found what it was looking for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallbackStop;
+
+impl std::fmt::Display for CallbackStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the callback requested to stop")
+    }
+}
+
+impl std::error::Error for CallbackStop {}
+
 pub struct OSSLCallback {
     cb_fn: InnerCB,
     args: *mut c_void,
@@ -25,4 +41,80 @@ impl OSSLCallback {
         let cb_fn = self.cb_fn;
         unsafe { cb_fn(params, self.args) }
     }
+
+    /// Like [`OSSLCallback::call`], but maps the `0` ("stop") return value
+    /// of the callback to [`CallbackStop`], so that enumeration loops can use
+    /// `?` to break out.
+    ///
+    /// Any other return value means "continue".
+    pub fn call_checked(&self, params: *const OSSL_PARAM) -> Result<(), CallbackStop> {
+        match self.call(params) {
+            0 => Err(CallbackStop),
+            _ => Result::Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::osslparams::{OSSLParam, CONST_OSSL_PARAM};
+
+    fn setup() -> Result<(), OurError> {
+        crate::tests::common::setup()
+    }
+
+    unsafe extern "C" fn continue_cb(_params: *const OSSL_PARAM, arg: *mut c_void) -> c_int {
+        let calls = unsafe { &mut *(arg as *mut usize) };
+        *calls += 1;
+        1
+    }
+
+    unsafe extern "C" fn stop_cb(_params: *const OSSL_PARAM, arg: *mut c_void) -> c_int {
+        let calls = unsafe { &mut *(arg as *mut usize) };
+        *calls += 1;
+        0
+    }
+
+    /// Calls `cb` with the same params 3 times, stopping with `?` as soon as
+    /// it asks to.
+    fn call_three_times(cb: &OSSLCallback) -> Result<(), CallbackStop> {
+        let params = [
+            OSSLParam::new_const_int(c"foo", Some(&1i32)),
+            CONST_OSSL_PARAM::END,
+        ];
+        for _ in 0..3 {
+            cb.call_checked(params.as_ptr() as *const OSSL_PARAM)?;
+        }
+        Result::Ok(())
+    }
+
+    #[test]
+    fn test_call_checked_continue() {
+        setup().expect("setup() failed");
+
+        let mut calls = 0usize;
+        let cb = OSSLCallback::try_new(Some(continue_cb), std::ptr::from_mut(&mut calls).cast())
+            .unwrap();
+        assert_eq!(call_three_times(&cb), Result::Ok(()));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_call_checked_stop() {
+        setup().expect("setup() failed");
+
+        let mut calls = 0usize;
+        let cb =
+            OSSLCallback::try_new(Some(stop_cb), std::ptr::from_mut(&mut calls).cast()).unwrap();
+        assert_eq!(call_three_times(&cb), Err(CallbackStop));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_null_callback() {
+        setup().expect("setup() failed");
+
+        assert!(OSSLCallback::try_new(None, std::ptr::null_mut()).is_err());
+    }
 }
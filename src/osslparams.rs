@@ -5,21 +5,36 @@
 //! [OSSL_PARAM(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM/
 
 use std::{
-    ffi::{c_char, CStr},
+    ffi::{c_char, CStr, CString},
     marker::PhantomData,
+    ptr::NonNull,
 };
 
 // We re-export related definitions from the FFI bindings, as they are generally
 // of use to users of this module.
 pub use crate::bindings::{
-    OSSL_PARAM, OSSL_PARAM_INTEGER, OSSL_PARAM_OCTET_STRING, OSSL_PARAM_UNMODIFIED,
-    OSSL_PARAM_UNSIGNED_INTEGER, OSSL_PARAM_UTF8_PTR, OSSL_PARAM_UTF8_STRING,
+    OSSL_PARAM, OSSL_PARAM_INTEGER, OSSL_PARAM_OCTET_PTR, OSSL_PARAM_OCTET_STRING,
+    OSSL_PARAM_UNMODIFIED, OSSL_PARAM_UNSIGNED_INTEGER, OSSL_PARAM_UTF8_PTR,
+    OSSL_PARAM_UTF8_STRING,
 };
-// FIXME: We should re-export this as well, once we actually use it....
-#[expect(unused_imports)]
-use crate::bindings::OSSL_PARAM_OCTET_PTR;
 
+pub mod coerce;
+pub mod convert;
 pub mod data;
+pub mod error;
+pub mod legacy;
+pub mod owned;
+pub mod redact;
+
+pub use coerce::OSSLParamCoercedGetter;
+pub use convert::{FromOsslParams, ParamField, ToOsslParams};
+pub use error::OSSLParamError;
+use owned::ParamStorage;
+pub use owned::{
+    descriptor_from, merge_descriptors, OSSLParamBuilder, OwnedConstParams, OwnedParam,
+    OwnedParamList, OwnedParamValue,
+};
+pub use redact::{add_sensitive_key, is_sensitive_key, remove_sensitive_key};
 
 #[cfg(test)]
 mod tests;
@@ -93,7 +108,24 @@ pub enum OSSLParam<'a> {
     ///
     /// [OSSL_PARAM(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM/
     OctetString(OctetStringData<'a>),
-    // FIXME: support for OctetPtr is currently missing
+
+    /// Represents a [OSSL_PARAM(3ossl)] of type [`OSSL_PARAM_OCTET_PTR`]:
+    ///
+    /// > The parameter data is a pointer to an arbitrary string of bytes.
+    ///
+    /// [OSSL_PARAM(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM/
+    OctetPtr(OctetPtrData<'a>),
+
+    /// Represents a [OSSL_PARAM(3ossl)] of type `OSSL_PARAM_REAL`:
+    ///
+    /// > The parameter data is a floating point value in native form.
+    ///
+    /// Only available when building against OpenSSL headers defining
+    /// `OSSL_PARAM_REAL`.
+    ///
+    /// [OSSL_PARAM(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM/
+    #[cfg(ossl_param_type = "real")]
+    Real(RealData<'a>),
 }
 
 impl<'a> OSSLParam<'a> {
@@ -107,19 +139,44 @@ impl<'a> OSSLParam<'a> {
     ///   * [`None`] will create a new `NULL` [`CONST_OSSL_PARAM`]
     ///   * `Some(_)` will set the inner value of the new [`CONST_OSSL_PARAM`]
     ///
+    /// Unlike an [`OSSLParam::Utf8String`], the `data` of an
+    /// [`OSSLParam::Utf8Ptr`] points at a _pointer_ to the string, which is
+    /// provided by a [`ConstUtf8Ptr`]. Its `data_size` is the length of the
+    /// string (not counting the terminating NUL byte).
+    ///
     /// # Examples
     ///
-    /// ## TODO(🛠️): add examples (tracked by: [#6](https://gitlab.com/nisec/qubip/openssl-provider-forge-rs/-/issues/6))
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    /// use std::ffi::CStr;
+    ///
+    /// const NAME: ConstUtf8Ptr = ConstUtf8Ptr::new(c"aurora");
+    /// const PARAMS: &[CONST_OSSL_PARAM] = &[
+    ///     OSSLParam::new_const_utf8ptr(c"name", Some(&NAME)),
+    ///     OSSLParam::new_const_utf8ptr(c"null", None),
+    ///     CONST_OSSL_PARAM::END,
+    /// ];
     ///
-    pub const fn new_const_utf8ptr(key: &'a KeyType, value: Option<&'a CStr>) -> CONST_OSSL_PARAM {
+    /// let p = OSSLParam::try_from(&PARAMS[0]).unwrap();
+    /// assert!(matches!(p, OSSLParam::Utf8Ptr(_)));
+    /// assert_eq!(p.get::<&CStr>(), Some(c"aurora"));
+    /// assert_eq!(PARAMS[0].data_size, 6);
+    ///
+    /// // `None` creates a `NULL` param
+    /// let p = OSSLParam::try_from(&PARAMS[1]).unwrap();
+    /// assert!(PARAMS[1].data.is_null());
+    /// assert_eq!(p.get::<&CStr>(), None);
+    /// ```
+    pub const fn new_const_utf8ptr(
+        key: &'a KeyType,
+        value: Option<&'a ConstUtf8Ptr<'a>>,
+    ) -> CONST_OSSL_PARAM {
         let (data, data_size) = match value {
             Some(value) => {
-                //let v = value.as_ptr();
-                //let v = v as *mut std::ffi::c_void;
-                //let sz = value.count_bytes();
-                //(v, sz)
-                let _ = value;
-                todo!()
+                let v = std::ptr::from_ref(value);
+                let v = v as *mut std::ffi::c_void;
+                let sz = value.value.count_bytes();
+                (v, sz)
             }
             None => (std::ptr::null_mut(), 0),
         };
@@ -252,8 +309,19 @@ impl<'a> OSSLParam<'a> {
     ///
     /// # Examples
     ///
-    /// ## TODO(🛠️): add examples (tracked by: [#6](https://gitlab.com/nisec/qubip/openssl-provider-forge-rs/-/issues/6))
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    /// use std::ffi::c_char;
+    ///
+    /// const SEED: [c_char; 4] = [0x0a, 0x0b, 0x0c, 0x0d];
+    /// const PARAMS: &[CONST_OSSL_PARAM] = &[
+    ///     OSSLParam::new_const_octetstring(c"seed", Some(&SEED)),
+    ///     CONST_OSSL_PARAM::END,
+    /// ];
     ///
+    /// let p = OSSLParam::try_from(&PARAMS[0]).unwrap();
+    /// assert_eq!(p.get::<&[u8]>(), Some([0x0a, 0x0b, 0x0c, 0x0d].as_slice()));
+    /// ```
     pub const fn new_const_octetstring(
         key: &'a KeyType,
         value: Option<&'a [c_char]>,
@@ -276,25 +344,201 @@ impl<'a> OSSLParam<'a> {
         }
     }
 
-    // FIXME: what about octetptr?
+    /// Creates a new [`OSSLParam::Int`] referencing `value`, for transient use.
+    ///
+    /// Unlike the `new_const_*` constructors, which return raw
+    /// [`CONST_OSSL_PARAM`]s, the returned [`OSSLParam`] borrows `value`
+    /// (and `key`), so the borrow checker prevents it from outliving them.
+    /// This makes it the safe choice to build params pointing at local
+    /// variables.
+    ///
+    /// Setting a value on the returned [`OSSLParam`] updates `value`.
+    ///
+    /// The underlying [`OSSL_PARAM`] struct is owned by the returned
+    /// [`OSSLParam`], and freed when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut bits = 2048i64;
+    /// {
+    ///     let mut p = OSSLParam::with_int(c"bits", &mut bits);
+    ///     assert_eq!(p.get::<i64>(), Some(2048));
+    ///     assert!(p.set(4096i64).is_ok());
+    /// }
+    /// assert_eq!(bits, 4096);
+    /// ```
+    ///
+    /// The param cannot escape the scope of the value it references:
+    ///
+    /// ```compile_fail
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let p = {
+    ///     let mut bits = 2048i64;
+    ///     OSSLParam::with_int(c"bits", &mut bits)
+    /// }; // ERROR: `bits` does not live long enough
+    /// assert_eq!(p.get::<i64>(), Some(2048));
+    /// ```
+    pub fn with_int(key: &'a KeyType, value: &'a mut i64) -> Self {
+        OSSLParam::Int(IntData {
+            param: Box::leak(Box::new(OSSL_PARAM {
+                key: key.as_ptr(),
+                data_type: OSSL_PARAM_INTEGER,
+                data: std::ptr::from_mut(value).cast(),
+                data_size: size_of::<i64>(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            }))
+            .into(),
+            // only the `OSSL_PARAM` is owned, `value` is borrowed
+            ownership: Ownership::ParamOnly,
+        })
+    }
+
+    /// Creates a new [`OSSLParam::UInt`] referencing `value`, for transient use.
+    ///
+    /// This is the unsigned counterpart of [`OSSLParam::with_int`], and the
+    /// same considerations apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut bits = 128u64;
+    /// let p = OSSLParam::with_uint(c"security-bits", &mut bits);
+    /// assert_eq!(p.get::<u64>(), Some(128));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let p = {
+    ///     let mut bits = 128u64;
+    ///     OSSLParam::with_uint(c"security-bits", &mut bits)
+    /// }; // ERROR: `bits` does not live long enough
+    /// assert_eq!(p.get::<u64>(), Some(128));
+    /// ```
+    pub fn with_uint(key: &'a KeyType, value: &'a mut u64) -> Self {
+        OSSLParam::UInt(UIntData {
+            param: Box::leak(Box::new(OSSL_PARAM {
+                key: key.as_ptr(),
+                data_type: OSSL_PARAM_UNSIGNED_INTEGER,
+                data: std::ptr::from_mut(value).cast(),
+                data_size: size_of::<u64>(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            }))
+            .into(),
+            // only the `OSSL_PARAM` is owned, `value` is borrowed
+            ownership: Ownership::ParamOnly,
+        })
+    }
+}
+
+/// Whether the `*Data` structs own the [`OSSL_PARAM`] they wrap, see
+/// `impl_drop_owned_param!`.
+#[derive(Debug)]
+enum Ownership {
+    /// The [`OSSL_PARAM`] is borrowed (from C, or from other Rust code), and
+    /// never freed.
+    Borrowed,
+    /// The [`OSSL_PARAM`] has been allocated as a `Box<OSSL_PARAM>` and
+    /// leaked, and is freed on drop; its key and data are borrowed.
+    ParamOnly,
+    /// The [`OSSL_PARAM`] has been allocated as a `Box<OSSL_PARAM>` and
+    /// leaked, and is freed on drop along with the storage backing its key
+    /// and data (see `OSSLParam::from_owned()`).
+    Owned { _storage: ParamStorage },
+}
+
+/// The pointer to the [`OSSL_PARAM`] wrapped by the `*Data` structs.
+///
+/// Unlike a `&'a mut OSSL_PARAM`, this does not claim unique access to the
+/// param, which may have been converted from a read-only source (e.g., a
+/// [`CONST_OSSL_PARAM`] or a `*const OSSL_PARAM`): the param is only
+/// borrowed mutably by [`ParamPtr::as_mut`], when setting its value.
+struct ParamPtr<'a> {
+    ptr: NonNull<OSSL_PARAM>,
+    _marker: PhantomData<&'a mut OSSL_PARAM>,
+}
+
+impl ParamPtr<'_> {
+    /// Wraps `p`, returning `None` if it is `NULL`.
+    ///
+    /// # Safety
+    ///
+    /// A non-`NULL` `p` must point to a valid [`OSSL_PARAM`], for the whole
+    /// lifetime of the returned value.
+    unsafe fn from_raw(p: *mut OSSL_PARAM) -> Option<Self> {
+        NonNull::new(p).map(|ptr| ParamPtr {
+            ptr,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the wrapped pointer, with its original provenance.
+    fn as_ptr(&self) -> *mut OSSL_PARAM {
+        self.ptr.as_ptr()
+    }
+
+    /// Borrows the wrapped param mutably.
+    ///
+    /// # Safety
+    ///
+    /// The param must be writable, i.e., it must not have been converted from
+    /// a read-only source.
+    unsafe fn as_mut(&mut self) -> &mut OSSL_PARAM {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<'a> From<&'a mut OSSL_PARAM> for ParamPtr<'a> {
+    fn from(p: &'a mut OSSL_PARAM) -> Self {
+        ParamPtr {
+            ptr: NonNull::from(p),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl std::ops::Deref for ParamPtr<'_> {
+    type Target = OSSL_PARAM;
+
+    fn deref(&self) -> &OSSL_PARAM {
+        // SAFETY: the pointer is valid for `'a` (see `ParamPtr::from_raw()`),
+        // and reading the param never requires unique access.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl std::fmt::Debug for ParamPtr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (**self).fmt(f)
+    }
 }
 
 /// This is an inner type, to represent in Rust the contents of an [`OSSL_PARAM`]
 /// of [`Utf8Ptr`][`OSSLParam::Utf8Ptr`] type.
 #[derive(Debug)]
 pub struct Utf8PtrData<'a> {
-    param: &'a mut OSSL_PARAM,
+    param: ParamPtr<'a>,
+    /// Whether `param` is owned, and freed on drop.
+    ownership: Ownership,
 }
 
 /// This is an inner type, to represent in Rust the contents of an [`OSSL_PARAM`]
 /// of [`Utf8String`][`OSSLParam::Utf8String`] type.
 pub struct Utf8StringData<'a> {
-    param: &'a mut OSSL_PARAM,
+    param: ParamPtr<'a>,
+    /// Whether `param` is owned, and freed on drop.
+    ownership: Ownership,
 }
 
 impl std::fmt::Debug for Utf8StringData<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let p = OSSLParam::try_from(self.param as *const OSSL_PARAM);
+        let p = OSSLParam::try_from(self.param.as_ptr().cast_const());
         match p {
             Ok(p) => {
                 let v: Option<&CStr> = p.get();
@@ -315,12 +559,14 @@ impl std::fmt::Debug for Utf8StringData<'_> {
 /// This is an inner type, to represent in Rust the contents of an [`OSSL_PARAM`]
 /// of [`Int`][`OSSLParam::Int`] type.
 pub struct IntData<'a> {
-    param: &'a mut OSSL_PARAM,
+    param: ParamPtr<'a>,
+    /// Whether `param` is owned, and freed on drop.
+    ownership: Ownership,
 }
 
 impl std::fmt::Debug for IntData<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let p = OSSLParam::try_from(self.param as *const OSSL_PARAM);
+        let p = OSSLParam::try_from(self.param.as_ptr().cast_const());
         match p {
             Ok(p) => {
                 let v: Option<i64> = p.get();
@@ -341,12 +587,14 @@ impl std::fmt::Debug for IntData<'_> {
 /// This is an inner type, to represent in Rust the contents of an [`OSSL_PARAM`]
 /// of [`UInt`][`OSSLParam::UInt`] type.
 pub struct UIntData<'a> {
-    param: &'a mut OSSL_PARAM,
+    param: ParamPtr<'a>,
+    /// Whether `param` is owned, and freed on drop.
+    ownership: Ownership,
 }
 
 impl std::fmt::Debug for UIntData<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let p = OSSLParam::try_from(self.param as *const OSSL_PARAM);
+        let p = OSSLParam::try_from(self.param.as_ptr().cast_const());
         match p {
             Ok(p) => {
                 let v: Option<u64> = p.get();
@@ -368,12 +616,29 @@ impl std::fmt::Debug for UIntData<'_> {
 /// This is an inner type, to represent in Rust the contents of an [`OSSL_PARAM`]
 /// of [`OctetString`][`OSSLParam::OctetString`] type.
 pub struct OctetStringData<'a> {
-    param: &'a mut OSSL_PARAM,
+    param: ParamPtr<'a>,
+    /// Whether `param` is owned, and freed on drop.
+    ownership: Ownership,
+}
+
+#[derive(Debug)]
+/// This is an inner type, to represent in Rust the contents of an [`OSSL_PARAM`]
+/// of [`OctetPtr`][`OSSLParam::OctetPtr`] type.
+pub struct OctetPtrData<'a> {
+    param: ParamPtr<'a>,
+    /// Whether `param` is owned, and freed on drop.
+    ownership: Ownership,
 }
 
-/// A type alias used for returning descriptive error messages in operations
-/// involving [`OSSLParam`].
-pub type OSSLParamError = String;
+#[cfg(ossl_param_type = "real")]
+#[derive(Debug)]
+/// This is an inner type, to represent in Rust the contents of an [`OSSL_PARAM`]
+/// of [`Real`][`OSSLParam::Real`] type.
+pub struct RealData<'a> {
+    param: ParamPtr<'a>,
+    /// Whether `param` is owned, and freed on drop.
+    ownership: Ownership,
+}
 
 /// A type alias to represent the [`key`][`CONST_OSSL_PARAM::key`] field of an [`OSSL_PARAM`].
 ///
@@ -406,6 +671,21 @@ impl<'a> OSSLParam<'a> {
     /// (`i32`, `i64`, `u32`, etc.). If the data pointer is `NULL` or the conversion fails,
     /// an appropriate error is returned.
     ///
+    /// # Zero-size data
+    ///
+    /// Setting a value into a non-`NULL` `data` buffer whose `data_size` is `0`
+    /// always fails with an [`OSSLParamError::BufferTooSmall`] error (see
+    /// [`is_buffer_too_small`]), as no value fits in it.
+    /// This does not apply to the pointer types ([`OSSLParam::Utf8Ptr`] and
+    /// [`OSSLParam::OctetPtr`]), whose `data` always points at a pointer.
+    ///
+    /// # Read-only params
+    ///
+    /// Setting a value writes to the wrapped [`OSSL_PARAM`] (and to its
+    /// `data`), so it must never be done on a param converted from a
+    /// read-only source, such as a [`CONST_OSSL_PARAM`] or the
+    /// `*const OSSL_PARAM` received by a `get_params()`-style function.
+    ///
     /// # Examples
     ///
     /// ## TODO(🛠️): add examples (tracked by: [#7](https://gitlab.com/nisec/qubip/openssl-provider-forge-rs/-/issues/7))
@@ -428,6 +708,20 @@ impl<'a> OSSLParam<'a> {
     ///
     /// Returns `Some(T)` if the value matches the type, otherwise returns `None`.
     ///
+    /// # Zero-size data
+    ///
+    /// A param with a non-`NULL` `data` but a `data_size` of `0` holds no
+    /// bytes, so it is read consistently as an _empty or absent_ value:
+    ///
+    /// * [`OSSLParam::Int`] and [`OSSLParam::UInt`] have no value (`None`);
+    /// * [`OSSLParam::OctetString`] has an empty value (`Some(&[])`);
+    /// * [`OSSLParam::Utf8String`] has an empty value (`Some(c"")`), without
+    ///   reading the buffer, which may not even hold a terminating NUL byte.
+    ///
+    /// For the pointer types ([`OSSLParam::Utf8Ptr`] and
+    /// [`OSSLParam::OctetPtr`]) the `data_size` describes the pointed value, so
+    /// a `0` size is simply an empty value.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -454,6 +748,50 @@ impl<'a> OSSLParam<'a> {
         self.get_inner()
     }
 
+    /// Like [`OSSLParam::get`], but distinguishes a param without a value from
+    /// a param with a value of the wrong type.
+    ///
+    /// Note that, unlike a lookup in a list of params, this cannot report
+    /// absent params: it is up to the caller to handle a missing key.
+    ///
+    /// # Return value
+    ///
+    /// * `Ok(Some(T))` if the param has a value of type `T`;
+    /// * `Ok(None)` if the param has `NULL` data (e.g., in a size query);
+    /// * an [`OSSLParamError`] if the value cannot be read as type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let p = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+    /// let param = OSSLParam::try_from(&p).unwrap();
+    /// assert_eq!(param.get_present::<i64>(), Ok(Some(2048)));
+    /// assert!(param.get_present::<u64>().is_err());
+    ///
+    /// let p = OSSLParam::new_const_int::<i64>(c"bits", None);
+    /// let param = OSSLParam::try_from(&p).unwrap();
+    /// assert_eq!(param.get_present::<i64>(), Ok(None));
+    /// ```
+    pub fn get_present<T>(&self) -> Result<Option<T>, OSSLParamError>
+    where
+        Self: OSSLParamGetter<T>,
+    {
+        // SAFETY: the pointer wrapped by an `OSSLParam` is never NULL
+        let data = unsafe { (*self.get_c_struct()).data };
+        if data.is_null() {
+            return Ok(None);
+        }
+        match self.get_inner() {
+            Some(value) => Ok(Some(value)),
+            None => Err(OSSLParamError::TypeMismatch {
+                expected: self.variant_name(),
+                got: std::any::type_name::<T>().to_string(),
+            }),
+        }
+    }
+
     /// Retrieves the C FFI representation of this [`OSSLParam`], regardless of its variant.
     ///
     /// # Return value
@@ -483,11 +821,14 @@ impl<'a> OSSLParam<'a> {
     ///
     pub fn get_c_struct(&self) -> *const OSSL_PARAM {
         match self {
-            OSSLParam::Utf8Ptr(d) => d.param,
-            OSSLParam::Utf8String(d) => d.param,
-            OSSLParam::Int(d) => d.param,
-            OSSLParam::UInt(d) => d.param,
-            OSSLParam::OctetString(d) => d.param,
+            OSSLParam::Utf8Ptr(d) => d.param.as_ptr(),
+            OSSLParam::Utf8String(d) => d.param.as_ptr(),
+            OSSLParam::Int(d) => d.param.as_ptr(),
+            OSSLParam::UInt(d) => d.param.as_ptr(),
+            OSSLParam::OctetString(d) => d.param.as_ptr(),
+            OSSLParam::OctetPtr(d) => d.param.as_ptr(),
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(d) => d.param.as_ptr(),
         }
     }
 
@@ -513,11 +854,49 @@ impl<'a> OSSLParam<'a> {
     ///
     pub fn get_c_struct_mut(&mut self) -> *mut OSSL_PARAM {
         match self {
-            OSSLParam::Utf8Ptr(d) => d.param,
-            OSSLParam::Utf8String(d) => d.param,
-            OSSLParam::Int(d) => d.param,
-            OSSLParam::UInt(d) => d.param,
-            OSSLParam::OctetString(d) => d.param,
+            OSSLParam::Utf8Ptr(d) => d.param.as_ptr(),
+            OSSLParam::Utf8String(d) => d.param.as_ptr(),
+            OSSLParam::Int(d) => d.param.as_ptr(),
+            OSSLParam::UInt(d) => d.param.as_ptr(),
+            OSSLParam::OctetString(d) => d.param.as_ptr(),
+            OSSLParam::OctetPtr(d) => d.param.as_ptr(),
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(d) => d.param.as_ptr(),
+        }
+    }
+
+    /// Returns the ownership of the param wrapped by the inner data.
+    fn ownership_mut(&mut self) -> &mut Ownership {
+        match self {
+            OSSLParam::Utf8Ptr(d) => &mut d.ownership,
+            OSSLParam::Utf8String(d) => &mut d.ownership,
+            OSSLParam::Int(d) => &mut d.ownership,
+            OSSLParam::UInt(d) => &mut d.ownership,
+            OSSLParam::OctetString(d) => &mut d.ownership,
+            OSSLParam::OctetPtr(d) => &mut d.ownership,
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(d) => &mut d.ownership,
+        }
+    }
+
+    /// Wraps `param` in an [`OSSLParam`] owning it, along with the `storage`
+    /// backing its key and data: both are freed when the [`OSSLParam`] is
+    /// dropped.
+    pub(crate) fn from_owned(
+        param: OSSL_PARAM,
+        storage: ParamStorage,
+    ) -> Result<Self, OSSLParamError> {
+        let ptr = Box::into_raw(Box::new(param));
+        match OSSLParam::try_from(ptr) {
+            Ok(mut p) => {
+                *p.ownership_mut() = Ownership::Owned { _storage: storage };
+                Ok(p)
+            }
+            Err(e) => {
+                // SAFETY: `ptr` has just been allocated, and is not wrapped.
+                drop(unsafe { Box::from_raw(ptr) });
+                Err(e)
+            }
         }
     }
 
@@ -562,24 +941,125 @@ impl<'a> OSSLParam<'a> {
         Some(k)
     }
 
+    /// Checks that the [`key`][`CONST_OSSL_PARAM::key`] of this [`OSSLParam`]
+    /// matches the expected `key`.
+    ///
+    /// # Return value
+    ///
+    /// Returns `Ok(self)` if the key matches, so that it can be used in chains,
+    /// otherwise returns an [`OSSLParamError`] naming both keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// # fn main() -> Result<(), OSSLParamError> {
+    /// let p = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+    /// let param = OSSLParam::try_from(&p)?;
+    ///
+    /// assert_eq!(param.expect_key(c"bits")?.get::<i64>(), Some(2048));
+    /// assert!(param.expect_key(c"size").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expect_key(&self, key: &KeyType) -> Result<&Self, OSSLParamError> {
+        match self.get_key() {
+            Some(k) if k == key => Ok(self),
+            Some(k) => Err(OSSLParamError::Other(format!(
+                "Expected param key {key:?}, found {k:?}"
+            ))),
+            None => Err(OSSLParamError::Other(format!(
+                "Expected param key {key:?}, found END item"
+            ))),
+        }
+    }
+
     /// Returns the value of the [`data_type`][`CONST_OSSL_PARAM::data_type`] field
     /// of the underlying [`OSSL_PARAM`] structure.
     ///
     /// # Return value
     ///
-    /// > ## 🛠️ TODO
-    /// >
-    /// > Document in which cases we get `Some(_)` and when the user should expect a `None`
+    /// Returns [`None`] if the underlying struct pointer is `NULL`, or if it
+    /// is an END item (i.e., its [`key`][`CONST_OSSL_PARAM::key`] is `NULL`),
+    /// and `Some(data_type)` otherwise.
     ///
     /// # Examples
     ///
-    /// ## TODO(🛠️): add examples (tracked by: [#9](https://gitlab.com/nisec/qubip/openssl-provider-forge-rs/-/issues/9))
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut bits: i64 = 2048;
+    /// let mut raw_param = OSSL_PARAM {
+    ///     key: c"bits".as_ptr(),
+    ///     data_type: OSSL_PARAM_INTEGER,
+    ///     data: std::ptr::from_mut(&mut bits).cast(),
+    ///     data_size: size_of::<i64>(),
+    ///     return_size: OSSL_PARAM_UNMODIFIED,
+    /// };
+    /// let param = OSSLParam::try_from(&mut raw_param).unwrap();
+    /// assert_eq!(param.get_data_type(), Some(OSSL_PARAM_INTEGER));
+    ///
+    /// // a NULL key marks the end of a list, so there is no data type to report
+    /// raw_param.key = std::ptr::null();
+    /// let param = OSSLParam::try_from(&mut raw_param).unwrap();
+    /// assert_eq!(param.get_data_type(), None);
+    /// ```
     pub fn get_data_type(&self) -> Option<u32> {
         let cptr: *const OSSL_PARAM = self.get_c_struct();
-        // FIXME: cptr could be NULL
-        let r = &(unsafe { *cptr });
+        if cptr.is_null() {
+            return None;
+        }
+        // SAFETY: we just checked that the struct pointer is not NULL
+        let r = unsafe { &*cptr };
+        if r.key.is_null() {
+            return None;
+        }
         Some(r.data_type)
-        // FIXME: should we return None if cptr is NULL or if it is an END item (i.e., its `key` is NULL)?
+    }
+
+    /// Returns the [`ParamType`] matching the raw
+    /// [`data_type`][`CONST_OSSL_PARAM::data_type`] of the underlying
+    /// [`OSSL_PARAM`] structure, or [`None`] if it is not a known data type.
+    ///
+    /// See [`OSSLParam::variant_type`] for the type implied by the variant.
+    pub fn param_type(&self) -> Option<ParamType> {
+        ParamType::from_data_type(self.get_data_type()?)
+    }
+
+    /// Returns the [`ParamType`] implied by the variant of this [`OSSLParam`]
+    /// (e.g., [`ParamType::Integer`] for [`OSSLParam::Int`]), regardless of
+    /// the raw [`data_type`][`CONST_OSSL_PARAM::data_type`].
+    ///
+    /// As [`OSSLParam::try_from`] picks the variant according to the
+    /// `data_type`, for a well-formed param this always agrees with
+    /// [`OSSLParam::param_type`].
+    /// A disagreement means that the underlying [`OSSL_PARAM`] has been
+    /// modified (or corrupted) after being wrapped, so its data must not be
+    /// trusted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let p = OSSLParam::new_const_utf8string(c"group", Some(c"x25519"));
+    /// let param = OSSLParam::try_from(&p).unwrap();
+    ///
+    /// assert_eq!(param.variant_type(), ParamType::Utf8String);
+    /// assert_eq!(param.param_type(), Some(param.variant_type()));
+    /// ```
+    pub fn variant_type(&self) -> ParamType {
+        match self {
+            OSSLParam::Utf8Ptr(_) => ParamType::Utf8Ptr,
+            OSSLParam::Utf8String(_) => ParamType::Utf8String,
+            OSSLParam::Int(_) => ParamType::Integer,
+            OSSLParam::UInt(_) => ParamType::UnsignedInteger,
+            OSSLParam::OctetString(_) => ParamType::OctetString,
+            OSSLParam::OctetPtr(_) => ParamType::OctetPtr,
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(_) => ParamType::Real,
+        }
     }
 
     /// Checks if this _parameter_ has been modified.
@@ -589,9 +1069,28 @@ impl<'a> OSSLParam<'a> {
     ///
     /// It corresponds to [OSSL_PARAM_modified(3ossl)].
     ///
+    /// Returns `false` if the underlying struct pointer is `NULL` (as
+    /// [`OSSLParam::get_key`] returns `None`).
+    ///
     /// # Examples
     ///
-    /// ## TODO(🛠️): add examples (tracked by: [#10](https://gitlab.com/nisec/qubip/openssl-provider-forge-rs/-/issues/10))
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut bits = 0i32;
+    /// let mut p = OSSL_PARAM {
+    ///     key: c"bits".as_ptr(),
+    ///     data_type: OSSL_PARAM_INTEGER,
+    ///     data: std::ptr::from_mut(&mut bits).cast(),
+    ///     data_size: size_of::<i32>(),
+    ///     return_size: OSSL_PARAM_UNMODIFIED,
+    /// };
+    /// let mut param = OSSLParam::try_from(&mut p).unwrap();
+    /// assert!(!param.modified());
+    ///
+    /// param.set(2048i32).unwrap();
+    /// assert!(param.modified());
+    /// ```
     ///
     /// [OSSL_PARAM_modified(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM_modified/
     //
@@ -600,11 +1099,117 @@ impl<'a> OSSLParam<'a> {
     // from the constant `OSSL_PARAM_UNMODIFIED`,
     // the parameter is considered to have been modified.
     pub fn modified(&mut self) -> bool {
-        // FIXME: could the struct pointer be NULL?
-        //        We should always perform check,
-        //        or comment on why they are not necessary,
-        //        before any unsafe block.
-        unsafe { (*self.get_c_struct()).return_size != OSSL_PARAM_UNMODIFIED }
+        let cptr: *const OSSL_PARAM = self.get_c_struct();
+        if cptr.is_null() {
+            return false;
+        }
+        // SAFETY: we just checked that the struct pointer is not NULL
+        unsafe { (*cptr).return_size != OSSL_PARAM_UNMODIFIED }
+    }
+
+    /// Resets the [`return_size`][`CONST_OSSL_PARAM::return_size`] of the
+    /// underlying [`OSSL_PARAM`] structure to [`OSSL_PARAM_UNMODIFIED`] if it
+    /// holds a value no setter could have stored, so that
+    /// [`OSSLParam::modified`] is meaningful afterwards.
+    ///
+    /// Callers usually pass response params with the `return_size` already
+    /// at [`OSSL_PARAM_UNMODIFIED`], but this is not guaranteed, so this is
+    /// meant to be called before filling a response.
+    /// A `return_size` is considered invalid if it is larger than
+    /// `isize::MAX` (the size of the largest possible object), or, for data
+    /// types other than the `*_PTR` ones, if it is larger than the
+    /// `data_size` of a non-`NULL` buffer.
+    ///
+    /// As a failed set reports the size needed in `return_size` (which may
+    /// then exceed `data_size`), this must not be called after filling the
+    /// response.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut bits = 0i32;
+    /// let mut p = OSSL_PARAM {
+    ///     key: c"bits".as_ptr(),
+    ///     data_type: OSSL_PARAM_INTEGER,
+    ///     data: std::ptr::from_mut(&mut bits).cast(),
+    ///     data_size: size_of::<i32>(),
+    ///     return_size: 0xdead_beef, // garbage
+    /// };
+    /// let mut param = OSSLParam::try_from(&mut p).unwrap();
+    /// assert!(param.modified());
+    ///
+    /// param.normalize_return_size();
+    /// assert!(!param.modified());
+    /// ```
+    pub fn normalize_return_size(&mut self) {
+        let cptr: *mut OSSL_PARAM = self.get_c_struct_mut();
+        if cptr.is_null() {
+            return;
+        }
+        // SAFETY: we just checked that the struct pointer is not NULL
+        let r = unsafe { &mut *cptr };
+        if r.return_size == OSSL_PARAM_UNMODIFIED {
+            return;
+        }
+        let is_ptr = matches!(r.data_type, OSSL_PARAM_UTF8_PTR | OSSL_PARAM_OCTET_PTR);
+        let invalid = r.return_size > isize::MAX as usize
+            || (!is_ptr && !r.data.is_null() && r.return_size > r.data_size);
+        if invalid {
+            log::debug!(
+                "Resetting the invalid return_size {} of OSSL_PARAM {:?}",
+                r.return_size,
+                error_key(r)
+            );
+            r.return_size = OSSL_PARAM_UNMODIFIED;
+        }
+    }
+
+    /// Returns the sizes of the underlying [`OSSL_PARAM`] structure along
+    /// with whether it has been modified (see [`OSSLParam::modified`]), as a
+    /// single [`SizeInfo`], e.g. to implement the size-query protocol.
+    ///
+    /// Returns [`None`] if the underlying struct pointer is `NULL`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let mut p = OSSL_PARAM {
+    ///     key: c"name".as_ptr(),
+    ///     data_type: OSSL_PARAM_UTF8_STRING,
+    ///     data: buf.as_mut_ptr().cast(),
+    ///     data_size: buf.len(),
+    ///     return_size: OSSL_PARAM_UNMODIFIED,
+    /// };
+    /// let mut param = OSSLParam::try_from(&mut p).unwrap();
+    /// assert!(!param.size_info().unwrap().modified);
+    ///
+    /// param.set(c"x25519").unwrap();
+    /// assert_eq!(
+    ///     param.size_info(),
+    ///     Some(SizeInfo {
+    ///         capacity: 16,
+    ///         returned: 6,
+    ///         modified: true
+    ///     })
+    /// );
+    /// ```
+    pub fn size_info(&self) -> Option<SizeInfo> {
+        let cptr: *const OSSL_PARAM = self.get_c_struct();
+        if cptr.is_null() {
+            return None;
+        }
+        // SAFETY: we just checked that the struct pointer is not NULL
+        let r = unsafe { &*cptr };
+        Some(SizeInfo {
+            capacity: r.data_size,
+            returned: r.return_size,
+            modified: r.return_size != OSSL_PARAM_UNMODIFIED,
+        })
     }
 
     /// Retrieves the name of the enum variant as a `String`.
@@ -645,7 +1250,7 @@ impl<'a> OSSLParam<'a> {
     ///     CONST_OSSL_PARAM::END
     /// ];
     ///
-    /// let params = OSSLParam::try_from(&params_list[0]).unwrap();
+    /// let params = OSSLParam::try_from(params_list.as_slice()).unwrap();
     ///
     /// let mut counter = 0;
     /// for p in params {
@@ -682,6 +1287,51 @@ impl<'a> OSSLParam<'a> {
     }
 }
 
+/// Two [`OSSLParam`]s are equal if they have the same key, the same
+/// [`data_type`][`CONST_OSSL_PARAM::data_type`], and the same decoded value,
+/// regardless of the size of their buffers (e.g., an `i32` and an `i64`
+/// holding the same number are equal).
+///
+/// Params of different variants are never equal, and neither are params
+/// whose value cannot be decoded (e.g., with `NULL` data) and params which
+/// can.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let narrow = OSSLParam::new_const_int(c"bits", Some(&2048i32));
+/// let wide = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+/// let unsigned = OSSLParam::new_const_uint(c"bits", Some(&2048u64));
+///
+/// let narrow = OSSLParam::try_from(&narrow).unwrap();
+/// assert!(narrow == OSSLParam::try_from(&wide).unwrap());
+/// assert!(narrow != OSSLParam::try_from(&unsigned).unwrap());
+/// ```
+impl PartialEq for OSSLParam<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.get_key() != other.get_key() || self.get_data_type() != other.get_data_type() {
+            return false;
+        }
+        match (self, other) {
+            (OSSLParam::Int(_), OSSLParam::Int(_)) => self.get::<i128>() == other.get::<i128>(),
+            (OSSLParam::UInt(_), OSSLParam::UInt(_)) => self.get::<u64>() == other.get::<u64>(),
+            (OSSLParam::Utf8Ptr(_), OSSLParam::Utf8Ptr(_))
+            | (OSSLParam::Utf8String(_), OSSLParam::Utf8String(_)) => {
+                self.get::<&CStr>() == other.get::<&CStr>()
+            }
+            (OSSLParam::OctetString(_), OSSLParam::OctetString(_))
+            | (OSSLParam::OctetPtr(_), OSSLParam::OctetPtr(_)) => {
+                self.get::<&[u8]>() == other.get::<&[u8]>()
+            }
+            #[cfg(ossl_param_type = "real")]
+            (OSSLParam::Real(_), OSSLParam::Real(_)) => self.get::<f64>() == other.get::<f64>(),
+            _ => false,
+        }
+    }
+}
+
 /// A trait for setting type-safe values on the inner data of an [`OSSLParam`] enum.
 ///
 /// This trait ensures type safety when setting values on [`OSSLParam`].
@@ -737,6 +1387,9 @@ pub trait OSSLParamGetter<T> {
 pub trait OSSLParamData {
     /// This function returns an OSSLParam of the given type and using the given key, but setting its value to NULL.
     ///
+    /// The returned value owns the allocated [`OSSL_PARAM`] (and its data
+    /// buffer, if any), which are freed when it is dropped.
+    ///
     /// # Examples
     ///
     /// ## TODO(🛠️): add examples (tracked by: [#12](https://gitlab.com/nisec/qubip/openssl-provider-forge-rs/-/issues/12))
@@ -770,32 +1423,81 @@ pub trait TypedOSSLParamData<T>: OSSLParamData {
     fn set(&mut self, value: T) -> Result<(), OSSLParamError>;
 }
 
-macro_rules! setter_type_err_string {
+macro_rules! setter_type_err {
     ($param:expr, $value:ident) => {
-        format!(
-            "Type {} could not be stored in OSSLParam::{}",
-            std::any::type_name_of_val(&$value),
-            $param.variant_name()
-        )
+        $crate::osslparams::OSSLParamError::TypeMismatch {
+            expected: $param.variant_name(),
+            got: std::any::type_name_of_val(&$value).to_string(),
+        }
     };
 }
-pub(crate) use setter_type_err_string;
+pub(crate) use setter_type_err;
 
 macro_rules! new_null_param {
     ($constructor:ident, $data_type:ident, $key:expr) => {
+        new_null_param!($constructor, $data_type, $key, 0)
+    };
+    ($constructor:ident, $data_type:ident, $key:expr, $data_size:expr) => {{
+        let mut storage = crate::osslparams::owned::ParamStorage::default();
+        let data_size: usize = $data_size;
+        let data = if data_size == 0 {
+            std::ptr::null_mut()
+        } else {
+            storage.store_bytes(&vec![0u8; data_size], false)
+        };
         $constructor {
             param: Box::leak(Box::new(crate::bindings::OSSL_PARAM {
-                key: $key.as_ptr().cast(),
+                key: storage.store_key($key),
                 data_type: $data_type,
-                data: std::ptr::null::<std::ffi::c_void>() as *mut std::ffi::c_void,
-                data_size: 0,
+                data,
+                data_size,
                 return_size: 0,
-            })),
+            }))
+            .into(),
+            ownership: crate::osslparams::Ownership::Owned { _storage: storage },
         }
-    };
+    }};
 }
 pub(crate) use new_null_param;
 
+/// Implements [`Drop`] for the `*Data` structs, freeing the wrapped
+/// [`OSSL_PARAM`] only if the struct owns it (see [`Ownership`]): params
+/// borrowed from C (or from other Rust code) are never freed.
+///
+/// The storage backing the key and data of an [`Ownership::Owned`] param is
+/// freed afterwards, when the `ownership` field is dropped.
+macro_rules! impl_drop_owned_param {
+    ($($(#[$attr:meta])* $data:ident),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl Drop for $data<'_> {
+                fn drop(&mut self) {
+                    match self.ownership {
+                        Ownership::Borrowed => {}
+                        Ownership::ParamOnly | Ownership::Owned { .. } => {
+                            // SAFETY: an owned `param` has been allocated as a
+                            // `Box<OSSL_PARAM>` and leaked, see
+                            // `OSSLParam::from_owned()` and `Ownership`.
+                            drop(unsafe { Box::from_raw(self.param.as_ptr()) });
+                        }
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_drop_owned_param!(
+    Utf8PtrData,
+    Utf8StringData,
+    IntData,
+    UIntData,
+    OctetStringData,
+    OctetPtrData,
+    #[cfg(ossl_param_type = "real")]
+    RealData,
+);
+
 macro_rules! impl_setter {
     ($t:ty, $variant:ident) => {
         impl<'a> $crate::osslparams::OSSLParamSetter<$t> for OSSLParam<'a> {
@@ -803,7 +1505,7 @@ macro_rules! impl_setter {
                 if let OSSLParam::$variant(d) = self {
                     d.set(value)
                 } else {
-                    Err($crate::osslparams::setter_type_err_string!(self, value))
+                    Err($crate::osslparams::setter_type_err!(self, value))
                 }
             }
         }
@@ -811,13 +1513,252 @@ macro_rules! impl_setter {
 }
 pub(crate) use impl_setter;
 
-impl<'a> TryFrom<&mut OSSL_PARAM> for OSSLParam<'a> {
-    type Error = OSSLParamError;
-    fn try_from(value: &mut OSSL_PARAM) -> Result<Self, Self::Error> {
-        OSSLParam::try_from(value as *mut OSSL_PARAM)
-    }
+/// Implements [`OSSLParamGetter`] for an integer type `$t` narrower than
+/// `$from`, by converting the value returned by the `$from` getter with the
+/// [`num_traits::ToPrimitive`] method `$conv`, i.e., returning `None` if the
+/// stored value does not fit in `$t`.
+macro_rules! impl_narrowing_getter {
+    ($t:ty, $from:ty, $conv:ident) => {
+        impl $crate::osslparams::OSSLParamGetter<$t> for OSSLParam<'_> {
+            fn get_inner(&self) -> Option<$t> {
+                num_traits::ToPrimitive::$conv(&self.get::<$from>()?)
+            }
+        }
+    };
+}
+pub(crate) use impl_narrowing_getter;
+
+/// Returns the name of the `OSSL_PARAM_*` constant matching `data_type`,
+/// or [`None`] if the data type is not defined by the OpenSSL version this
+/// crate has been built against.
+///
+/// Data types which are recognized but not (yet) supported by [`OSSLParam`]
+/// are reported by name in the errors returned by [`OSSLParam::try_from`].
+///
+/// # Supporting additional data types
+///
+/// The build script emits a `ossl_param_type = "<name>"` cfg for every
+/// `OSSL_PARAM_<NAME>` data type found in the OpenSSL headers.
+/// Data types which are only defined by newer OpenSSL versions should be
+/// added to this function (and, once supported, to [`OSSLParam::try_from`])
+/// guarded by the corresponding cfg, e.g. `#[cfg(ossl_param_type = "real")]`,
+/// so that the crate still builds against older versions.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// assert_eq!(data_type_name(OSSL_PARAM_INTEGER), Some("OSSL_PARAM_INTEGER"));
+/// assert_eq!(data_type_name(0), None);
+/// ```
+pub fn data_type_name(data_type: u32) -> Option<&'static str> {
+    match data_type {
+        OSSL_PARAM_INTEGER => Some("OSSL_PARAM_INTEGER"),
+        OSSL_PARAM_UNSIGNED_INTEGER => Some("OSSL_PARAM_UNSIGNED_INTEGER"),
+        OSSL_PARAM_UTF8_STRING => Some("OSSL_PARAM_UTF8_STRING"),
+        OSSL_PARAM_OCTET_STRING => Some("OSSL_PARAM_OCTET_STRING"),
+        OSSL_PARAM_UTF8_PTR => Some("OSSL_PARAM_UTF8_PTR"),
+        OSSL_PARAM_OCTET_PTR => Some("OSSL_PARAM_OCTET_PTR"),
+        #[cfg(ossl_param_type = "real")]
+        crate::bindings::OSSL_PARAM_REAL => Some("OSSL_PARAM_REAL"),
+        _ => None,
+    }
+}
+
+/// The sizes of an [`OSSL_PARAM`], as returned by [`OSSLParam::size_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeInfo {
+    /// The [`data_size`][`CONST_OSSL_PARAM::data_size`] field, i.e. the size
+    /// of the buffer pointed to by the param.
+    pub capacity: usize,
+    /// The [`return_size`][`CONST_OSSL_PARAM::return_size`] field, i.e. the
+    /// size of the value set (or needed, if the buffer is too small or
+    /// `NULL`): it is [`OSSL_PARAM_UNMODIFIED`] if `modified` is `false`.
+    pub returned: usize,
+    /// Whether the param has been modified (see [`OSSLParam::modified`]).
+    pub modified: bool,
+}
+
+/// The data types of [OSSL_PARAM(3ossl)], as an enum.
+///
+/// [OSSL_PARAM(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamType {
+    /// [`OSSL_PARAM_INTEGER`]
+    Integer,
+    /// [`OSSL_PARAM_UNSIGNED_INTEGER`]
+    UnsignedInteger,
+    /// [`OSSL_PARAM_UTF8_STRING`]
+    Utf8String,
+    /// [`OSSL_PARAM_OCTET_STRING`]
+    OctetString,
+    /// [`OSSL_PARAM_UTF8_PTR`]
+    Utf8Ptr,
+    /// `OSSL_PARAM_OCTET_PTR`
+    OctetPtr,
+    /// `OSSL_PARAM_REAL`
+    #[cfg(ossl_param_type = "real")]
+    Real,
+}
+
+impl ParamType {
+    /// Returns the [`ParamType`] matching the `data_type` field of an
+    /// [`OSSL_PARAM`], or [`None`] if it is not a known data type (see
+    /// [`data_type_name`]).
+    pub fn from_data_type(data_type: u32) -> Option<Self> {
+        match data_type {
+            OSSL_PARAM_INTEGER => Some(Self::Integer),
+            OSSL_PARAM_UNSIGNED_INTEGER => Some(Self::UnsignedInteger),
+            OSSL_PARAM_UTF8_STRING => Some(Self::Utf8String),
+            OSSL_PARAM_OCTET_STRING => Some(Self::OctetString),
+            OSSL_PARAM_UTF8_PTR => Some(Self::Utf8Ptr),
+            OSSL_PARAM_OCTET_PTR => Some(Self::OctetPtr),
+            #[cfg(ossl_param_type = "real")]
+            crate::bindings::OSSL_PARAM_REAL => Some(Self::Real),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the `data_type` field of an [`OSSL_PARAM`] of
+    /// this type.
+    pub const fn data_type(self) -> u32 {
+        match self {
+            Self::Integer => OSSL_PARAM_INTEGER,
+            Self::UnsignedInteger => OSSL_PARAM_UNSIGNED_INTEGER,
+            Self::Utf8String => OSSL_PARAM_UTF8_STRING,
+            Self::OctetString => OSSL_PARAM_OCTET_STRING,
+            Self::Utf8Ptr => OSSL_PARAM_UTF8_PTR,
+            Self::OctetPtr => OSSL_PARAM_OCTET_PTR,
+            #[cfg(ossl_param_type = "real")]
+            Self::Real => crate::bindings::OSSL_PARAM_REAL,
+        }
+    }
+}
+
+/// Returns `true` if `err` reports a _malformed_ [`OSSL_PARAM`]
+/// ([`OSSLParamError::MalformedParam`]), i.e. an item with a non-`NULL` key
+/// but with the data type (`0`) reserved for the END item.
+///
+/// This tells the error apart from the one returned for a genuinely
+/// unsupported data type.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut p = OSSL_PARAM {
+///     key: c"foo".as_ptr(),
+///     ..OSSL_PARAM::END
+/// };
+/// let err = OSSLParam::try_from(&mut p).unwrap_err();
+/// assert!(is_malformed_param(&err));
+/// ```
+pub fn is_malformed_param(err: &OSSLParamError) -> bool {
+    matches!(err, OSSLParamError::MalformedParam { .. })
+}
+
+/// Returns `true` if `err` reports a value set into a param whose (non-`NULL`)
+/// `data` buffer is too small for it, e.g. because it has a `data_size` of `0`
+/// ([`OSSLParamError::BufferTooSmall`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut buf = [0u8; 1];
+/// let mut p = OSSL_PARAM {
+///     key: c"foo".as_ptr(),
+///     data_type: OSSL_PARAM_INTEGER,
+///     data: buf.as_mut_ptr().cast(),
+///     data_size: 0,
+///     return_size: OSSL_PARAM_UNMODIFIED,
+/// };
+/// let err = OSSLParam::try_from(&mut p).unwrap().set(42i64).unwrap_err();
+/// assert!(is_buffer_too_small(&err));
+/// ```
+pub fn is_buffer_too_small(err: &OSSLParamError) -> bool {
+    matches!(err, OSSLParamError::BufferTooSmall { .. })
 }
 
+/// Returns `true` if `err` reports a value set into a param whose `data_size`
+/// is not among the supported sizes ([`OSSLParamError::UnsupportedSize`]).
+///
+/// The error reports both the actual `data_size` and the supported sizes.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut buf = [0u8; 3];
+/// let mut p = OSSL_PARAM {
+///     key: c"foo".as_ptr(),
+///     data_type: OSSL_PARAM_INTEGER,
+///     data: buf.as_mut_ptr().cast(),
+///     data_size: buf.len(),
+///     return_size: OSSL_PARAM_UNMODIFIED,
+/// };
+/// let err = OSSLParam::try_from(&mut p).unwrap().set(42i64).unwrap_err();
+/// assert!(is_unsupported_size(&err));
+/// assert!(err.to_string().contains("data_size 3"));
+/// ```
+pub fn is_unsupported_size(err: &OSSLParamError) -> bool {
+    matches!(err, OSSLParamError::UnsupportedSize { .. })
+}
+
+/// Returns the key of `p` for errors.
+pub(crate) fn error_key(p: &OSSL_PARAM) -> String {
+    if p.key.is_null() {
+        "<END>".to_string()
+    } else {
+        // SAFETY: we just checked that the key is not NULL
+        unsafe { CStr::from_ptr(p.key) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Returns the [`OSSLParamError::UnsupportedSize`] error for `p`, whose `data_size` is not
+/// among the `supported` ones.
+pub(crate) fn unsupported_size_error(p: &OSSL_PARAM, supported: &[usize]) -> OSSLParamError {
+    OSSLParamError::UnsupportedSize {
+        key: error_key(p),
+        data_size: p.data_size,
+        supported: supported.to_vec(),
+    }
+}
+
+/// Returns the [`OSSLParamError::BufferTooSmall`] error if `p` has a non-`NULL` `data`
+/// buffer with a `data_size` of `0`.
+///
+/// The `return_size` of `p` must already be set to the size of the value.
+pub(crate) fn check_zero_size_buffer(p: &OSSL_PARAM) -> Result<(), OSSLParamError> {
+    if p.data.is_null() || p.data_size != 0 {
+        return Ok(());
+    }
+    Err(OSSLParamError::BufferTooSmall {
+        key: error_key(p),
+        needed: p.return_size,
+        available: 0,
+    })
+}
+
+impl<'a> TryFrom<&mut OSSL_PARAM> for OSSLParam<'a> {
+    type Error = OSSLParamError;
+    fn try_from(value: &mut OSSL_PARAM) -> Result<Self, Self::Error> {
+        OSSLParam::try_from(value as *mut OSSL_PARAM)
+    }
+}
+
+/// Converts a single [`CONST_OSSL_PARAM`] into an [`OSSLParam`].
+///
+/// The returned param may only access `value` itself: to iterate over a
+/// list of params, convert the whole list instead (see the
+/// [`TryFrom<&[CONST_OSSL_PARAM]>`](#impl-TryFrom<%26%5BCONST_OSSL_PARAM%5D>-for-OSSLParam<'a>)
+/// implementation).
 impl<'a> TryFrom<&CONST_OSSL_PARAM> for OSSLParam<'a> {
     type Error = OSSLParamError;
     fn try_from(value: &CONST_OSSL_PARAM) -> Result<Self, Self::Error> {
@@ -826,6 +1767,39 @@ impl<'a> TryFrom<&CONST_OSSL_PARAM> for OSSLParam<'a> {
     }
 }
 
+/// Converts the head of an END-terminated list of [`CONST_OSSL_PARAM`]s into
+/// an [`OSSLParam`], which can then be iterated over the whole list.
+///
+/// Fails if `list` is not terminated by an END item.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let list = [
+///     OSSLParam::new_const_int(c"foo", Some(&1i64)),
+///     OSSLParam::new_const_uint(c"bar", Some(&2u64)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let params = OSSLParam::try_from(list.as_slice()).unwrap();
+/// assert_eq!(params.into_iter().count(), 2);
+///
+/// assert!(OSSLParam::try_from(&list[..2]).is_err());
+/// ```
+impl<'a> TryFrom<&[CONST_OSSL_PARAM]> for OSSLParam<'a> {
+    type Error = OSSLParamError;
+    fn try_from(list: &[CONST_OSSL_PARAM]) -> Result<Self, Self::Error> {
+        if !list.last().is_some_and(|p| p.key.is_null()) {
+            return Err(OSSLParamError::Other(
+                "Couldn't convert to OSSLParam a list without an END item".to_string(),
+            ));
+        }
+        OSSLParam::try_from(list.as_ptr() as *mut OSSL_PARAM)
+    }
+}
+
 /// Converts a mutable raw pointer ([`*mut OSSL_PARAM`][`OSSL_PARAM`]) into an [`OSSLParam`] enum.
 impl<'a> TryFrom<*mut OSSL_PARAM> for OSSLParam<'a> {
     type Error = OSSLParamError;
@@ -899,24 +1873,29 @@ impl<'a> TryFrom<*mut OSSL_PARAM> for OSSLParam<'a> {
     /// ```
     ///
     fn try_from(p: *mut OSSL_PARAM) -> std::result::Result<Self, Self::Error> {
-        match unsafe { p.as_mut() } {
-            Some(p) => match p.data_type {
-                OSSL_PARAM_UTF8_PTR => Ok(OSSLParam::Utf8Ptr(Utf8PtrData::try_from(
-                    p as *mut OSSL_PARAM,
-                )?)),
-                OSSL_PARAM_UTF8_STRING => Ok(OSSLParam::Utf8String(Utf8StringData::try_from(
-                    p as *mut OSSL_PARAM,
-                )?)),
-                OSSL_PARAM_INTEGER => Ok(OSSLParam::Int(IntData::try_from(p as *mut OSSL_PARAM)?)),
-                OSSL_PARAM_UNSIGNED_INTEGER => {
-                    Ok(OSSLParam::UInt(UIntData::try_from(p as *mut OSSL_PARAM)?))
+        // Only borrow the param immutably here, as it may be read-only: the
+        // `*Data` structs below wrap `p` itself, keeping its provenance.
+        match unsafe { p.as_ref() } {
+            Some(r) => match r.data_type {
+                OSSL_PARAM_UTF8_PTR => Ok(OSSLParam::Utf8Ptr(Utf8PtrData::try_from(p)?)),
+                OSSL_PARAM_UTF8_STRING => Ok(OSSLParam::Utf8String(Utf8StringData::try_from(p)?)),
+                OSSL_PARAM_INTEGER => Ok(OSSLParam::Int(IntData::try_from(p)?)),
+                OSSL_PARAM_UNSIGNED_INTEGER => Ok(OSSLParam::UInt(UIntData::try_from(p)?)),
+                OSSL_PARAM_OCTET_STRING => {
+                    Ok(OSSLParam::OctetString(OctetStringData::try_from(p)?))
                 }
-                OSSL_PARAM_OCTET_STRING => Ok(OSSLParam::OctetString(OctetStringData::try_from(
-                    p as *mut OSSL_PARAM,
-                )?)),
-                _ => Err("Couldn't convert to OSSLParam from *mut OSSL_PARAM".to_string()),
+                OSSL_PARAM_OCTET_PTR => Ok(OSSLParam::OctetPtr(OctetPtrData::try_from(p)?)),
+                #[cfg(ossl_param_type = "real")]
+                crate::bindings::OSSL_PARAM_REAL => Ok(OSSLParam::Real(RealData::try_from(p)?)),
+                0 if !r.key.is_null() => Err(OSSLParamError::MalformedParam { key: error_key(r) }),
+                data_type => Err(OSSLParamError::Other(match data_type_name(data_type) {
+                    Some(name) => format!("Unsupported OSSL_PARAM data type {name} ({data_type})"),
+                    None => format!("Unknown OSSL_PARAM data type {data_type}"),
+                })),
             },
-            None => Err("Couldn't convert to OSSLParam from null pointer".to_string()),
+            None => Err(OSSLParamError::Other(
+                "Couldn't convert to OSSLParam from null pointer".to_string(),
+            )),
         }
     }
 }
@@ -1002,11 +1981,14 @@ impl<'a> TryFrom<*const OSSL_PARAM> for OSSLParam<'a> {
 impl<'a> From<&mut OSSLParam<'a>> for *mut OSSL_PARAM {
     fn from(val: &mut OSSLParam<'a>) -> Self {
         match val {
-            OSSLParam::Utf8Ptr(d) => d.param as *mut OSSL_PARAM,
-            OSSLParam::Utf8String(d) => d.param as *mut OSSL_PARAM,
-            OSSLParam::Int(d) => d.param as *mut OSSL_PARAM,
-            OSSLParam::UInt(d) => d.param as *mut OSSL_PARAM,
-            OSSLParam::OctetString(d) => d.param as *mut OSSL_PARAM,
+            OSSLParam::Utf8Ptr(d) => d.param.as_ptr(),
+            OSSLParam::Utf8String(d) => d.param.as_ptr(),
+            OSSLParam::Int(d) => d.param.as_ptr(),
+            OSSLParam::UInt(d) => d.param.as_ptr(),
+            OSSLParam::OctetString(d) => d.param.as_ptr(),
+            OSSLParam::OctetPtr(d) => d.param.as_ptr(),
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(d) => d.param.as_ptr(),
         }
     }
 }
@@ -1014,24 +1996,134 @@ impl<'a> From<&mut OSSLParam<'a>> for *mut OSSL_PARAM {
 impl<'a> From<&OSSLParam<'a>> for *const OSSL_PARAM {
     fn from(val: &OSSLParam<'a>) -> Self {
         match val {
-            OSSLParam::Utf8Ptr(d) => d.param as *const OSSL_PARAM,
-            OSSLParam::Utf8String(d) => d.param as *const OSSL_PARAM,
-            OSSLParam::Int(d) => d.param as *const OSSL_PARAM,
-            OSSLParam::UInt(d) => d.param as *const OSSL_PARAM,
-            OSSLParam::OctetString(d) => d.param as *const OSSL_PARAM,
+            OSSLParam::Utf8Ptr(d) => d.param.as_ptr().cast_const(),
+            OSSLParam::Utf8String(d) => d.param.as_ptr().cast_const(),
+            OSSLParam::Int(d) => d.param.as_ptr().cast_const(),
+            OSSLParam::UInt(d) => d.param.as_ptr().cast_const(),
+            OSSLParam::OctetString(d) => d.param.as_ptr().cast_const(),
+            OSSLParam::OctetPtr(d) => d.param.as_ptr().cast_const(),
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(d) => d.param.as_ptr().cast_const(),
         }
     }
 }
 
+/// Converting an [`OSSLParam`] by value never frees the wrapped
+/// [`OSSL_PARAM`], even if it has been created by [`OSSLParamData::new_null`]:
+/// in that case, it is leaked, so that the returned pointer remains valid.
 impl<'a> From<OSSLParam<'a>> for *mut OSSL_PARAM {
-    fn from(mut val: OSSLParam<'a>) -> Self {
-        (&mut val).into()
+    fn from(val: OSSLParam<'a>) -> Self {
+        let mut val = std::mem::ManuallyDrop::new(val);
+        (&mut *val).into()
     }
 }
 
+/// Converting an [`OSSLParam`] by value never frees the wrapped
+/// [`OSSL_PARAM`] (see the conversion to `*mut OSSL_PARAM`).
 impl<'a> From<OSSLParam<'a>> for *const OSSL_PARAM {
     fn from(val: OSSLParam<'a>) -> Self {
-        (&val).into()
+        let val = std::mem::ManuallyDrop::new(val);
+        (&*val).into()
+    }
+}
+
+/// Collects all the items of a properly END-terminated list of
+/// [`OSSL_PARAM`]s starting at `head` (the END item excluded).
+///
+/// Unlike [`OSSLParamIterator`], which stops at the first item it cannot
+/// convert, items whose data type is not supported by [`OSSLParam`] are
+/// skipped (logging a warning) and the following ones are still collected.
+///
+/// This is meant for lists received from C: a Rust reference to `head` does
+/// not allow access to the following items, so lists built in Rust should
+/// rather be collected whole, with [`collect_params`].
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut bits = 2048i32;
+/// let mut params_list = [
+///     OSSL_PARAM {
+///         key: c"bits".as_ptr(),
+///         data_type: OSSL_PARAM_INTEGER,
+///         data: std::ptr::from_mut(&mut bits).cast(),
+///         data_size: size_of::<i32>(),
+///         return_size: OSSL_PARAM_UNMODIFIED,
+///     },
+///     OSSL_PARAM_END,
+/// ];
+///
+/// let params: Vec<OSSLParam> = Vec::from(&mut params_list[0]);
+/// assert_eq!(params.len(), 1);
+/// assert_eq!(params[0].get::<i32>(), Some(2048));
+/// ```
+impl<'a> From<&mut OSSL_PARAM> for Vec<OSSLParam<'a>> {
+    fn from(head: &mut OSSL_PARAM) -> Self {
+        let head = std::ptr::from_mut(head);
+        // the params are derived from `head` itself (not from the references
+        // yielded by `raw_params()`), so that they may be written to
+        raw_params(head)
+            .enumerate()
+            // SAFETY: `i` is the index of an item of the list.
+            .filter_map(|(i, _)| collect_param(unsafe { head.add(i) }))
+            .collect()
+    }
+}
+
+/// Collects all the items of `list` (up to its first END item, excluded) into
+/// [`OSSLParam`]s, like the `From<&mut OSSL_PARAM>` implementation of
+/// `Vec<OSSLParam>`.
+///
+/// Unlike a reference to the head of the list, `list` gives access to all its
+/// items, so the returned params may be written to.
+/// A list without an END item is collected up to its length.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut bits = 2048i32;
+/// let mut params_list = [
+///     OSSL_PARAM {
+///         key: c"bits".as_ptr(),
+///         data_type: OSSL_PARAM_INTEGER,
+///         data: std::ptr::from_mut(&mut bits).cast(),
+///         data_size: size_of::<i32>(),
+///         return_size: OSSL_PARAM_UNMODIFIED,
+///     },
+///     OSSL_PARAM_END,
+/// ];
+///
+/// let mut params = collect_params(&mut params_list);
+/// assert_eq!(params.len(), 1);
+/// assert!(params[0].set(4096i32).is_ok());
+/// drop(params);
+/// assert_eq!(bits, 4096);
+/// ```
+pub fn collect_params(list: &mut [OSSL_PARAM]) -> Vec<OSSLParam<'_>> {
+    let head = list.as_mut_ptr();
+    (0..list.len())
+        // SAFETY: `i` is in bounds, and `head` may access the whole list.
+        .map(|i| unsafe { head.add(i) })
+        .take_while(|&p| !unsafe { (*p).key }.is_null())
+        .filter_map(collect_param)
+        .collect()
+}
+
+/// Converts `p` into an [`OSSLParam`] for [`collect_params`] and the `From`
+/// implementation of `Vec<OSSLParam>`, skipping (with a warning) the items which cannot be
+/// converted.
+fn collect_param<'a>(p: *mut OSSL_PARAM) -> Option<OSSLParam<'a>> {
+    match OSSLParam::try_from(p) {
+        Ok(param) => Some(param),
+        Err(e) => {
+            // SAFETY: `p` points at a valid item of the list being collected.
+            log::warn!("Skipping OSSL_PARAM {:?}: {e}", error_key(unsafe { &*p }));
+            None
+        }
     }
 }
 
@@ -1054,6 +2146,30 @@ pub const OSSL_PARAM_END: OSSL_PARAM = OSSL_PARAM::END;
 /// Used to represent an empty parameter list in OpenSSL operations.
 pub const EMPTY_PARAMS: [OSSL_PARAM; 1] = [OSSL_PARAM_END];
 
+/// A single-element array containing the [`CONST_OSSL_PARAM::END`] marker.
+///
+/// This is the [`CONST_OSSL_PARAM`] counterpart of [`EMPTY_PARAMS`], to be
+/// returned by descriptor functions (e.g., `settable_ctx_params()` or
+/// `gettable_params()`) of operations which expose no parameters at all.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+/// use std::ffi::c_void;
+///
+/// extern "C" fn settable_ctx_params(
+///     _vctx: *mut c_void,
+///     _provctx: *mut c_void,
+/// ) -> *const OSSL_PARAM {
+///     EMPTY_CONST_PARAMS.as_ptr() as *const OSSL_PARAM
+/// }
+///
+/// let params = settable_ctx_params(std::ptr::null_mut(), std::ptr::null_mut());
+/// assert!(!params.is_null());
+/// ```
+pub const EMPTY_CONST_PARAMS: [CONST_OSSL_PARAM; 1] = [CONST_OSSL_PARAM::END];
+
 /// An iterator for a properly END-terminated sequence of [`OSSL_PARAM`]s.
 ///
 /// **⚠ WARNING**: this implementation assumes the list is properly terminated with an END item.
@@ -1072,8 +2188,7 @@ pub const EMPTY_PARAMS: [OSSL_PARAM; 1] = [OSSL_PARAM_END];
 ///     CONST_OSSL_PARAM::END
 /// ];
 ///
-/// let first = params_list.first().unwrap();
-/// let p = OSSLParam::try_from(first).unwrap();
+/// let p = OSSLParam::try_from(params_list.as_slice()).unwrap();
 ///
 /// // here we explicitly get an `OSSLParamIterator`,
 /// // but we can also directly iterate over
@@ -1126,7 +2241,7 @@ pub const EMPTY_PARAMS: [OSSL_PARAM; 1] = [OSSL_PARAM_END];
 ///     CONST_OSSL_PARAM::END
 /// ];
 ///
-/// let params = OSSLParam::try_from(&params_list[0]).unwrap();
+/// let params = OSSLParam::try_from(params_list.as_slice()).unwrap();
 ///
 /// let mut sum = 0;
 /// for p in params {
@@ -1140,30 +2255,187 @@ pub const EMPTY_PARAMS: [OSSL_PARAM; 1] = [OSSL_PARAM_END];
 /// assert_eq!(sum, 42);
 /// ```
 ///
+///
+/// ## Safety cap
+///
+/// To bound the damage done by a list which is not properly END-terminated,
+/// the iterator stops (logging an error) after [`max_params()`] items, unless
+/// a different cap is set with [`OSSLParamIterator::with_max_params`].
+///
+/// When the length of the underlying buffer is known, e.g. because it was
+/// passed by C code along with the list, use
+/// [`OSSLParamIterator::with_max_len`] (or [`OSSLParam::into_iter_with_max_len`])
+/// instead, so that a list missing its END item is never read past the end of
+/// the buffer.
 pub struct OSSLParamIterator<'a> {
     ptr: *mut OSSL_PARAM,
+    remaining: usize,
+    unread: usize,
+    truncated: bool,
     phantom: PhantomData<OSSLParam<'a>>,
 }
 
+/// The default value of [`max_params()`].
+pub const DEFAULT_MAX_PARAMS: usize = 1024;
+
+/// The environment variable which can be used to override
+/// [`DEFAULT_MAX_PARAMS`].
+pub const MAX_PARAMS_ENV_VAR: &str = "OSSL_PROVIDER_FORGE_MAX_PARAMS";
+
+fn parse_max_params(value: Option<&str>) -> usize {
+    match value.map(|v| v.trim().parse::<usize>()) {
+        None => DEFAULT_MAX_PARAMS,
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            log::warn!(
+                "Ignoring invalid {MAX_PARAMS_ENV_VAR}={:?}, using the default ({DEFAULT_MAX_PARAMS})",
+                value.unwrap_or_default()
+            );
+            DEFAULT_MAX_PARAMS
+        }
+    }
+}
+
+/// Returns the maximum number of items an [`OSSLParamIterator`] yields by
+/// default, which also bounds the helpers scanning a list of [`OSSL_PARAM`]s
+/// (e.g., [`list_keys()`] or [`find_duplicate_keys()`]).
+///
+/// This is [`DEFAULT_MAX_PARAMS`], unless the
+/// [`OSSL_PROVIDER_FORGE_MAX_PARAMS`][`MAX_PARAMS_ENV_VAR`] environment
+/// variable is set to a positive integer.
+/// The environment is only read the first time this function is called, so
+/// the variable must be set before any param list is iterated.
+pub fn max_params() -> usize {
+    static MAX_PARAMS: std::sync::OnceLock<usize> = std::sync::OnceLock::new();
+    *MAX_PARAMS.get_or_init(|| parse_max_params(std::env::var(MAX_PARAMS_ENV_VAR).ok().as_deref()))
+}
+
 impl OSSLParamIterator<'_> {
-    fn new(ptr: *const OSSL_PARAM) -> Self {
+    /// Creates an iterator over the list of [`OSSL_PARAM`]s starting at
+    /// `ptr`.
+    ///
+    /// Unlike going through [`OSSLParam::try_from`] and
+    /// [`into_iter()`][`IntoIterator::into_iter`], this accepts a `ptr`
+    /// pointing directly at the END item (i.e., an empty list), or a `NULL`
+    /// one, for which the iterator yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// // `OSSLParam::try_from()` rejects an END item...
+    /// assert!(OSSLParam::try_from(EMPTY_PARAMS.as_ptr()).is_err());
+    ///
+    /// // ...but iterating over an empty list is fine
+    /// let mut iter = OSSLParamIterator::new(EMPTY_PARAMS.as_ptr());
+    /// assert!(iter.next().is_none());
+    /// assert!(!iter.truncated());
+    /// ```
+    pub fn new(ptr: *const OSSL_PARAM) -> Self {
         OSSLParamIterator {
             ptr: ptr as *mut OSSL_PARAM,
+            remaining: max_params(),
+            unread: usize::MAX,
+            truncated: false,
             phantom: PhantomData,
         }
     }
+
+    /// Creates an iterator over the list of [`OSSL_PARAM`]s starting at
+    /// `ptr`, which never reads more than `max_len` items of it (including
+    /// the END one).
+    ///
+    /// If no END item is found within `max_len` items, the iteration stops
+    /// (logging an error) and [`truncated()`][`Self::truncated`] returns
+    /// `true`.
+    ///
+    /// This mirrors the guard of `CoreDispatch::try_from()`, and is meant
+    /// for lists coming from C code, which might not be properly
+    /// END-terminated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// // a malformed list, missing its END item
+    /// let params_list = [
+    ///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+    ///     OSSLParam::new_const_int(c"bar", Some(&2i32)),
+    /// ];
+    ///
+    /// let head = params_list.as_ptr() as *const OSSL_PARAM;
+    /// let mut iter = OSSLParamIterator::with_max_len(head, params_list.len());
+    /// assert_eq!(iter.by_ref().count(), 2);
+    /// assert!(iter.truncated());
+    /// ```
+    pub fn with_max_len(ptr: *const OSSL_PARAM, max_len: usize) -> Self {
+        let mut iter = Self::new(ptr);
+        iter.unread = max_len;
+        iter
+    }
+
+    /// Sets the maximum number of items this iterator yields, overriding
+    /// [`max_params()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let params_list = [
+    ///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+    ///     OSSLParam::new_const_int(c"bar", Some(&2i32)),
+    ///     CONST_OSSL_PARAM::END,
+    /// ];
+    ///
+    /// let params = OSSLParam::try_from(params_list.as_slice()).unwrap();
+    /// let mut iter = params.into_iter().with_max_params(1);
+    /// assert_eq!(iter.by_ref().count(), 1);
+    /// assert!(iter.truncated());
+    /// ```
+    pub fn with_max_params(mut self, max: usize) -> Self {
+        self.remaining = max;
+        self
+    }
+
+    /// Returns `true` if this iterator stopped because it reached its cap
+    /// before finding the END item.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
 }
 
 impl<'a> Iterator for OSSLParamIterator<'a> {
     type Item = OSSLParam<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.truncated {
+            return None;
+        }
+        if self.unread == 0 {
+            log::error!(
+                "Stopping the iteration over an OSSL_PARAM list: no END item found within the length of its buffer"
+            );
+            self.truncated = true;
+            return None;
+        }
+        self.unread -= 1;
         match unsafe { self.ptr.as_ref() } {
             Some(p) => {
                 if p.key.is_null() {
                     // we've reached OSSL_PARAM_END
                     return None;
                 }
+                if self.remaining == 0 {
+                    log::error!(
+                        "Stopping the iteration over an OSSL_PARAM list: no END item found within the cap (see {MAX_PARAMS_ENV_VAR})"
+                    );
+                    self.truncated = true;
+                    return None;
+                }
+                self.remaining -= 1;
                 let param = OSSLParam::try_from(self.ptr);
                 self.ptr = unsafe { self.ptr.offset(1) };
                 param.ok()
@@ -1191,7 +2463,7 @@ impl<'a> Iterator for OSSLParamIterator<'a> {
 ///     CONST_OSSL_PARAM::END
 /// ];
 ///
-/// let params = OSSLParam::try_from(&params_list[0]).unwrap();
+/// let params = OSSLParam::try_from(params_list.as_slice()).unwrap();
 ///
 /// let mut counter = 0;
 /// for p in params {
@@ -1225,8 +2497,388 @@ impl<'a> IntoIterator for OSSLParam<'a> {
     type IntoIter = OSSLParamIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        OSSLParamIterator::new(self.get_c_struct())
+        // The iterator borrows the list, so the head must not be freed.
+        let head = std::mem::ManuallyDrop::new(self);
+        OSSLParamIterator::new(head.get_c_struct())
+    }
+}
+
+impl<'a> OSSLParam<'a> {
+    /// Same as [`into_iter()`][`IntoIterator::into_iter`], but never reads
+    /// more than `max_len` items of the list this param is the head of.
+    ///
+    /// See [`OSSLParamIterator::with_max_len`].
+    pub fn into_iter_with_max_len(self, max_len: usize) -> OSSLParamIterator<'a> {
+        // The iterator borrows the list, so the head must not be freed.
+        let head = std::mem::ManuallyDrop::new(self);
+        OSSLParamIterator::with_max_len(head.get_c_struct(), max_len)
+    }
+}
+
+/// Iterates over the raw items of a properly END-terminated list of
+/// [`OSSL_PARAM`]s starting at `head`, regardless of their data type.
+///
+/// The END item is not returned. A `NULL` `head` yields an empty iterator.
+///
+/// Like [`OSSLParamIterator`], this stops (logging an error) after
+/// [`max_params()`] items, so that the helpers built on it never walk a list
+/// missing its END item without bound.
+pub(crate) fn raw_params<'a>(head: *const OSSL_PARAM) -> impl Iterator<Item = &'a OSSL_PARAM> {
+    raw_params_with_max(head, max_params())
+}
+
+/// Same as [`raw_params()`], with a cap of `max` items instead of
+/// [`max_params()`].
+fn raw_params_with_max<'a>(
+    head: *const OSSL_PARAM,
+    max: usize,
+) -> impl Iterator<Item = &'a OSSL_PARAM> {
+    let mut ptr = head;
+    let mut remaining = max;
+    std::iter::from_fn(move || {
+        // SAFETY: `ptr` is either NULL or points at an item of a properly
+        // END-terminated list, and we never move past the END item.
+        let p = unsafe { ptr.as_ref() }?;
+        if p.key.is_null() {
+            return None;
+        }
+        if remaining == 0 {
+            log::error!(
+                "Stopping the scan of an OSSL_PARAM list: no END item found within the cap (see {MAX_PARAMS_ENV_VAR})"
+            );
+            // never read any further, so that the error is only logged once
+            ptr = std::ptr::null();
+            return None;
+        }
+        remaining -= 1;
+        ptr = unsafe { ptr.add(1) };
+        Some(p)
+    })
+}
+
+/// Returns the keys of all the items of a properly END-terminated list of
+/// [`OSSL_PARAM`]s starting at `head`, in order (the END item excluded).
+///
+/// Unlike [`OSSLParamIterator`], this also lists the keys of items whose data
+/// type is not supported by [`OSSLParam`].
+/// A `NULL` `head` yields an empty list.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let params_list = [
+///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+///     OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let keys = list_keys(params_list.as_ptr() as *const OSSL_PARAM);
+/// assert_eq!(keys, [c"foo", c"bar"]);
+/// ```
+pub fn list_keys<'a>(head: *const OSSL_PARAM) -> Vec<&'a KeyType> {
+    raw_params(head)
+        // SAFETY: `raw_params()` only yields items with a non-NULL key.
+        .map(|p| unsafe { CStr::from_ptr(p.key) })
+        .collect()
+}
+
+/// Returns the key and data type of all the items of a properly
+/// END-terminated list of [`OSSL_PARAM`]s starting at `head`, in order (the
+/// END item excluded).
+///
+/// This is meant for building a descriptor mirroring the shape of a list of
+/// values, e.g. so that a provider can echo back a descriptor matching what
+/// it received.
+/// Like [`list_keys()`], this also considers items whose data type is not
+/// supported by [`OSSLParam`], and a `NULL` `head` yields an empty list.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let params_list = [
+///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+///     OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let shape = shape_of(params_list.as_ptr() as *const OSSL_PARAM);
+/// assert_eq!(
+///     shape,
+///     [
+///         (c"foo".to_owned(), OSSL_PARAM_INTEGER),
+///         (c"bar".to_owned(), OSSL_PARAM_UTF8_STRING),
+///     ]
+/// );
+/// ```
+pub fn shape_of(head: *const OSSL_PARAM) -> Vec<(CString, u32)> {
+    raw_params(head)
+        // SAFETY: `raw_params()` only yields items with a non-NULL key.
+        .map(|p| (unsafe { CStr::from_ptr(p.key) }.to_owned(), p.data_type))
+        .collect()
+}
+
+/// Calls `f` on each item of a properly END-terminated list of
+/// [`OSSL_PARAM`]s starting at `head`, in order (the END item excluded).
+///
+/// This is an allocation-free alternative to collecting the params (e.g.,
+/// into a [`Vec<OSSLParam>`]) only to process each of them once.
+/// Items whose data type is not supported by [`OSSLParam`] are skipped, and a
+/// `NULL` `head` is an empty list.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let params_list = [
+///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+///     OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+///     OSSLParam::new_const_int(c"baz", Some(&2i32)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let mut sum = 0;
+/// for_each_param(params_list.as_ptr() as *const OSSL_PARAM, |p| {
+///     sum += p.get::<i32>().unwrap_or(0);
+/// });
+/// assert_eq!(sum, 3);
+/// ```
+pub fn for_each_param(head: *const OSSL_PARAM, mut f: impl FnMut(&OSSLParam)) {
+    raw_params(head)
+        .filter_map(|p| OSSLParam::try_from(std::ptr::from_ref(p)).ok())
+        .for_each(|p| f(&p));
+}
+
+/// Returns the keys which appear more than once in a properly END-terminated
+/// list of [`OSSL_PARAM`]s starting at `head`.
+///
+/// Each duplicated key is returned only once, in the order in which its first
+/// repetition is found.
+/// Like [`list_keys()`], this also considers items whose data type is not
+/// supported by [`OSSLParam`].
+///
+/// This is meant to catch mistakes in hand-written param arrays, as OpenSSL
+/// only ever looks at the first item with a given key.
+/// For arrays known at compile time, see [`has_duplicate_keys()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let params_list = [
+///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+///     OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+///     OSSLParam::new_const_int(c"foo", Some(&2i32)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let dups = find_duplicate_keys(params_list.as_ptr() as *const OSSL_PARAM);
+/// assert_eq!(dups, [c"foo"]);
+/// ```
+pub fn find_duplicate_keys<'a>(head: *const OSSL_PARAM) -> Vec<&'a KeyType> {
+    let mut seen = std::collections::HashSet::new();
+    let mut dups = Vec::new();
+    for key in list_keys(head) {
+        if !seen.insert(key) && !dups.contains(&key) {
+            dups.push(key);
+        }
     }
+    dups
+}
+
+/// Returns `true` if any key, other than `ignored`, appears more than once in
+/// `params`, up to its END item (if any).
+///
+/// This is the `const` counterpart of [`find_duplicate_keys()`], so that
+/// arrays built at compile time (e.g., by the capability macros) can be
+/// checked with a `const` assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// const PARAMS: &[CONST_OSSL_PARAM] = &[
+///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+///     OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// const _: () = assert!(!has_duplicate_keys(PARAMS, None));
+///
+/// const DUPS: &[CONST_OSSL_PARAM] = &[
+///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+///     OSSLParam::new_const_int(c"foo", Some(&2i32)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// assert!(has_duplicate_keys(DUPS, None));
+/// assert!(!has_duplicate_keys(DUPS, Some(c"foo")));
+/// ```
+pub const fn has_duplicate_keys(params: &[CONST_OSSL_PARAM], ignored: Option<&KeyType>) -> bool {
+    let mut i = 0;
+    while i < params.len() {
+        let Some(key) = const_param_key(&params[i]) else {
+            return false;
+        };
+        let is_ignored = match ignored {
+            Some(ignored) => keys_eq(key, ignored),
+            None => false,
+        };
+        if !is_ignored && contains_key(params.split_at(i + 1).1, key) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns `true` if an item of `params`, up to its END item (if any), has the
+/// given `key`.
+pub(crate) const fn contains_key(params: &[CONST_OSSL_PARAM], key: &KeyType) -> bool {
+    let mut i = 0;
+    while i < params.len() {
+        match const_param_key(&params[i]) {
+            None => return false,
+            Some(k) if keys_eq(k, key) => return true,
+            Some(_) => i += 1,
+        }
+    }
+    false
+}
+
+/// Returns the key of `p`, or [`None`] for an END item, in a `const` context.
+const fn const_param_key(p: &CONST_OSSL_PARAM) -> Option<&KeyType> {
+    if p.key.is_null() {
+        None
+    } else {
+        // SAFETY: a non-NULL key of a `CONST_OSSL_PARAM` is a valid C string,
+        // see `OSSLParam::new_const_*()`.
+        Some(unsafe { CStr::from_ptr(p.key) })
+    }
+}
+
+/// Compares two keys in a `const` context.
+const fn keys_eq(a: &KeyType, b: &KeyType) -> bool {
+    let (a, b) = (a.to_bytes(), b.to_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the first item with the given `key` in a properly END-terminated
+/// list of [`OSSL_PARAM`]s starting at `params`.
+///
+/// This is the equivalent of `OSSL_PARAM_locate()` ([OSSL_PARAM_locate(3ossl)]),
+/// returning the matching item as an [`OSSLParam`].
+///
+/// Returns [`None`] if `params` is `NULL`, if no item before the END one has
+/// the given `key`, or if the matching item has a data type not supported by
+/// [`OSSLParam`].
+///
+/// [OSSL_PARAM_locate(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM_locate/
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut bits = 0i32;
+/// let mut params_list = [
+///     OSSL_PARAM {
+///         key: c"bits".as_ptr(),
+///         data_type: OSSL_PARAM_INTEGER,
+///         data: std::ptr::from_mut(&mut bits).cast(),
+///         data_size: size_of::<i32>(),
+///         return_size: OSSL_PARAM_UNMODIFIED,
+///     },
+///     OSSL_PARAM_END,
+/// ];
+///
+/// let mut p = ossl_param_locate(params_list.as_mut_ptr(), c"bits").unwrap();
+/// p.set(2048i32).unwrap();
+/// assert_eq!(bits, 2048);
+///
+/// assert!(ossl_param_locate(params_list.as_mut_ptr(), c"missing").is_none());
+/// ```
+pub fn ossl_param_locate<'a>(params: *mut OSSL_PARAM, key: &KeyType) -> Option<OSSLParam<'a>> {
+    let i = locate_index(params, key)?;
+    // `i` is the index of an item of the list. The param is derived from
+    // `params` itself (not from a reference to the item), so that it keeps
+    // the provenance needed to write to it.
+    OSSLParam::try_from(params.wrapping_add(i)).ok()
+}
+
+/// Same as [`ossl_param_locate()`], for a list of [`OSSL_PARAM`]s which is
+/// only read.
+///
+/// This is the equivalent of `OSSL_PARAM_locate_const()`
+/// ([OSSL_PARAM_locate(3ossl)]).
+///
+/// [OSSL_PARAM_locate(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM_locate/
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let params_list = [
+///     OSSLParam::new_const_int(c"bits", Some(&2048i32)),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// let head = params_list.as_ptr() as *const OSSL_PARAM;
+///
+/// let p = ossl_param_locate_const(head, c"bits").unwrap();
+/// assert_eq!(p.get::<i32>(), Some(2048));
+///
+/// assert!(ossl_param_locate_const(head, c"missing").is_none());
+/// ```
+pub fn ossl_param_locate_const<'a>(
+    params: *const OSSL_PARAM,
+    key: &KeyType,
+) -> Option<OSSLParam<'a>> {
+    ossl_param_locate(params.cast_mut(), key)
+}
+
+/// Returns the index of the first item of the list starting at `params`
+/// whose key is `key`.
+fn locate_index(params: *const OSSL_PARAM, key: &KeyType) -> Option<usize> {
+    // computed once, instead of re-scanning `key` for every item
+    let key = key.to_bytes_with_nul();
+    raw_params(params)
+        // SAFETY: `raw_params()` only yields items with a non-NULL key.
+        .position(|p| unsafe { key_matches(p.key, key) })
+}
+
+/// Compares the NUL-terminated C string at `raw` with `key` (including its
+/// NUL terminator) byte by byte, stopping at the first mismatch.
+///
+/// Unlike building a [`CStr`] from `raw`, this never scans past the length of
+/// `key`, so mismatching keys are usually rejected after their first byte.
+///
+/// # Safety
+///
+/// `raw` must be a non-NULL pointer to a NUL-terminated C string.
+unsafe fn key_matches(raw: *const c_char, key: &[u8]) -> bool {
+    for (i, &b) in key.iter().enumerate() {
+        // SAFETY: every byte before index `i` matched a non-NUL byte of
+        // `key`, so the NUL terminator of `raw` has not been reached yet.
+        if unsafe { *raw.add(i) } as u8 != b {
+            return false;
+        }
+    }
+    true
 }
 
 /// This type has exactly the same C representation as [`OSSL_PARAM`] ([OSSL_PARAM(3ossl)])
@@ -1356,6 +3008,40 @@ pub struct CONST_OSSL_PARAM {
 unsafe impl Send for CONST_OSSL_PARAM {}
 unsafe impl Sync for CONST_OSSL_PARAM {}
 
+/// The pointer to a string referenced by a constant [`OSSLParam::Utf8Ptr`]
+/// param (see [`OSSLParam::new_const_utf8ptr`]).
+///
+/// The `data` of an `OSSL_PARAM_UTF8_PTR` param points at a `char *`, rather
+/// than at the string itself: a [`ConstUtf8Ptr`] is such a pointer, which can
+/// be built in `const` contexts (e.g., as a `const` item) and must outlive the
+/// param.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ConstUtf8Ptr<'a> {
+    // This must be the first field, as the `data` of the param points here.
+    ptr: *const c_char,
+    value: &'a CStr,
+}
+
+impl<'a> ConstUtf8Ptr<'a> {
+    /// Creates a pointer to `value`.
+    pub const fn new(value: &'a CStr) -> Self {
+        Self {
+            ptr: value.as_ptr(),
+            value,
+        }
+    }
+
+    /// Returns the string pointed to.
+    pub const fn value(&self) -> &'a CStr {
+        self.value
+    }
+}
+
+// SAFETY: the inner pointer always points at the immutable `value`.
+unsafe impl Send for ConstUtf8Ptr<'_> {}
+unsafe impl Sync for ConstUtf8Ptr<'_> {}
+
 /// [`CONST_OSSL_PARAM`] implements [`std::ops::Deref`], so we
 /// can deref [`&CONST_OSSL_PARAM`][`CONST_OSSL_PARAM`] into a [`&OSSL_PARAM`][`OSSL_PARAM`]
 ///
@@ -1403,3 +3089,36 @@ impl CONST_OSSL_PARAM {
         return_size: 0,
     };
 }
+
+/// Builds an array of [`CONST_OSSL_PARAM`]s from the given items, appending
+/// the [`CONST_OSSL_PARAM::END`] item, so that it cannot be forgotten.
+///
+/// The result is an array (of one more item than those given) and a `const`
+/// expression, so a reference to it can initialize a `static` (or `const`)
+/// item.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// static PARAMS: &[CONST_OSSL_PARAM] = &ossl_params![
+///     OSSLParam::new_const_int(c"foo", Some(&1i32)),
+///     OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+/// ];
+///
+/// assert_eq!(PARAMS.len(), 3);
+/// assert!(PARAMS[2].key.is_null());
+/// assert_eq!(list_keys(PARAMS.as_ptr().cast()), [c"foo", c"bar"]);
+/// ```
+#[macro_export]
+macro_rules! ossl_params {
+    ($($param:expr),* $(,)?) => {
+        [
+            $($param,)*
+            // IMPORTANT: always terminate a params array!!!
+            $crate::osslparams::CONST_OSSL_PARAM::END,
+        ]
+    };
+}
+pub use ossl_params;
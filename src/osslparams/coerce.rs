@@ -0,0 +1,131 @@
+//! This submodule provides [`OSSLParam::get_coerced`], an opt-in alternative
+//! to [`OSSLParam::get`] which, as allowed by [OSSL_PARAM(3ossl)], tries to
+//! convert the data to a suitable form when its type does not match.
+//!
+//! The following coercions are attempted (after the direct getter failed):
+//!
+//! | Requested type | Param type                    | Coercion                                                  |
+//! |----------------|-------------------------------|-----------------------------------------------------------|
+//! | [`u64`]        | octet string (or pointer)     | the bytes are a native-endian unsigned integer            |
+//! | [`u64`]        | UTF-8 string (or pointer)     | the string is a decimal unsigned integer                  |
+//! | [`i64`]        | UTF-8 string (or pointer)     | the string is a decimal signed integer                    |
+//! | [`Vec<u8>`]    | [`OSSLParam::UInt`]           | the native-endian bytes of the value (as a [`u64`])       |
+//! | [`String`]     | [`OSSLParam::Int`] or `UInt`  | the decimal representation of the value                   |
+//!
+//! An octet string longer than 8 bytes is accepted as a [`u64`] only if the
+//! extra (most significant) bytes are all zero, while an empty one is never
+//! accepted.
+//! Decimal strings must not contain any leading or trailing whitespace.
+//!
+//! [OSSL_PARAM(3ossl)]: https://docs.openssl.org/master/man3/OSSL_PARAM/
+
+use std::ffi::CStr;
+
+use super::OSSLParam;
+
+/// A trait for retrieving values from an [`OSSLParam`], coercing the data to
+/// type `T` when the param has a different (but convertible) type.
+///
+/// See the [module documentation][`self`] for the supported coercions.
+pub trait OSSLParamCoercedGetter<T> {
+    /// Extracts the inner value as type `T`, if the param has type `T` or its
+    /// data can be coerced to it.
+    fn get_coerced_inner(&self) -> Option<T>;
+}
+
+impl OSSLParam<'_> {
+    /// Like [`OSSLParam::get`], but when the param type does not match `T`,
+    /// tries to convert the data to `T` before giving up.
+    ///
+    /// See the [`coerce`][`crate::osslparams::coerce`] module for the supported
+    /// coercions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let param = OSSLParam::new_const_utf8string(c"bits", Some(c"2048"));
+    /// let param = OSSLParam::try_from(&param as *const CONST_OSSL_PARAM as *const OSSL_PARAM).unwrap();
+    ///
+    /// assert_eq!(param.get::<i64>(), None);
+    /// assert_eq!(param.get_coerced::<i64>(), Some(2048));
+    /// ```
+    pub fn get_coerced<T>(&self) -> Option<T>
+    where
+        Self: OSSLParamCoercedGetter<T>,
+    {
+        self.get_coerced_inner()
+    }
+}
+
+/// Interprets `bytes` as a native-endian unsigned integer.
+fn u64_from_ne_bytes(bytes: &[u8]) -> Option<u64> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let mut le = bytes.to_vec();
+    if cfg!(target_endian = "big") {
+        le.reverse();
+    }
+    let (low, high) = le.split_at(le.len().min(size_of::<u64>()));
+    if high.iter().any(|&b| b != 0) {
+        return None;
+    }
+    let mut buf = [0u8; size_of::<u64>()];
+    buf[..low.len()].copy_from_slice(low);
+    Some(u64::from_le_bytes(buf))
+}
+
+/// Parses the value of a UTF-8 string param as a decimal number.
+fn parse_decimal<T: std::str::FromStr>(param: &OSSLParam<'_>) -> Option<T> {
+    param.get::<&CStr>()?.to_str().ok()?.parse().ok()
+}
+
+impl OSSLParamCoercedGetter<u64> for OSSLParam<'_> {
+    fn get_coerced_inner(&self) -> Option<u64> {
+        match self {
+            OSSLParam::UInt(_) => self.get::<u64>(),
+            OSSLParam::OctetString(_) | OSSLParam::OctetPtr(_) => {
+                u64_from_ne_bytes(self.get::<&[u8]>()?)
+            }
+            OSSLParam::Utf8Ptr(_) | OSSLParam::Utf8String(_) => parse_decimal(self),
+            _ => None,
+        }
+    }
+}
+
+impl OSSLParamCoercedGetter<i64> for OSSLParam<'_> {
+    fn get_coerced_inner(&self) -> Option<i64> {
+        match self {
+            OSSLParam::Int(_) => self.get::<i64>(),
+            OSSLParam::Utf8Ptr(_) | OSSLParam::Utf8String(_) => parse_decimal(self),
+            _ => None,
+        }
+    }
+}
+
+impl OSSLParamCoercedGetter<Vec<u8>> for OSSLParam<'_> {
+    fn get_coerced_inner(&self) -> Option<Vec<u8>> {
+        match self {
+            OSSLParam::OctetString(_) | OSSLParam::OctetPtr(_) => {
+                self.get::<&[u8]>().map(<[u8]>::to_vec)
+            }
+            OSSLParam::UInt(_) => self.get::<u64>().map(|v| v.to_ne_bytes().to_vec()),
+            _ => None,
+        }
+    }
+}
+
+impl OSSLParamCoercedGetter<String> for OSSLParam<'_> {
+    fn get_coerced_inner(&self) -> Option<String> {
+        match self {
+            OSSLParam::Utf8Ptr(_) | OSSLParam::Utf8String(_) => {
+                self.get::<&CStr>()?.to_str().ok().map(String::from)
+            }
+            OSSLParam::Int(_) => self.get::<i64>().map(|v| v.to_string()),
+            OSSLParam::UInt(_) => self.get::<u64>().map(|v| v.to_string()),
+            _ => None,
+        }
+    }
+}
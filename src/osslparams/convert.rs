@@ -0,0 +1,263 @@
+//! This submodule provides conversions between lists of [`OSSL_PARAM`]s and
+//! Rust structs, for operations with a fixed, known set of params.
+//!
+//! A struct implements [`FromOsslParams`] and [`ToOsslParams`] (usually
+//! through the [`impl_from_ossl_params!`][`crate::impl_from_ossl_params`] and
+//! [`impl_to_ossl_params!`][`crate::impl_to_ossl_params`] macros) by mapping
+//! each of its fields to a key, while the type of each field (implementing
+//! [`ParamField`]) determines how the value is extracted, and which data type
+//! is used to serialize it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use openssl_provider_forge::impl_from_ossl_params;
+//! use openssl_provider_forge::osslparams::*;
+//!
+//! #[derive(Debug, Default, PartialEq)]
+//! struct Settings {
+//!     bits: i64,
+//!     group: String,
+//! }
+//!
+//! impl_from_ossl_params!(Settings {
+//!     bits: c"bits",
+//!     group: c"group",
+//! });
+//!
+//! let params_list = [
+//!     OSSLParam::new_const_int(c"bits", Some(&2048i64)),
+//!     CONST_OSSL_PARAM::END,
+//! ];
+//!
+//! let settings = Settings::from_params(params_list.as_ptr() as *const OSSL_PARAM).unwrap();
+//! assert_eq!(settings, Settings { bits: 2048, group: String::new() });
+//! ```
+
+use std::ffi::{CStr, CString};
+
+use crate::osslparams::{OSSLParam, OSSLParamError, OwnedParamList, OwnedParamValue, OSSL_PARAM};
+
+/// A Rust type which can be extracted from (and serialized into) a single
+/// [`OSSLParam`], to be used as a field of a struct implementing
+/// [`FromOsslParams`] or [`ToOsslParams`].
+pub trait ParamField: Sized {
+    /// Extracts a value from `param`, returning `None` if the param has an
+    /// incompatible type or its value does not fit `Self`.
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self>;
+
+    /// Serializes this value, choosing the data type of the param through
+    /// the [`OwnedParamValue`] variant.
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError>;
+}
+
+impl ParamField for i32 {
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self> {
+        param.get::<i32>()
+    }
+
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError> {
+        Ok(OwnedParamValue::Int((*self).into()))
+    }
+}
+
+impl ParamField for i64 {
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self> {
+        param.get::<i64>()
+    }
+
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError> {
+        Ok(OwnedParamValue::Int(*self))
+    }
+}
+
+impl ParamField for u32 {
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self> {
+        param.get::<u64>()?.try_into().ok()
+    }
+
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError> {
+        Ok(OwnedParamValue::UInt((*self).into()))
+    }
+}
+
+impl ParamField for u64 {
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self> {
+        param.get::<u64>()
+    }
+
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError> {
+        Ok(OwnedParamValue::UInt(*self))
+    }
+}
+
+impl ParamField for CString {
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self> {
+        param.get::<&CStr>().map(CStr::to_owned)
+    }
+
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError> {
+        Ok(OwnedParamValue::Utf8String(self.clone()))
+    }
+}
+
+impl ParamField for String {
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self> {
+        param.get::<&CStr>()?.to_str().ok().map(String::from)
+    }
+
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError> {
+        CString::new(self.as_str())
+            .map(OwnedParamValue::Utf8String)
+            .map_err(|e| {
+                OSSLParamError::Other(format!(
+                    "Cannot store {self:?} as a UTF-8 string param: {e}"
+                ))
+            })
+    }
+}
+
+impl ParamField for Vec<u8> {
+    fn from_param(param: &OSSLParam<'_>) -> Option<Self> {
+        param.get::<&[u8]>().map(<[u8]>::to_vec)
+    }
+
+    fn to_param_value(&self) -> Result<OwnedParamValue, OSSLParamError> {
+        Ok(OwnedParamValue::OctetString(self.clone()))
+    }
+}
+
+/// A Rust struct which can be populated from a list of [`OSSL_PARAM`]s.
+///
+/// Use the [`impl_from_ossl_params!`][`crate::impl_from_ossl_params`] macro
+/// to implement this trait.
+pub trait FromOsslParams {
+    /// Updates the fields of `self` for which a param is found in the
+    /// properly END-terminated list starting at `head`.
+    ///
+    /// Fields without a corresponding param are left untouched, while a param
+    /// whose value cannot be converted to the type of its field is an error.
+    fn update_from_params(&mut self, head: *const OSSL_PARAM) -> Result<(), OSSLParamError>;
+
+    /// Creates a new instance from the list of params starting at `head`,
+    /// leaving any field without a corresponding param to its default value.
+    fn from_params(head: *const OSSL_PARAM) -> Result<Self, OSSLParamError>
+    where
+        Self: Default,
+    {
+        let mut ret = Self::default();
+        ret.update_from_params(head)?;
+        Ok(ret)
+    }
+}
+
+/// A Rust struct which can be serialized into an [`OwnedParamList`].
+///
+/// Use the [`impl_to_ossl_params!`][`crate::impl_to_ossl_params`] macro to
+/// implement this trait.
+pub trait ToOsslParams {
+    /// Serializes `self` into a new END-terminated [`OwnedParamList`].
+    fn to_params(&self) -> Result<OwnedParamList, OSSLParamError>;
+}
+
+/// Implements [`FromOsslParams`] for a struct, mapping each listed field to
+/// the key of the param it is read from.
+///
+/// The type of each listed field must implement [`ParamField`], and the
+/// fields which are not listed are never modified.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::impl_from_ossl_params;
+/// use openssl_provider_forge::osslparams::*;
+///
+/// #[derive(Default)]
+/// struct Settings {
+///     bits: u32,
+///     seed: Vec<u8>,
+/// }
+///
+/// impl_from_ossl_params!(Settings {
+///     bits: c"bits",
+///     seed: c"seed",
+/// });
+/// ```
+#[macro_export]
+macro_rules! impl_from_ossl_params {
+    ($type:ty { $($field:ident : $key:expr),* $(,)? }) => {
+        impl $crate::osslparams::FromOsslParams for $type {
+            fn update_from_params(
+                &mut self,
+                head: *const $crate::osslparams::OSSL_PARAM,
+            ) -> ::std::result::Result<(), $crate::osslparams::OSSLParamError> {
+                $(
+                    let key: &$crate::osslparams::KeyType = $key;
+                    if let Some(p) = $crate::osslparams::ossl_param_locate_const(head, key) {
+                        self.$field = $crate::osslparams::ParamField::from_param(&p)
+                            .ok_or_else(|| {
+                                ::std::format!(
+                                    "Cannot convert param {:?} to the type of field `{}`",
+                                    key,
+                                    ::std::stringify!($field),
+                                )
+                            })?;
+                    }
+                )*
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implements [`ToOsslParams`] for a struct, mapping each listed field to the
+/// key of the param it is written to.
+///
+/// The type of each listed field must implement [`ParamField`], which also
+/// determines the data type of the param.
+/// The params are listed in the same order as the fields in the macro
+/// invocation, and the fields which are not listed are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::{impl_from_ossl_params, impl_to_ossl_params};
+/// use openssl_provider_forge::osslparams::*;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Settings {
+///     bits: u32,
+///     group: String,
+/// }
+///
+/// impl_from_ossl_params!(Settings { bits: c"bits", group: c"group" });
+/// impl_to_ossl_params!(Settings { bits: c"bits", group: c"group" });
+///
+/// let settings = Settings { bits: 128, group: "x25519".to_string() };
+/// let list = settings.to_params().unwrap();
+/// assert_eq!(Settings::from_params(list.as_ptr()), Ok(settings));
+/// ```
+#[macro_export]
+macro_rules! impl_to_ossl_params {
+    ($type:ty { $($field:ident : $key:expr),* $(,)? }) => {
+        impl $crate::osslparams::ToOsslParams for $type {
+            fn to_params(
+                &self,
+            ) -> ::std::result::Result<
+                $crate::osslparams::OwnedParamList,
+                $crate::osslparams::OSSLParamError,
+            > {
+                let entries = ::std::vec![
+                    $({
+                        let key: &$crate::osslparams::KeyType = $key;
+                        (
+                            key.to_owned(),
+                            $crate::osslparams::ParamField::to_param_value(&self.$field)?,
+                        )
+                    }),*
+                ];
+                Ok($crate::osslparams::OwnedParamList::from_entries(entries))
+            }
+        }
+    };
+}
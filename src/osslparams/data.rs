@@ -1,9 +1,12 @@
 //! The `data` module provides functionalities for handling different data types.
 //! Data types include integers (`int`), unsigned integers (`uint`),
-//! UTF-8 pointers (`utf8_ptr`), and Octet.
+//! UTF-8 pointers (`utf8_ptr`), Octet, and (if supported by OpenSSL) floating
+//! point numbers (`real`).
 //!
 
 pub mod int;
 pub mod octet;
+#[cfg(ossl_param_type = "real")]
+pub mod real;
 pub mod uint;
 pub mod utf8;
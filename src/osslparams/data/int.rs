@@ -8,8 +8,9 @@ use num_traits::ToPrimitive;
 
 use crate::bindings::{OSSL_PARAM, OSSL_PARAM_INTEGER};
 use crate::osslparams::{
-    impl_setter, new_null_param, IntData, KeyType, OSSLParam, OSSLParamData, OSSLParamError,
-    OSSLParamGetter, TypedOSSLParamData,
+    check_zero_size_buffer, impl_narrowing_getter, impl_setter, new_null_param,
+    unsupported_size_error, IntData, KeyType, OSSLParam, OSSLParamData, OSSLParamError,
+    OSSLParamGetter, Ownership, ParamPtr, TypedOSSLParamData,
 };
 
 /// A marker trait that extends `PrimInt` from `num_traits`,
@@ -23,11 +24,7 @@ impl PrimIntMarker for i64 {}
 
 impl OSSLParamData for IntData<'_> {
     fn new_null(key: &KeyType) -> Self {
-        let param_data = new_null_param!(IntData, OSSL_PARAM_INTEGER, key);
-        let buf = Box::into_raw(Box::new(0i64));
-        param_data.param.data = buf as *mut std::ffi::c_void;
-        param_data.param.data_size = size_of::<i64>();
-        param_data
+        new_null_param!(IntData, OSSL_PARAM_INTEGER, key, size_of::<i64>())
     }
 }
 
@@ -68,6 +65,9 @@ impl OSSLParamGetter<i32> for OSSLParam<'_> {
     }
 }
 
+impl_narrowing_getter!(i16, i64, to_i16);
+impl_narrowing_getter!(i8, i64, to_i8);
+
 /* Implementing cross-signedness getters (e.g. impling TypedOSSLParamGetter<u64> for
  * OSSLParam::IntData) is out of scope. If the user wants to get a u64 from that then they can get
  * a i64 from it and cast it themselves.
@@ -93,11 +93,40 @@ impl OSSLParamGetter<i64> for OSSLParam<'_> {
     }
 }
 
+impl OSSLParamGetter<i128> for OSSLParam<'_> {
+    fn get_inner(&self) -> Option<i128> {
+        if let OSSLParam::Int(d) = self {
+            let data = d.param.data;
+            if data.is_null() {
+                return None;
+            }
+            match d.param.data_size {
+                s if s == size_of::<i32>() => {
+                    Some(unsafe { std::ptr::read(data as *const i32) } as i128)
+                }
+                s if s == size_of::<i64>() => {
+                    Some(unsafe { std::ptr::read(data as *const i64) } as i128)
+                }
+                // Buffers coming from C are not guaranteed to be aligned for i128, and the
+                // native-endian two's complement representation carries the sign.
+                s if s == size_of::<i128>() => {
+                    Some(unsafe { std::ptr::read_unaligned(data as *const i128) })
+                }
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
 impl<T: PrimIntMarker> TypedOSSLParamData<T> for IntData<'_> {
     // https://github.com/openssl/openssl/blob/7f62adaf2b088de38ad2e534d0bfae2ff7ae01f2/crypto/params.c#L780-L796
     fn set(&mut self, value: T) -> Result<(), OSSLParamError> {
-        let p = &mut *self.param;
+        // SAFETY: only writable params can be set, see `OSSLParam::set()`.
+        let p = unsafe { self.param.as_mut() };
         p.return_size = size_of::<i64>();
+        check_zero_size_buffer(p)?;
         if p.data.is_null() {
             Ok(())
         } else {
@@ -108,7 +137,7 @@ impl<T: PrimIntMarker> TypedOSSLParamData<T> for IntData<'_> {
                         unsafe { std::ptr::write(p.data as *mut i32, x) };
                         Ok(())
                     } else {
-                        Err("value could not be converted to i32".to_string())
+                        Err(OSSLParamError::ConversionFailed { target: "i32" })
                     }
                 }
                 s if s == size_of::<i64>() => {
@@ -116,10 +145,13 @@ impl<T: PrimIntMarker> TypedOSSLParamData<T> for IntData<'_> {
                         unsafe { std::ptr::write(p.data as *mut i64, x) };
                         Ok(())
                     } else {
-                        Err("value could not be converted to i64".to_string())
+                        Err(OSSLParamError::ConversionFailed { target: "i64" })
                     }
                 }
-                _ => Err("param.data_size was neither the size of i32 nor of i64".to_string()),
+                _ => Err(unsupported_size_error(
+                    p,
+                    &[size_of::<i32>(), size_of::<i64>()],
+                )),
             }
         }
     }
@@ -149,12 +181,17 @@ impl TryFrom<*mut OSSL_PARAM> for IntData<'_> {
     /// ```
     ///
     fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
-        match unsafe { param.as_mut() } {
+        // SAFETY: `param` comes from C (or from other Rust code), and it is
+        // valid for `'a`, if not `NULL`.
+        match unsafe { ParamPtr::from_raw(param) } {
             Some(param) => {
                 if param.data_type != OSSL_PARAM_INTEGER {
                     Err("tried to make IntData from OSSL_PARAM with data_type != OSSL_PARAM_INTEGER")
                 } else {
-                    Ok(IntData { param })
+                    Ok(IntData {
+                        param,
+                        ownership: Ownership::Borrowed,
+                    })
                 }
             }
             None => Err("tried to make IntData from null pointer"),
@@ -1,30 +1,92 @@
-//! This submodule provides functionality for handling OpenSSL octet parameters.
+//! This submodule provides functionality for handling OpenSSL octet parameters,
+//! both as strings ([`OSSL_PARAM_OCTET_STRING`]) and as pointers
+//! ([`OSSL_PARAM_OCTET_PTR`]).
 
 use std::slice::from_raw_parts;
 
-use crate::bindings::{OSSL_PARAM, OSSL_PARAM_OCTET_STRING};
+use zeroize::Zeroizing;
+
+use crate::bindings::{
+    OSSL_PARAM, OSSL_PARAM_OCTET_PTR, OSSL_PARAM_OCTET_STRING, OSSL_PARAM_UNMODIFIED,
+};
 use crate::osslparams::{
-    impl_setter, new_null_param, KeyType, OSSLParam, OSSLParamData, OSSLParamError,
-    OSSLParamGetter, OctetStringData, TypedOSSLParamData,
+    check_zero_size_buffer, error_key, new_null_param, setter_type_err, KeyType, OSSLParam,
+    OSSLParamData, OSSLParamError, OSSLParamGetter, OSSLParamSetter, OctetPtrData, OctetStringData,
+    Ownership, ParamPtr, TypedOSSLParamData,
 };
 
-// TODO: don't leak the buffer
 // TODO, maybe: let the user specify how big the buffer should be
 impl OSSLParamData for OctetStringData<'_> {
     fn new_null(key: &KeyType) -> Self
     where
         Self: Sized,
     {
-        let param_data = new_null_param!(OctetStringData, OSSL_PARAM_OCTET_STRING, key);
         let bufsize = 1024;
-        let buf = Box::into_raw(vec![0u8; bufsize].into_boxed_slice());
-        param_data.param.data = buf as *mut std::ffi::c_void;
-        param_data.param.data_size = bufsize;
-        param_data
+        new_null_param!(OctetStringData, OSSL_PARAM_OCTET_STRING, key, bufsize)
+    }
+}
+
+impl OSSLParamData for OctetPtrData<'_> {
+    fn new_null(key: &KeyType) -> Self
+    where
+        Self: Sized,
+    {
+        new_null_param!(OctetPtrData, OSSL_PARAM_OCTET_PTR, key)
     }
 }
 
-impl_setter!(&[u8], OctetString);
+impl<'a> OctetStringData<'a> {
+    /// Creates a new [`OctetStringData`] whose `data`/`data_size` describe
+    /// the caller-provided `buf`.
+    ///
+    /// This matches the OpenSSL pattern of filling a buffer provided by the
+    /// caller: setting a value writes it directly into `buf`, without any
+    /// allocation, and fails if `buf` is too small to fit it.
+    ///
+    /// The underlying [`OSSL_PARAM`] struct is owned by the returned
+    /// [`OctetStringData`], and freed when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// {
+    ///     let mut p = OSSLParam::OctetString(OctetStringData::from_out_buffer(c"out", &mut buf));
+    ///     assert!(p.set(&[1u8, 2, 3][..]).is_ok());
+    /// }
+    /// assert_eq!(buf[..3], [1, 2, 3]);
+    /// ```
+    pub fn from_out_buffer(key: &'a KeyType, buf: &'a mut [u8]) -> Self {
+        OctetStringData {
+            param: Box::leak(Box::new(OSSL_PARAM {
+                key: key.as_ptr(),
+                data_type: OSSL_PARAM_OCTET_STRING,
+                data: buf.as_mut_ptr().cast(),
+                data_size: buf.len(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            }))
+            .into(),
+            // only the `OSSL_PARAM` is owned, `buf` is borrowed
+            ownership: Ownership::ParamOnly,
+        }
+    }
+}
+
+// For these, we can't use impl_setter!, because that macro only lets you specify one enum variant
+// per Rust type.
+impl<'a> OSSLParamSetter<&'a [u8]> for OSSLParam<'_> {
+    fn set_inner(&mut self, value: &'a [u8]) -> Result<(), OSSLParamError> {
+        if let OSSLParam::OctetString(d) = self {
+            d.set(value)
+        } else if let OSSLParam::OctetPtr(d) = self {
+            d.set(value)
+        } else {
+            Err(setter_type_err!(self, value))
+        }
+    }
+}
 
 // A potential issue here (which I think is the same with Utf8String) is that this returns a slice
 // which points to the same underlying memory used internally by the param, whereas the
@@ -40,27 +102,81 @@ impl<'a> OSSLParamGetter<&'a [u8]> for OSSLParam<'_> {
             }
             let slice = unsafe { from_raw_parts(ptr, d.param.data_size) };
             Some(slice)
+        } else if let OSSLParam::OctetPtr(d) = self {
+            let ptr = d.param.data as *const *const u8;
+            if ptr.is_null() {
+                return None;
+            }
+            let ptr = unsafe { *ptr };
+            if ptr.is_null() {
+                return None;
+            }
+            // Once a responder has set the param, the length of the bytes pointed to is in
+            // `return_size`; otherwise (e.g., for params set by the requester), it's in
+            // `data_size`, as for `OSSL_PARAM_get_octet_ptr()`.
+            let len = match d.param.return_size {
+                OSSL_PARAM_UNMODIFIED => d.param.data_size,
+                len => len,
+            };
+            let slice = unsafe { from_raw_parts(ptr, len) };
+            Some(slice)
         } else {
             None
         }
     }
 }
 
+impl OSSLParam<'_> {
+    /// Returns a copy of the value of an octet string (or pointer) param,
+    /// which is zeroized when dropped.
+    ///
+    /// This is meant for params carrying secrets (e.g., private keys), so
+    /// that the copy does not linger in freed memory.
+    ///
+    /// Returns [`None`] if this is not an octet param, or if its data is
+    /// `NULL`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let secret = [1, 2, 3, 4].map(|b| b as std::ffi::c_char);
+    /// let p = OSSLParam::new_const_octetstring(c"priv", Some(&secret));
+    /// let param = OSSLParam::try_from(&p).unwrap();
+    ///
+    /// let copy = param.get_secret_octets().unwrap();
+    /// assert_eq!(copy.as_slice(), [1, 2, 3, 4]);
+    /// ```
+    pub fn get_secret_octets(&self) -> Option<Zeroizing<Vec<u8>>> {
+        let bytes = self.get::<&[u8]>()?;
+        Some(Zeroizing::new(bytes.to_vec()))
+    }
+}
+
 // This function can leave old data in the param's data buffer if the new data is shorter than what
 // was previously written to the buffer, which bothers me, but I believe it matches the way the
 // corresponding C function is implemented in OSSL, so maybe it's fine....
 impl<'a> TypedOSSLParamData<&'a [u8]> for OctetStringData<'_> {
     fn set(&mut self, value: &'a [u8]) -> Result<(), OSSLParamError> {
-        let p = &mut *self.param;
+        // SAFETY: only writable params can be set, see `OSSLParam::set()`.
+        let p = unsafe { self.param.as_mut() };
         let len = value.len();
+        // As in OpenSSL, `return_size` is always set to the length of the value, so that the
+        // caller can learn how many bytes were written (or are needed, for a size query).
         p.return_size = len;
         if p.data.is_null() {
+            // A size query: the caller only wants to learn the needed size
             // https://github.com/openssl/openssl/blob/85f17585b0d8b55b335f561e2862db14a20b1e64/crypto/params.c#L1398
-            // ?????
             return Ok(());
         }
+        check_zero_size_buffer(p)?;
         if p.data_size < len {
-            return Err("p.data_size in param is too small to fit the octet string".to_string());
+            return Err(OSSLParamError::BufferTooSmall {
+                key: error_key(p),
+                needed: len,
+                available: p.data_size,
+            });
         }
         // Set the inner contents of the param
         unsafe {
@@ -70,20 +186,73 @@ impl<'a> TypedOSSLParamData<&'a [u8]> for OctetStringData<'_> {
     }
 }
 
+// Like for `OSSL_PARAM_set_octet_ptr()`, the param points at the bytes of `value`, which must
+// therefore outlive any use of the param.
+impl<'a> TypedOSSLParamData<&'a [u8]> for OctetPtrData<'_> {
+    fn set(&mut self, value: &'a [u8]) -> Result<(), OSSLParamError> {
+        // SAFETY: only writable params can be set, see `OSSLParam::set()`.
+        let p = unsafe { self.param.as_mut() };
+        p.return_size = value.len();
+        if !p.data.is_null() {
+            unsafe { *(p.data as *mut *const u8) = value.as_ptr() };
+        }
+        Ok(())
+    }
+}
+
 /// ## TODO(🛠️): add examples (tracked by: [#4](https://gitlab.com/nisec/qubip/openssl-provider-forge-rs/-/issues/4))
 impl TryFrom<*mut OSSL_PARAM> for OctetStringData<'_> {
     type Error = OSSLParamError;
 
     fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
-        match unsafe { param.as_mut() } {
+        // SAFETY: `param` comes from C (or from other Rust code), and it is
+        // valid for `'a`, if not `NULL`.
+        match unsafe { ParamPtr::from_raw(param) } {
             Some(param) => {
                 if param.data_type != OSSL_PARAM_OCTET_STRING {
-                    Err("tried to make OctetStringData from OSSL_PARAM with data_type != OSSL_PARAM_OCTET_STRING".to_string())
+                    Err(OSSLParamError::DataTypeMismatch {
+                        key: error_key(&param),
+                        expected: OSSL_PARAM_OCTET_STRING,
+                        got: param.data_type,
+                    })
+                } else {
+                    Ok(OctetStringData {
+                        param,
+                        ownership: Ownership::Borrowed,
+                    })
+                }
+            }
+            None => Err(OSSLParamError::Other(
+                "tried to make OctetStringData from null pointer".to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<*mut OSSL_PARAM> for OctetPtrData<'_> {
+    type Error = OSSLParamError;
+
+    fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
+        // SAFETY: `param` comes from C (or from other Rust code), and it is
+        // valid for `'a`, if not `NULL`.
+        match unsafe { ParamPtr::from_raw(param) } {
+            Some(param) => {
+                if param.data_type != OSSL_PARAM_OCTET_PTR {
+                    Err(OSSLParamError::DataTypeMismatch {
+                        key: error_key(&param),
+                        expected: OSSL_PARAM_OCTET_PTR,
+                        got: param.data_type,
+                    })
                 } else {
-                    Ok(OctetStringData { param })
+                    Ok(OctetPtrData {
+                        param,
+                        ownership: Ownership::Borrowed,
+                    })
                 }
             }
-            None => Err("tried to make OctetStringData from null pointer".to_string()),
+            None => Err(OSSLParamError::Other(
+                "tried to make OctetPtrData from null pointer".to_string(),
+            )),
         }
     }
 }
@@ -0,0 +1,101 @@
+//! The `real` submodule focuses on handling OpenSSL floating point parameters,
+//! represented by the `OSSL_PARAM_REAL` type.
+//! It provides getters and setters for `f64` (the native `double` layout used
+//! by OpenSSL) and `f32`, which is converted to and from `f64` with range
+//! checks.
+//!
+//! This module is only available when building against OpenSSL headers
+//! defining `OSSL_PARAM_REAL`.
+
+use crate::bindings::{OSSL_PARAM, OSSL_PARAM_REAL};
+use crate::osslparams::{
+    check_zero_size_buffer, impl_setter, new_null_param, unsupported_size_error, KeyType,
+    OSSLParam, OSSLParamData, OSSLParamError, OSSLParamGetter, Ownership, ParamPtr, RealData,
+    TypedOSSLParamData,
+};
+
+impl OSSLParamData for RealData<'_> {
+    fn new_null(key: &KeyType) -> Self {
+        new_null_param!(RealData, OSSL_PARAM_REAL, key, size_of::<f64>())
+    }
+}
+
+impl_setter!(f32, Real);
+impl_setter!(f64, Real);
+
+impl OSSLParamGetter<f64> for OSSLParam<'_> {
+    fn get_inner(&self) -> Option<f64> {
+        if let OSSLParam::Real(d) = self {
+            let data = d.param.data;
+            if data.is_null() || d.param.data_size != size_of::<f64>() {
+                return None;
+            }
+            // Buffers coming from C are not guaranteed to be aligned for f64.
+            Some(unsafe { std::ptr::read_unaligned(data as *const f64) })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns [`None`] if the (finite) value does not fit in an `f32`.
+///
+/// NaNs and infinities are converted as they are, while values which only
+/// lose precision are rounded to the nearest `f32`.
+impl OSSLParamGetter<f32> for OSSLParam<'_> {
+    fn get_inner(&self) -> Option<f32> {
+        let value = self.get::<f64>()?;
+        if value.is_finite() && value.abs() > f32::MAX as f64 {
+            None
+        } else {
+            Some(value as f32)
+        }
+    }
+}
+
+/// A marker trait for the floating point types which can be stored in an
+/// [`OSSLParam::Real`].
+pub trait FloatMarker: Into<f64> + Copy {}
+
+impl FloatMarker for f32 {}
+impl FloatMarker for f64 {}
+
+impl<T: FloatMarker> TypedOSSLParamData<T> for RealData<'_> {
+    fn set(&mut self, value: T) -> Result<(), OSSLParamError> {
+        // SAFETY: only writable params can be set, see `OSSLParam::set()`.
+        let p = unsafe { self.param.as_mut() };
+        p.return_size = size_of::<f64>();
+        check_zero_size_buffer(p)?;
+        if p.data.is_null() {
+            Ok(())
+        } else if p.data_size == size_of::<f64>() {
+            unsafe { std::ptr::write_unaligned(p.data as *mut f64, value.into()) };
+            Ok(())
+        } else {
+            Err(unsupported_size_error(p, &[size_of::<f64>()]))
+        }
+    }
+}
+
+/// Converts a raw pointer (`*mut OSSL_PARAM`) into a `RealData`.
+impl TryFrom<*mut OSSL_PARAM> for RealData<'_> {
+    type Error = &'static str;
+
+    fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
+        // SAFETY: `param` comes from C (or from other Rust code), and it is
+        // valid for `'a`, if not `NULL`.
+        match unsafe { ParamPtr::from_raw(param) } {
+            Some(param) => {
+                if param.data_type != OSSL_PARAM_REAL {
+                    Err("tried to make RealData from OSSL_PARAM with data_type != OSSL_PARAM_REAL")
+                } else {
+                    Ok(RealData {
+                        param,
+                        ownership: Ownership::Borrowed,
+                    })
+                }
+            }
+            None => Err("tried to make RealData from null pointer"),
+        }
+    }
+}
@@ -8,8 +8,9 @@
 //!
 use crate::bindings::{OSSL_PARAM, OSSL_PARAM_UNSIGNED_INTEGER};
 use crate::osslparams::{
-    impl_setter, new_null_param, KeyType, OSSLParam, OSSLParamData, OSSLParamError,
-    OSSLParamGetter, TypedOSSLParamData, UIntData,
+    check_zero_size_buffer, impl_narrowing_getter, impl_setter, new_null_param,
+    unsupported_size_error, KeyType, OSSLParam, OSSLParamData, OSSLParamError, OSSLParamGetter,
+    Ownership, ParamPtr, TypedOSSLParamData, UIntData,
 };
 
 /// A marker trait that extends `PrimInt` from `num_traits`, indicating that a type is a primitive unsigned integer.
@@ -25,11 +26,7 @@ impl OSSLParamData for UIntData<'_> {
     where
         Self: Sized,
     {
-        let param_data = new_null_param!(UIntData, OSSL_PARAM_UNSIGNED_INTEGER, key);
-        let buf = Box::into_raw(Box::new(0u64));
-        param_data.param.data = buf as *mut std::ffi::c_void;
-        param_data.param.data_size = size_of::<u64>();
-        param_data
+        new_null_param!(UIntData, OSSL_PARAM_UNSIGNED_INTEGER, key, size_of::<u64>())
     }
 }
 
@@ -88,6 +85,10 @@ impl OSSLParamGetter<u64> for OSSLParam<'_> {
     }
 }
 
+impl_narrowing_getter!(u32, u64, to_u32);
+impl_narrowing_getter!(u16, u64, to_u16);
+impl_narrowing_getter!(u8, u64, to_u8);
+
 /* However, when we're doing `impl ... for UIntData`, we can use the marker trait, because it
  * doesn't risk overlapping with other impls like `impl ... for OSSLParam` does.
  */
@@ -95,8 +96,10 @@ impl OSSLParamGetter<u64> for OSSLParam<'_> {
 impl<T: PrimUIntMarker> TypedOSSLParamData<T> for UIntData<'_> {
     // https://github.com/openssl/openssl/blob/7f62adaf2b088de38ad2e534d0bfae2ff7ae01f2/crypto/params.c#L937-L951
     fn set(&mut self, value: T) -> Result<(), OSSLParamError> {
-        let p = &mut *self.param;
+        // SAFETY: only writable params can be set, see `OSSLParam::set()`.
+        let p = unsafe { self.param.as_mut() };
         p.return_size = size_of::<u64>();
+        check_zero_size_buffer(p)?;
         if p.data.is_null() {
             Ok(())
         } else {
@@ -107,7 +110,7 @@ impl<T: PrimUIntMarker> TypedOSSLParamData<T> for UIntData<'_> {
                         unsafe { std::ptr::write(p.data as *mut u32, x) };
                         Ok(())
                     } else {
-                        Err("value could not be converted to u32".to_string())
+                        Err(OSSLParamError::ConversionFailed { target: "u32" })
                     }
                 }
                 s if s == size_of::<u64>() => {
@@ -115,10 +118,13 @@ impl<T: PrimUIntMarker> TypedOSSLParamData<T> for UIntData<'_> {
                         unsafe { std::ptr::write(p.data as *mut u64, x) };
                         Ok(())
                     } else {
-                        Err("value could not be converted to u64".to_string())
+                        Err(OSSLParamError::ConversionFailed { target: "u64" })
                     }
                 }
-                _ => Err("param.data_size was neither the size of u32 nor of u64".to_string()),
+                _ => Err(unsupported_size_error(
+                    p,
+                    &[size_of::<u32>(), size_of::<u64>()],
+                )),
             }
         }
     }
@@ -148,12 +154,17 @@ impl TryFrom<*mut OSSL_PARAM> for UIntData<'_> {
     /// ```
     ///
     fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
-        match unsafe { param.as_mut() } {
+        // SAFETY: `param` comes from C (or from other Rust code), and it is
+        // valid for `'a`, if not `NULL`.
+        match unsafe { ParamPtr::from_raw(param) } {
             Some(param) => {
                 if param.data_type != OSSL_PARAM_UNSIGNED_INTEGER {
                     Err("tried to make UIntData from OSSL_PARAM with data_type != OSSL_PARAM_UNSIGNED_INTEGER")
                 } else {
-                    Ok(UIntData { param })
+                    Ok(UIntData {
+                        param,
+                        ownership: Ownership::Borrowed,
+                    })
                 }
             }
             None => Err("tried to make UIntData from null pointer"),
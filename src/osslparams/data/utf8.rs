@@ -7,10 +7,13 @@
 
 use std::ffi::{c_char, CStr};
 
-use crate::bindings::{OSSL_PARAM, OSSL_PARAM_UTF8_PTR, OSSL_PARAM_UTF8_STRING};
+use crate::bindings::{
+    OSSL_PARAM, OSSL_PARAM_UNMODIFIED, OSSL_PARAM_UTF8_PTR, OSSL_PARAM_UTF8_STRING,
+};
 use crate::osslparams::{
-    new_null_param, setter_type_err_string, KeyType, OSSLParam, OSSLParamData, OSSLParamError,
-    OSSLParamGetter, OSSLParamSetter, TypedOSSLParamData, Utf8PtrData, Utf8StringData,
+    check_zero_size_buffer, error_key, new_null_param, setter_type_err, KeyType, OSSLParam,
+    OSSLParamData, OSSLParamError, OSSLParamGetter, OSSLParamSetter, Ownership, ParamPtr,
+    TypedOSSLParamData, Utf8PtrData, Utf8StringData,
 };
 
 impl OSSLParamData for Utf8PtrData<'_> {
@@ -22,19 +25,63 @@ impl OSSLParamData for Utf8PtrData<'_> {
     }
 }
 
-// TODO: don't leak the buffer
 // TODO, maybe: let the user specify how big the buffer should be
 impl OSSLParamData for Utf8StringData<'_> {
     fn new_null(key: &KeyType) -> Self
     where
         Self: Sized,
     {
-        let param_data = new_null_param!(Utf8StringData, OSSL_PARAM_UTF8_STRING, key);
         let bufsize = 1024;
-        let buf = Box::into_raw(vec![0u8; bufsize].into_boxed_slice());
-        param_data.param.data = buf as *mut std::ffi::c_void;
-        param_data.param.data_size = bufsize;
-        param_data
+        new_null_param!(Utf8StringData, OSSL_PARAM_UTF8_STRING, key, bufsize)
+    }
+}
+
+impl OSSLParam<'_> {
+    /// Returns the _capacity_ of an [`OSSLParam::Utf8String`], i.e. the size
+    /// (in bytes) of the buffer its `data` points at.
+    ///
+    /// This is the `data_size` field, which is set by the caller requesting
+    /// the param, and it is the longest string (optionally followed by a NUL
+    /// byte) which can be set into the param.
+    ///
+    /// Returns `None` for any other variant.
+    pub fn utf8_capacity(&self) -> Option<usize> {
+        match self {
+            OSSLParam::Utf8String(d) => Some(d.param.data_size),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bytes _used_ by the string set into an
+    /// [`OSSLParam::Utf8String`] (excluding the terminating NUL byte).
+    ///
+    /// This is the `return_size` field, which is set by the responder.
+    /// In the size-query protocol (i.e., when `data` is `NULL`) it is the
+    /// capacity needed to fit the string, so it can be larger than
+    /// [`OSSLParam::utf8_capacity`].
+    ///
+    /// Returns `None` if the param has not been modified (i.e., its
+    /// `return_size` is [`OSSL_PARAM_UNMODIFIED`]), and for any other
+    /// variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut param = OSSLParam::Utf8String(Utf8StringData::new_null(c"group"));
+    /// param.set(c"x25519").unwrap();
+    /// assert_eq!(param.utf8_used(), Some(6));
+    /// assert!(param.utf8_capacity().unwrap() > 6);
+    /// ```
+    pub fn utf8_used(&self) -> Option<usize> {
+        match self {
+            OSSLParam::Utf8String(d) => match d.param.return_size {
+                OSSL_PARAM_UNMODIFIED => None,
+                n => Some(n),
+            },
+            _ => None,
+        }
     }
 }
 
@@ -47,7 +94,7 @@ impl OSSLParamSetter<*const CStr> for OSSLParam<'_> {
         } else if let OSSLParam::Utf8String(d) = self {
             d.set(value)
         } else {
-            Err(setter_type_err_string!(self, value))
+            Err(setter_type_err!(self, value))
         }
     }
 }
@@ -59,7 +106,7 @@ impl OSSLParamSetter<&'static CStr> for OSSLParam<'_> {
         } else if let OSSLParam::Utf8String(d) = self {
             d.set(value)
         } else {
-            Err(setter_type_err_string!(self, value))
+            Err(setter_type_err!(self, value))
         }
     }
 }
@@ -78,6 +125,10 @@ impl<'a> OSSLParamGetter<&'a CStr> for OSSLParam<'_> {
             if ptr.is_null() {
                 return None;
             }
+            if d.param.data_size == 0 {
+                // there may be no room even for the terminating NUL byte
+                return Some(c"");
+            }
             let v = unsafe { CStr::from_ptr(ptr) };
             Some(v)
         } else {
@@ -88,7 +139,8 @@ impl<'a> OSSLParamGetter<&'a CStr> for OSSLParam<'_> {
 
 impl TypedOSSLParamData<*const CStr> for Utf8PtrData<'_> {
     fn set(&mut self, value: *const CStr) -> Result<(), OSSLParamError> {
-        let p = &mut *self.param;
+        // SAFETY: only writable params can be set, see `OSSLParam::set()`.
+        let p = unsafe { self.param.as_mut() };
         if p.data.is_null() {
             p.return_size = 0;
         } else {
@@ -97,7 +149,7 @@ impl TypedOSSLParamData<*const CStr> for Utf8PtrData<'_> {
                     p.return_size = cstr.to_bytes().len();
                     unsafe { *(p.data as *mut *const c_char) = cstr.as_ptr() };
                 }
-                None => return Err("couldn't get &CStr from *const CStr".to_string()),
+                None => return Err(OSSLParamError::NullData),
             }
         }
         Ok(())
@@ -106,21 +158,25 @@ impl TypedOSSLParamData<*const CStr> for Utf8PtrData<'_> {
 
 impl TypedOSSLParamData<*const CStr> for Utf8StringData<'_> {
     fn set(&mut self, value: *const CStr) -> Result<(), OSSLParamError> {
-        let p = &mut *self.param;
+        // SAFETY: only writable params can be set, see `OSSLParam::set()`.
+        let p = unsafe { self.param.as_mut() };
         p.return_size = 0;
         if value.is_null() {
-            return Err("value was null".to_string());
+            return Err(OSSLParamError::NullData);
         }
         // Set the inner contents of the param
         match unsafe { value.as_ref() } {
             Some(cstr) => {
                 let len = cstr.to_bytes().len();
                 p.return_size = len;
+                check_zero_size_buffer(p)?;
                 if !p.data.is_null() {
                     if p.data_size < len {
-                        return Err(
-                            "p.data_size in param is too small to fit the string".to_string()
-                        );
+                        return Err(OSSLParamError::BufferTooSmall {
+                            key: error_key(p),
+                            needed: len,
+                            available: p.data_size,
+                        });
                     }
                     // copy the string, with the terminating null byte if there's room for it
                     let total_len = if p.data_size > len { len + 1 } else { len };
@@ -128,7 +184,7 @@ impl TypedOSSLParamData<*const CStr> for Utf8StringData<'_> {
                 }
                 Ok(())
             }
-            None => Err("couldn't get &CStr from *const CStr".to_string()),
+            None => Err(OSSLParamError::NullData),
         }
     }
 }
@@ -149,15 +205,26 @@ impl TryFrom<*mut OSSL_PARAM> for Utf8PtrData<'_> {
     /// expected OpenSSL parameter types.
     ///
     fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
-        match unsafe { param.as_mut() } {
+        // SAFETY: `param` comes from C (or from other Rust code), and it is
+        // valid for `'a`, if not `NULL`.
+        match unsafe { ParamPtr::from_raw(param) } {
             Some(param) => {
                 if param.data_type != OSSL_PARAM_UTF8_PTR {
-                    Err("tried to make Utf8PtrData from OSSL_PARAM with data_type != OSSL_PARAM_UTF8_PTR".to_string())
+                    Err(OSSLParamError::DataTypeMismatch {
+                        key: error_key(&param),
+                        expected: OSSL_PARAM_UTF8_PTR,
+                        got: param.data_type,
+                    })
                 } else {
-                    Ok(Utf8PtrData { param })
+                    Ok(Utf8PtrData {
+                        param,
+                        ownership: Ownership::Borrowed,
+                    })
                 }
             }
-            None => Err("tried to make Utf8PtrData from null pointer".to_string()),
+            None => Err(OSSLParamError::Other(
+                "tried to make Utf8PtrData from null pointer".to_string(),
+            )),
         }
     }
 }
@@ -167,15 +234,26 @@ impl TryFrom<*mut OSSL_PARAM> for Utf8StringData<'_> {
     type Error = OSSLParamError;
 
     fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
-        match unsafe { param.as_mut() } {
+        // SAFETY: `param` comes from C (or from other Rust code), and it is
+        // valid for `'a`, if not `NULL`.
+        match unsafe { ParamPtr::from_raw(param) } {
             Some(param) => {
                 if param.data_type != OSSL_PARAM_UTF8_STRING {
-                    Err("tried to make Utf8StringData from OSSL_PARAM with data_type != OSSL_PARAM_UTF8_STRING".to_string())
+                    Err(OSSLParamError::DataTypeMismatch {
+                        key: error_key(&param),
+                        expected: OSSL_PARAM_UTF8_STRING,
+                        got: param.data_type,
+                    })
                 } else {
-                    Ok(Utf8StringData { param })
+                    Ok(Utf8StringData {
+                        param,
+                        ownership: Ownership::Borrowed,
+                    })
                 }
             }
-            None => Err("tried to make Utf8StringData from null pointer".to_string()),
+            None => Err(OSSLParamError::Other(
+                "tried to make Utf8StringData from null pointer".to_string(),
+            )),
         }
     }
 }
@@ -0,0 +1,192 @@
+//! This submodule defines [`OSSLParamError`], the error type of the
+//! operations involving [`OSSLParam`][`super::OSSLParam`].
+
+use std::fmt;
+
+use super::data_type_name;
+
+/// The error returned by the operations involving
+/// [`OSSLParam`][`super::OSSLParam`].
+///
+/// Callers can branch on the variant to tell the failures apart, while the
+/// [`Display`][`fmt::Display`] implementation renders a descriptive message
+/// (and the conversion to [`String`] is available for code which used to
+/// handle these errors as plain messages).
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut buf = [0u8; 3];
+/// let mut p = OSSL_PARAM {
+///     key: c"foo".as_ptr(),
+///     data_type: OSSL_PARAM_INTEGER,
+///     data: buf.as_mut_ptr().cast(),
+///     data_size: buf.len(),
+///     return_size: OSSL_PARAM_UNMODIFIED,
+/// };
+/// let err = OSSLParam::try_from(&mut p).unwrap().set(42i64).unwrap_err();
+/// match &err {
+///     OSSLParamError::UnsupportedSize { data_size, .. } => assert_eq!(*data_size, 3),
+///     _ => panic!("unexpected error: {err}"),
+/// }
+/// let message: String = err.into();
+/// assert!(message.contains("data_size 3"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OSSLParamError {
+    /// A value of type `got` cannot be stored in (or read from) a param of the
+    /// `expected` variant.
+    TypeMismatch {
+        /// The name of the [`OSSLParam`][`super::OSSLParam`] variant.
+        expected: String,
+        /// The name of the type of the value.
+        got: String,
+    },
+
+    /// The value to store is `NULL`.
+    NullData,
+
+    /// The `data` buffer of the param `key` has room for `available` bytes,
+    /// but `needed` bytes are needed to store the value.
+    ///
+    /// See [`is_buffer_too_small()`][`super::is_buffer_too_small`].
+    BufferTooSmall {
+        /// The key of the param.
+        key: String,
+        /// The size of the value.
+        needed: usize,
+        /// The `data_size` of the param.
+        available: usize,
+    },
+
+    /// The `data_size` of the param `key` is not among the `supported` ones.
+    ///
+    /// See [`is_unsupported_size()`][`super::is_unsupported_size`].
+    UnsupportedSize {
+        /// The key of the param.
+        key: String,
+        /// The `data_size` of the param.
+        data_size: usize,
+        /// The supported sizes, in bytes.
+        supported: Vec<usize>,
+    },
+
+    /// The param `key` has the data type reserved for the END item.
+    ///
+    /// See [`is_malformed_param()`][`super::is_malformed_param`].
+    MalformedParam {
+        /// The key of the param.
+        key: String,
+    },
+
+    /// The param `key` has data type `got`, but `expected` is expected (e.g.,
+    /// by the params descriptor of an operation).
+    DataTypeMismatch {
+        /// The key of the param.
+        key: String,
+        /// The expected data type.
+        expected: u32,
+        /// The data type of the param.
+        got: u32,
+    },
+
+    /// The param `key` is not known (e.g., it is not in the params
+    /// descriptor of an operation).
+    UnknownKey {
+        /// The key of the param.
+        key: String,
+    },
+
+    /// The value cannot be converted to the `target` type, as it does not fit.
+    ConversionFailed {
+        /// The name of the target type.
+        target: &'static str,
+    },
+
+    /// Any other failure, described by its message.
+    Other(String),
+}
+
+impl fmt::Display for OSSLParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeMismatch { expected, got } => {
+                write!(f, "Type {got} could not be stored in OSSLParam::{expected}")
+            }
+            Self::NullData => write!(f, "value was null"),
+            Self::BufferTooSmall {
+                key, available: 0, ..
+            } => write!(f, "OSSL_PARAM {key:?} has a zero-size data buffer"),
+            Self::BufferTooSmall {
+                key,
+                needed,
+                available,
+            } => write!(
+                f,
+                "OSSL_PARAM {key:?} has data_size {available}, but {needed} bytes are needed"
+            ),
+            Self::UnsupportedSize {
+                key,
+                data_size,
+                supported,
+            } => {
+                let supported = supported
+                    .iter()
+                    .map(usize::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "OSSL_PARAM {key:?} has data_size {data_size}, but only sizes of {supported} bytes are supported"
+                )
+            }
+            Self::MalformedParam { key } => write!(
+                f,
+                "OSSL_PARAM {key:?} has data type 0, which is reserved for the END item"
+            ),
+            Self::DataTypeMismatch { key, expected, got } => write!(
+                f,
+                "OSSL_PARAM {key:?} has type {}, but {} is expected",
+                type_name(*got),
+                type_name(*expected),
+            ),
+            Self::UnknownKey { key } => write!(f, "OSSL_PARAM {key:?} is not a known key"),
+            Self::ConversionFailed { target } => {
+                write!(f, "value could not be converted to {target}")
+            }
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for OSSLParamError {}
+
+/// Returns the name of `data_type` for error messages, falling back to its
+/// numeric value.
+fn type_name(data_type: u32) -> String {
+    match data_type_name(data_type) {
+        Some(name) => name.to_string(),
+        None => format!("data type {data_type}"),
+    }
+}
+
+impl From<String> for OSSLParamError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+impl From<&str> for OSSLParamError {
+    fn from(message: &str) -> Self {
+        Self::Other(message.to_string())
+    }
+}
+
+impl From<OSSLParamError> for String {
+    fn from(err: OSSLParamError) -> Self {
+        err.to_string()
+    }
+}
@@ -0,0 +1,125 @@
+//! This submodule provides a migration shim for code written against the
+//! legacy `OSSLParam` API, in which params wrapped raw `ossl_param_st`
+//! pointers.
+//!
+//! The legacy [`OSSLParam`] defined here wraps the current
+//! [`osslparams::OSSLParam`][`super::OSSLParam`], restoring the legacy
+//! signatures of the methods which changed:
+//!
+//! | Method                       | Legacy (this module)     | Current                        |
+//! |------------------------------|--------------------------|--------------------------------|
+//! | [`OSSLParam::get_c_struct`]  | `*mut OSSL_PARAM`        | `*const OSSL_PARAM`            |
+//! | [`OSSLParam::get_key`]       | `&KeyType`               | `Option<&KeyType>`             |
+//!
+//! Every other method is available through [`Deref`]/[`DerefMut`] to the
+//! current type, so in most cases migrating only requires changing the
+//! imports, e.g.:
+//!
+//! ```rust
+//! // use openssl_provider_forge::osslparams::OSSLParam;
+//! use openssl_provider_forge::osslparams::legacy::OSSLParam;
+//! use openssl_provider_forge::osslparams::{CONST_OSSL_PARAM, OSSL_PARAM};
+//!
+//! let params_list = [
+//!     openssl_provider_forge::osslparams::OSSLParam::new_const_int(c"bits", Some(&2048i64)),
+//!     CONST_OSSL_PARAM::END,
+//! ];
+//!
+//! let param = OSSLParam::try_from(params_list.as_ptr() as *mut OSSL_PARAM).unwrap();
+//! assert_eq!(param.get_key(), c"bits");
+//! assert_eq!(param.get::<i64>(), Some(2048));
+//! ```
+//!
+//! New code should use [`osslparams::OSSLParam`][`super::OSSLParam`] directly:
+//! this module is only meant to ease migrations, and may be removed in a
+//! future release.
+
+use std::ops::{Deref, DerefMut};
+
+use super::{KeyType, OSSLParamError, OSSL_PARAM};
+
+/// The legacy `OSSLParam`, wrapping the current
+/// [`osslparams::OSSLParam`][`super::OSSLParam`].
+///
+/// See the [module documentation][`self`] for the differences.
+#[derive(Debug)]
+pub struct OSSLParam<'a>(pub super::OSSLParam<'a>);
+
+impl<'a> OSSLParam<'a> {
+    /// Retrieves the C FFI representation of this param, as a `mut` pointer.
+    ///
+    /// This is equivalent to
+    /// [`osslparams::OSSLParam::get_c_struct_mut`][`super::OSSLParam::get_c_struct_mut`],
+    /// without requiring a `&mut self`.
+    pub fn get_c_struct(&self) -> *mut OSSL_PARAM {
+        self.0.get_c_struct().cast_mut()
+    }
+
+    /// Retrieves the key of this param.
+    ///
+    /// # Panics
+    ///
+    /// Unlike [`osslparams::OSSLParam::get_key`][`super::OSSLParam::get_key`],
+    /// this panics if the key is `NULL` (i.e., for a terminating item).
+    pub fn get_key(&self) -> &KeyType {
+        self.0
+            .get_key()
+            .expect("legacy OSSLParam::get_key() called on an item without a key")
+    }
+
+    /// Returns the wrapped current [`osslparams::OSSLParam`][`super::OSSLParam`].
+    pub fn into_inner(self) -> super::OSSLParam<'a> {
+        self.0
+    }
+}
+
+impl<'a> Deref for OSSLParam<'a> {
+    type Target = super::OSSLParam<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OSSLParam<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'a> From<super::OSSLParam<'a>> for OSSLParam<'a> {
+    fn from(param: super::OSSLParam<'a>) -> Self {
+        Self(param)
+    }
+}
+
+impl<'a> From<OSSLParam<'a>> for super::OSSLParam<'a> {
+    fn from(param: OSSLParam<'a>) -> Self {
+        param.0
+    }
+}
+
+impl TryFrom<*mut OSSL_PARAM> for OSSLParam<'_> {
+    type Error = OSSLParamError;
+
+    fn try_from(param: *mut OSSL_PARAM) -> Result<Self, Self::Error> {
+        super::OSSLParam::try_from(param).map(Self)
+    }
+}
+
+impl TryFrom<*const OSSL_PARAM> for OSSLParam<'_> {
+    type Error = OSSLParamError;
+
+    fn try_from(param: *const OSSL_PARAM) -> Result<Self, Self::Error> {
+        super::OSSLParam::try_from(param).map(Self)
+    }
+}
+
+impl<'a> IntoIterator for OSSLParam<'a> {
+    type Item = OSSLParam<'a>;
+    type IntoIter = std::iter::Map<super::OSSLParamIterator<'a>, fn(super::OSSLParam<'a>) -> Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().map(OSSLParam)
+    }
+}
@@ -0,0 +1,921 @@
+//! This submodule provides _owned_ lists of [`OSSL_PARAM`]s, i.e. lists which
+//! are built at runtime and own the memory backing their keys and values.
+//!
+//! Params declared with the `new_const_*` constructors of [`OSSLParam`] must
+//! point at data living for the whole lifetime of the program.
+//! The types in this module instead copy keys and values into buffers they
+//! own, so that the resulting lists remain valid for as long as the owning
+//! object is alive.
+
+use std::ffi::{c_char, c_void, CStr, CString};
+
+use crate::osslparams::{
+    KeyType, OSSLParam, OSSLParamError, OSSLParamIterator, ParamType, CONST_OSSL_PARAM, OSSL_PARAM,
+    OSSL_PARAM_INTEGER, OSSL_PARAM_OCTET_PTR, OSSL_PARAM_OCTET_STRING, OSSL_PARAM_UNMODIFIED,
+    OSSL_PARAM_UNSIGNED_INTEGER, OSSL_PARAM_UTF8_PTR, OSSL_PARAM_UTF8_STRING,
+};
+
+/// Backing storage for the keys and values referenced by owned param lists.
+///
+/// Values are stored in `u128`-aligned buffers, so that the integer getters
+/// can read them in place regardless of their width.
+///
+/// Moving a [`ParamStorage`] does not move the heap allocations it owns, so
+/// pointers handed out by its methods remain valid until it is dropped.
+#[derive(Debug, Default)]
+pub(crate) struct ParamStorage {
+    keys: Vec<CString>,
+    buffers: Vec<Box<[u128]>>,
+}
+
+impl ParamStorage {
+    /// Stores a copy of `key`, returning a pointer to the copy.
+    pub(crate) fn store_key(&mut self, key: &CStr) -> *const c_char {
+        // The pointer is only taken once the copy has been moved into
+        // `self.keys`, as moving it would invalidate any pointer taken before
+        // (under the Stacked Borrows model).
+        self.keys.push(key.to_owned());
+        self.keys[self.keys.len() - 1].as_ptr()
+    }
+
+    /// Stores a copy of `bytes` (optionally followed by a NUL byte, which is
+    /// not counted in any size), returning a pointer to the copy.
+    pub(crate) fn store_bytes(&mut self, bytes: &[u8], nul_terminate: bool) -> *mut c_void {
+        let len = bytes.len() + usize::from(nul_terminate);
+        let buf = vec![0u128; len.div_ceil(size_of::<u128>()).max(1)].into_boxed_slice();
+        // as in `store_key()`, only take the pointer once `buf` has been moved
+        self.buffers.push(buf);
+        let last = self.buffers.len() - 1;
+        let ptr = self.buffers[last].as_mut_ptr() as *mut u8;
+        // SAFETY: the buffer is at least `bytes.len()` bytes long, and it's
+        // freshly allocated, so it cannot overlap with `bytes`.
+        unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len()) };
+        ptr as *mut c_void
+    }
+
+    /// Stores the pointer `target` itself, returning a pointer to where it has
+    /// been stored (as expected by the `*_PTR` data types).
+    pub(crate) fn store_ptr(&mut self, target: *const c_void) -> *mut c_void {
+        let ptr = self.store_bytes(&[0; size_of::<*const c_void>()], false);
+        // SAFETY: the buffer is large enough and `u128`-aligned. The pointer
+        // is written as such (rather than as bytes), to keep its provenance.
+        unsafe { ptr.cast::<*const c_void>().write(target) };
+        ptr
+    }
+
+    /// Deep copies the `data` of `param`, according to its `data_type`,
+    /// returning a pointer to the copy (or `NULL` if `param.data` is `NULL`).
+    ///
+    /// # Safety
+    ///
+    /// `param.data` must be `NULL` or valid for reads as described by
+    /// `param.data_type` and `param.data_size`.
+    pub(crate) unsafe fn store_data(&mut self, param: &OSSL_PARAM) -> *mut c_void {
+        if param.data.is_null() {
+            return std::ptr::null_mut();
+        }
+        match param.data_type {
+            OSSL_PARAM_UTF8_PTR | OSSL_PARAM_OCTET_PTR => {
+                let target = unsafe { *(param.data as *const *const u8) };
+                if target.is_null() {
+                    return self.store_ptr(std::ptr::null());
+                }
+                let bytes = unsafe { std::slice::from_raw_parts(target, param.data_size) };
+                let copy = self.store_bytes(bytes, param.data_type == OSSL_PARAM_UTF8_PTR);
+                self.store_ptr(copy)
+            }
+            data_type => {
+                let bytes =
+                    unsafe { std::slice::from_raw_parts(param.data as *const u8, param.data_size) };
+                self.store_bytes(bytes, data_type == OSSL_PARAM_UTF8_STRING)
+            }
+        }
+    }
+}
+
+/// An owned, END-terminated list of [`CONST_OSSL_PARAM`]s, built at runtime.
+///
+/// It is the runtime counterpart of the `const` arrays produced by the
+/// `new_const_*` constructors of [`OSSLParam`] and by the capability macros:
+/// its keys and values are deep copied into memory owned by the list itself.
+///
+/// # Thread safety
+///
+/// [`OwnedConstParams`] is [`Send`] and [`Sync`], so it can be stored in a
+/// global and shared across threads (e.g., to answer capability queries).
+///
+/// This holds because the list is never mutated after it has been built:
+/// the inner pointers only reference memory owned by the list itself,
+/// which is freed only when the list is dropped.
+///
+/// If a `&'static` list is needed (e.g., to return it from a provider
+/// function), use [`OwnedConstParams::into_static`].
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let value = 42u32;
+/// let owned = {
+///     let params = [
+///         OSSLParam::new_const_uint(c"answer", Some(&value)),
+///         CONST_OSSL_PARAM::END,
+///     ];
+///     OwnedConstParams::from_params(&params)
+/// };
+///
+/// let p = OSSLParam::try_from(owned.as_ptr()).unwrap();
+/// assert_eq!(p.get_key(), Some(c"answer"));
+/// assert_eq!(p.get::<u64>(), Some(42));
+/// ```
+#[derive(Debug)]
+pub struct OwnedConstParams {
+    params: Vec<CONST_OSSL_PARAM>,
+    _storage: ParamStorage,
+}
+
+impl OwnedConstParams {
+    /// Builds a new [`OwnedConstParams`] deep copying the items of `params`.
+    ///
+    /// Items are copied up to the first END item (or to the end of the slice):
+    /// the resulting list is always properly END-terminated.
+    pub fn from_params(params: &[CONST_OSSL_PARAM]) -> Self {
+        let mut storage = ParamStorage::default();
+        let mut owned = Vec::with_capacity(params.len() + 1);
+        for p in params.iter().take_while(|p| !p.key.is_null()) {
+            // SAFETY: `p.key` is not NULL, and `CONST_OSSL_PARAM` items are
+            // expected to always reference valid keys and data.
+            let key = storage.store_key(unsafe { CStr::from_ptr(p.key) });
+            let data = unsafe { storage.store_data(p) };
+            owned.push(CONST_OSSL_PARAM {
+                key,
+                data_type: p.data_type,
+                data,
+                data_size: p.data_size,
+                return_size: p.return_size,
+            });
+        }
+        owned.push(CONST_OSSL_PARAM::END);
+        Self {
+            params: owned,
+            _storage: storage,
+        }
+    }
+
+    /// Returns the list as a slice, **including** the terminating END item.
+    pub fn as_slice(&self) -> &[CONST_OSSL_PARAM] {
+        &self.params
+    }
+
+    /// Returns a pointer to the first item of the list, to be passed to
+    /// OpenSSL functions through the FFI layer.
+    pub fn as_ptr(&self) -> *const OSSL_PARAM {
+        self.params.as_ptr() as *const OSSL_PARAM
+    }
+
+    /// Leaks this list, returning a `&'static` slice (including the
+    /// terminating END item).
+    ///
+    /// This is meant for lists which are built once and then used for the
+    /// whole lifetime of the provider.
+    pub fn into_static(self) -> &'static [CONST_OSSL_PARAM] {
+        Box::leak(Box::new(self)).as_slice()
+    }
+}
+
+/// Merges two descriptor arrays (e.g., the settable params of two mixins)
+/// into a single END-terminated [`OwnedConstParams`].
+///
+/// The entries of `a` come first, followed by those of `b`; entries whose key
+/// has already been seen are dropped, so only the first one is kept.
+/// Each slice is read up to its first END item (or to its end).
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let a = [
+///     OSSLParam::new_const_int::<i32>(c"bits", None),
+///     CONST_OSSL_PARAM::END,
+/// ];
+/// let b = [
+///     OSSLParam::new_const_int::<i32>(c"bits", None),
+///     OSSLParam::new_const_utf8string(c"group", None),
+///     CONST_OSSL_PARAM::END,
+/// ];
+///
+/// let merged = merge_descriptors(&a, &b);
+/// assert_eq!(merged.len(), 3); // "bits", "group" and END
+/// ```
+pub fn merge_descriptors(a: &[CONST_OSSL_PARAM], b: &[CONST_OSSL_PARAM]) -> OwnedConstParams {
+    let not_end = |p: &&CONST_OSSL_PARAM| !p.key.is_null();
+
+    let mut seen = std::collections::HashSet::new();
+    let merged: Vec<CONST_OSSL_PARAM> = a
+        .iter()
+        .take_while(not_end)
+        .chain(b.iter().take_while(not_end))
+        // SAFETY: `p.key` is not NULL, and `CONST_OSSL_PARAM` items are
+        // expected to always reference valid keys.
+        .filter(|p| seen.insert(unsafe { CStr::from_ptr(p.key) }))
+        .copied()
+        .collect();
+    OwnedConstParams::from_params(&merged)
+}
+
+/// Builds an END-terminated descriptor array (e.g., the settable ctx params
+/// of an operation) from `(key, type)` pairs, in order.
+///
+/// Each entry has the given key and data type, `NULL` data and a
+/// `data_size` of 0, as the entries built by the `new_const_*` constructors
+/// of [`OSSLParam`] with no value.
+/// This is meant as the generic building block of per-operation descriptors.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let settable = descriptor_from(&[
+///     (c"bits", ParamType::UnsignedInteger),
+///     (c"group", ParamType::Utf8String),
+/// ]);
+/// assert_eq!(settable.len(), 3); // "bits", "group" and END
+/// assert_eq!(settable[1].data_type, OSSL_PARAM_UTF8_STRING);
+/// assert!(settable[1].data.is_null());
+/// ```
+pub fn descriptor_from(entries: &[(&'static CStr, ParamType)]) -> OwnedConstParams {
+    let params: Vec<CONST_OSSL_PARAM> = entries
+        .iter()
+        .map(|&(key, param_type)| CONST_OSSL_PARAM {
+            key: key.as_ptr(),
+            data_type: param_type.data_type(),
+            data: std::ptr::null_mut(),
+            data_size: 0,
+            return_size: OSSL_PARAM_UNMODIFIED,
+        })
+        .collect();
+    OwnedConstParams::from_params(&params)
+}
+
+// SAFETY: the inner pointers of `params` only reference memory owned by
+// `_storage` (or NULL), which is never mutated after `from_params()` returns
+// and is only freed when the whole list is dropped.
+// The heap allocations do not move when the list is moved across threads.
+unsafe impl Send for OwnedConstParams {}
+unsafe impl Sync for OwnedConstParams {}
+
+impl std::ops::Deref for OwnedConstParams {
+    type Target = [CONST_OSSL_PARAM];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+/// A value to be stored in an [`OwnedParamList`].
+///
+/// The variant determines the data type of the resulting [`OSSL_PARAM`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnedParamValue {
+    /// A signed integer, stored as an [`OSSL_PARAM_INTEGER`] of 8 bytes.
+    Int(i64),
+    /// An unsigned integer, stored as an [`OSSL_PARAM_UNSIGNED_INTEGER`] of 8 bytes.
+    UInt(u64),
+    /// A printable string, stored as an [`OSSL_PARAM_UTF8_STRING`].
+    Utf8String(CString),
+    /// An arbitrary string of bytes, stored as an [`OSSL_PARAM_OCTET_STRING`].
+    OctetString(Vec<u8>),
+}
+
+impl OwnedParamValue {
+    /// Returns the `OSSL_PARAM_*` data type used to store this value.
+    pub fn data_type(&self) -> u32 {
+        match self {
+            Self::Int(_) => OSSL_PARAM_INTEGER,
+            Self::UInt(_) => OSSL_PARAM_UNSIGNED_INTEGER,
+            Self::Utf8String(_) => OSSL_PARAM_UTF8_STRING,
+            Self::OctetString(_) => OSSL_PARAM_OCTET_STRING,
+        }
+    }
+
+    /// Copies this value into `storage`, returning the `data` and `data_size`
+    /// fields of the corresponding [`OSSL_PARAM`].
+    fn store(&self, storage: &mut ParamStorage) -> (*mut c_void, usize) {
+        match self {
+            Self::Int(v) => (
+                storage.store_bytes(&v.to_ne_bytes(), false),
+                size_of::<i64>(),
+            ),
+            Self::UInt(v) => (
+                storage.store_bytes(&v.to_ne_bytes(), false),
+                size_of::<u64>(),
+            ),
+            Self::Utf8String(v) => (storage.store_bytes(v.as_bytes(), true), v.count_bytes()),
+            Self::OctetString(v) => (storage.store_bytes(v, false), v.len()),
+        }
+    }
+}
+
+/// The alphabet of the standard base64 encoding (RFC 4648, section 4).
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl OwnedParamValue {
+    /// Creates an [`OwnedParamValue::OctetString`] from its hexadecimal
+    /// representation (two digits per byte, in either case).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `hex` has an odd length, or any character which
+    /// is not a hexadecimal digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::OwnedParamValue;
+    ///
+    /// let value = OwnedParamValue::octet_from_hex("00ff10").unwrap();
+    /// assert_eq!(value, OwnedParamValue::OctetString(vec![0x00, 0xff, 0x10]));
+    /// assert_eq!(value.to_hex().as_deref(), Some("00ff10"));
+    ///
+    /// assert!(OwnedParamValue::octet_from_hex("0ff").is_err());
+    /// ```
+    pub fn octet_from_hex(hex: &str) -> Result<Self, OSSLParamError> {
+        let invalid = || OSSLParamError::Other(format!("Invalid hexadecimal octet string {hex:?}"));
+        if !hex.len().is_multiple_of(2) {
+            return Err(invalid());
+        }
+        let digit = |c: u8| {
+            (c as char)
+                .to_digit(16)
+                .map(|d| d as u8)
+                .ok_or_else(invalid)
+        };
+        let bytes = hex
+            .as_bytes()
+            .chunks_exact(2)
+            .map(|pair| Ok((digit(pair[0])? << 4) | digit(pair[1])?))
+            .collect::<Result<_, OSSLParamError>>()?;
+        Ok(Self::OctetString(bytes))
+    }
+
+    /// Creates an [`OwnedParamValue::OctetString`] from its standard base64
+    /// representation (RFC 4648), where the trailing `=` padding is
+    /// optional.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `base64` has any character outside of the
+    /// standard base64 alphabet (besides the trailing padding), or a length
+    /// which no byte string encodes to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::OwnedParamValue;
+    ///
+    /// let value = OwnedParamValue::octet_from_base64("AP8Q").unwrap();
+    /// assert_eq!(value, OwnedParamValue::OctetString(vec![0x00, 0xff, 0x10]));
+    /// assert_eq!(value.to_base64().as_deref(), Some("AP8Q"));
+    ///
+    /// assert!(OwnedParamValue::octet_from_base64("A").is_err());
+    /// ```
+    pub fn octet_from_base64(base64: &str) -> Result<Self, OSSLParamError> {
+        let invalid = || OSSLParamError::Other(format!("Invalid base64 octet string {base64:?}"));
+        let digits = base64.trim_end_matches('=').as_bytes();
+        let padding = base64.len() - digits.len();
+        if digits.len() % 4 == 1 || padding > 2 || (padding > 0 && !base64.len().is_multiple_of(4))
+        {
+            return Err(invalid());
+        }
+        let sextet = |c: u8| {
+            BASE64_ALPHABET
+                .iter()
+                .position(|&a| a == c)
+                .map(|i| i as u32)
+                .ok_or_else(invalid)
+        };
+        let mut bytes = Vec::with_capacity(digits.len() * 3 / 4);
+        for chunk in digits.chunks(4) {
+            let mut group = 0u32;
+            for (i, &c) in chunk.iter().enumerate() {
+                group |= sextet(c)? << (18 - 6 * i);
+            }
+            // n digits encode n - 1 bytes (for a partial chunk)
+            bytes.extend_from_slice(&group.to_be_bytes()[1..chunk.len()]);
+        }
+        Ok(Self::OctetString(bytes))
+    }
+
+    /// Returns the hexadecimal representation (in lower case) of an
+    /// [`OwnedParamValue::OctetString`], or `None` for the other variants.
+    pub fn to_hex(&self) -> Option<String> {
+        let Self::OctetString(bytes) = self else {
+            return None;
+        };
+        Some(bytes.iter().map(|b| format!("{b:02x}")).collect())
+    }
+
+    /// Returns the standard base64 representation (RFC 4648, with padding)
+    /// of an [`OwnedParamValue::OctetString`], or `None` for the other
+    /// variants.
+    pub fn to_base64(&self) -> Option<String> {
+        let Self::OctetString(bytes) = self else {
+            return None;
+        };
+        let mut base64 = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let mut group = [0u8; 4];
+            group[1..=chunk.len()].copy_from_slice(chunk);
+            let group = u32::from_be_bytes(group);
+            // n bytes are encoded by n + 1 digits, padded to 4
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    base64.push(BASE64_ALPHABET[(group >> (18 - 6 * i)) as usize & 0x3f] as char);
+                } else {
+                    base64.push('=');
+                }
+            }
+        }
+        Some(base64)
+    }
+}
+
+impl From<i64> for OwnedParamValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<u64> for OwnedParamValue {
+    fn from(value: u64) -> Self {
+        Self::UInt(value)
+    }
+}
+
+impl From<CString> for OwnedParamValue {
+    fn from(value: CString) -> Self {
+        Self::Utf8String(value)
+    }
+}
+
+impl From<&CStr> for OwnedParamValue {
+    fn from(value: &CStr) -> Self {
+        Self::Utf8String(value.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for OwnedParamValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::OctetString(value)
+    }
+}
+
+impl From<&[u8]> for OwnedParamValue {
+    fn from(value: &[u8]) -> Self {
+        Self::OctetString(value.to_vec())
+    }
+}
+
+impl OSSLParam<'_> {
+    /// Returns the key of this [`OSSLParam`] together with a copy of its value,
+    /// decoded according to the variant.
+    ///
+    /// This is the one-call form of [`OSSLParam::get_key`] followed by the
+    /// [`OSSLParam::get`] matching the variant, with both
+    /// [`OSSLParam::Utf8Ptr`] and [`OSSLParam::Utf8String`] decoded as
+    /// [`OwnedParamValue::Utf8String`].
+    ///
+    /// Returns `None` for a terminator (i.e., a param without a key), if the
+    /// value cannot be decoded (e.g., its `data` is `NULL`), and for
+    /// [`OSSLParam::Real`] params, which have no [`OwnedParamValue`]
+    /// counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let p = OSSLParam::new_const_int(c"bits", Some(&2048i32));
+    /// let param = OSSLParam::try_from(&p).unwrap();
+    /// assert_eq!(param.entry(), Some((c"bits", OwnedParamValue::Int(2048))));
+    /// ```
+    pub fn entry(&self) -> Option<(&KeyType, OwnedParamValue)> {
+        let key = self.get_key()?;
+        let value = match self {
+            OSSLParam::Int(_) => OwnedParamValue::Int(self.get::<i64>()?),
+            OSSLParam::UInt(_) => OwnedParamValue::UInt(self.get::<u64>()?),
+            OSSLParam::Utf8Ptr(_) | OSSLParam::Utf8String(_) => {
+                OwnedParamValue::from(self.get::<&CStr>()?)
+            }
+            OSSLParam::OctetString(_) | OSSLParam::OctetPtr(_) => {
+                OwnedParamValue::from(self.get::<&[u8]>()?)
+            }
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(_) => return None,
+        };
+        Some((key, value))
+    }
+}
+
+/// An owned, END-terminated list of [`OSSL_PARAM`]s, built at runtime.
+///
+/// Unlike [`OwnedConstParams`], the items of this list can be modified
+/// (e.g., when passed to a function that fills in requested values), but the
+/// list cannot be shared across threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+/// use std::ffi::CStr;
+///
+/// let mut list = OwnedParamList::from_entries([
+///     (c"bits".to_owned(), OwnedParamValue::Int(2048)),
+///     (c"group".to_owned(), OwnedParamValue::from(c"x25519")),
+/// ]);
+///
+/// let mut values = list.iter_mut();
+/// assert_eq!(values.next().unwrap().get::<i64>(), Some(2048));
+/// assert_eq!(values.next().unwrap().get::<&CStr>(), Some(c"x25519"));
+/// assert!(values.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct OwnedParamList {
+    params: Vec<OSSL_PARAM>,
+    _storage: ParamStorage,
+}
+
+impl OwnedParamList {
+    /// Builds a new END-terminated [`OwnedParamList`] from `(key, value)`
+    /// entries.
+    ///
+    /// The data type of each item is chosen according to the
+    /// [`OwnedParamValue`] variant, and the keys and values are copied into
+    /// buffers owned by the list.
+    pub fn from_entries<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (CString, OwnedParamValue)>,
+    {
+        let mut builder = OSSLParamBuilder::new();
+        for (key, value) in entries {
+            builder.push(&key, value);
+        }
+        builder.build()
+    }
+
+    /// Returns the number of items in the list, **excluding** the terminating
+    /// END item.
+    pub fn len(&self) -> usize {
+        self.params.len() - 1
+    }
+
+    /// Returns `true` if the list contains only the terminating END item.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the list as a slice, **including** the terminating END item.
+    pub fn as_slice(&self) -> &[OSSL_PARAM] {
+        &self.params
+    }
+
+    /// Returns a pointer to the first item of the list, to be passed to
+    /// OpenSSL functions through the FFI layer.
+    pub fn as_ptr(&self) -> *const OSSL_PARAM {
+        self.params.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the first item of the list, to be passed
+    /// to OpenSSL functions through the FFI layer.
+    pub fn as_mut_ptr(&mut self) -> *mut OSSL_PARAM {
+        self.params.as_mut_ptr()
+    }
+
+    /// Returns an [`OSSLParamIterator`] over the items of the list.
+    pub fn iter_mut(&mut self) -> OSSLParamIterator<'_> {
+        OSSLParamIterator::new(self.as_mut_ptr())
+    }
+}
+
+/// A builder of [`OwnedParamList`]s, adding one item at a time.
+///
+/// The items are kept in a single contiguous array, which is always
+/// terminated by an END item, so the list being built can be passed to
+/// OpenSSL at any time through [`OSSLParamBuilder::as_ptr`].
+/// Keys and values are copied into buffers owned by the builder (and then by
+/// the built list), which are freed when it is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+/// use std::ffi::CStr;
+///
+/// let mut builder = OSSLParamBuilder::new();
+/// builder
+///     .push_int(c"bits", 2048)
+///     .push_utf8_string(c"group", c"x25519")
+///     .push_octet_string(c"seed", &[1, 2, 3]);
+///
+/// let p = OSSLParam::try_from(builder.as_ptr()).unwrap();
+/// assert_eq!(p.get_key(), Some(c"bits"));
+///
+/// let list = builder.build();
+/// assert_eq!(list_keys(list.as_ptr()), [c"bits", c"group", c"seed"]);
+/// ```
+#[derive(Debug)]
+pub struct OSSLParamBuilder {
+    list: OwnedParamList,
+}
+
+impl Default for OSSLParamBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OSSLParamBuilder {
+    /// Creates a builder of an empty list (i.e., holding only the END item).
+    pub fn new() -> Self {
+        Self {
+            list: OwnedParamList {
+                params: vec![OSSL_PARAM::END],
+                _storage: ParamStorage::default(),
+            },
+        }
+    }
+
+    /// Appends an item with a copy of `key` and `value`, whose data type is
+    /// chosen according to the [`OwnedParamValue`] variant.
+    pub fn push(&mut self, key: &KeyType, value: impl Into<OwnedParamValue>) -> &mut Self {
+        let value = value.into();
+        let storage = &mut self.list._storage;
+        let key = storage.store_key(key);
+        let (data, data_size) = value.store(storage);
+        let end = self.list.params.len() - 1;
+        self.list.params.insert(
+            end,
+            OSSL_PARAM {
+                key,
+                data_type: value.data_type(),
+                data,
+                data_size,
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+        );
+        self
+    }
+
+    /// Appends an [`OSSL_PARAM_INTEGER`] item.
+    pub fn push_int(&mut self, key: &KeyType, value: i64) -> &mut Self {
+        self.push(key, value)
+    }
+
+    /// Appends an [`OSSL_PARAM_UNSIGNED_INTEGER`] item.
+    pub fn push_uint(&mut self, key: &KeyType, value: u64) -> &mut Self {
+        self.push(key, value)
+    }
+
+    /// Appends an [`OSSL_PARAM_UTF8_STRING`] item.
+    pub fn push_utf8_string(&mut self, key: &KeyType, value: &CStr) -> &mut Self {
+        self.push(key, value)
+    }
+
+    /// Appends an [`OSSL_PARAM_OCTET_STRING`] item.
+    pub fn push_octet_string(&mut self, key: &KeyType, value: &[u8]) -> &mut Self {
+        self.push(key, value)
+    }
+
+    /// Returns the number of items pushed so far.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if no item has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns a pointer to the first item of the (END-terminated) list built
+    /// so far, to be passed to OpenSSL functions through the FFI layer.
+    ///
+    /// The pointer is invalidated by the next push, as the array may be moved
+    /// to a larger allocation.
+    pub fn as_ptr(&self) -> *const OSSL_PARAM {
+        self.list.as_ptr()
+    }
+
+    /// Returns the built list, which takes over the ownership of the keys and
+    /// values.
+    pub fn build(self) -> OwnedParamList {
+        self.list
+    }
+}
+
+/// A single owned [`OSSL_PARAM`] (followed by an END item), built at runtime.
+///
+/// Like [`OwnedParamList`], it owns the memory backing its key and value, so
+/// the param remains valid for as long as the [`OwnedParam`] is alive.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// let mut owned = OSSLParam::new_owned_int128(c"counter", i128::MIN);
+/// assert_eq!(owned.as_param().get::<i128>(), Some(i128::MIN));
+/// ```
+#[derive(Debug)]
+pub struct OwnedParam {
+    params: Vec<OSSL_PARAM>,
+    _storage: ParamStorage,
+}
+
+impl OwnedParam {
+    /// Builds a param with the given `key` and `data_type`, whose data is a
+    /// copy of `bytes`.
+    fn from_bytes(key: &KeyType, data_type: u32, bytes: &[u8]) -> Self {
+        let mut storage = ParamStorage::default();
+        let param = OSSL_PARAM {
+            key: storage.store_key(key),
+            data_type,
+            data: storage.store_bytes(bytes, false),
+            data_size: bytes.len(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        };
+        Self {
+            params: vec![param, OSSL_PARAM::END],
+            _storage: storage,
+        }
+    }
+
+    /// Returns an [`OSSLParam`] wrapping the owned param.
+    pub fn as_param(&mut self) -> OSSLParam<'_> {
+        OSSLParam::try_from(self.as_mut_ptr()).expect("an OwnedParam should always be valid")
+    }
+
+    /// Returns a pointer to the owned param, to be passed to OpenSSL functions
+    /// through the FFI layer (as an END-terminated list).
+    pub fn as_ptr(&self) -> *const OSSL_PARAM {
+        self.params.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the owned param, to be passed to OpenSSL
+    /// functions through the FFI layer (as an END-terminated list).
+    pub fn as_mut_ptr(&mut self) -> *mut OSSL_PARAM {
+        self.params.as_mut_ptr()
+    }
+}
+
+impl OSSLParam<'_> {
+    /// Creates an [`OwnedParam`] holding `value` as an [`OSSL_PARAM_INTEGER`]
+    /// of 16 bytes, in native-endian order.
+    ///
+    /// The value can be read back with [`OSSLParam::get`] as an [`i128`].
+    pub fn new_owned_int128(key: &KeyType, value: i128) -> OwnedParam {
+        OwnedParam::from_bytes(key, OSSL_PARAM_INTEGER, &value.to_ne_bytes())
+    }
+
+    /// Creates an [`OwnedParam`] holding `value` as an [`OSSL_PARAM_INTEGER`]
+    /// of `size` bytes, in native-endian order (sign-extended if `size` is
+    /// larger than 8).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `size` is not between 1 and 16, or if `value` does
+    /// not fit in `size` bytes, rather than silently truncating it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut owned = OSSLParam::new_owned_int_sized(c"bits", 2048, 4).unwrap();
+    /// assert_eq!(owned.as_param().get::<i32>(), Some(2048));
+    ///
+    /// assert!(OSSLParam::new_owned_int_sized(c"bits", i64::MAX, 4).is_err());
+    /// ```
+    pub fn new_owned_int_sized(
+        key: &KeyType,
+        value: i64,
+        size: usize,
+    ) -> Result<OwnedParam, OSSLParamError> {
+        if !(1..=size_of::<i128>()).contains(&size) {
+            return Err(OSSLParamError::UnsupportedSize {
+                key: key.to_string_lossy().into_owned(),
+                data_size: size,
+                supported: (1..=size_of::<i128>()).collect(),
+            });
+        }
+        let bits = size * 8;
+        if bits < 64 && !(-(1i64 << (bits - 1))..1i64 << (bits - 1)).contains(&value) {
+            return Err(OSSLParamError::Other(format!(
+                "Value {value} does not fit in the {size} bytes of the integer param {key:?}"
+            )));
+        }
+        let mut bytes = i128::from(value).to_le_bytes()[..size].to_vec();
+        if cfg!(target_endian = "big") {
+            bytes.reverse();
+        }
+        Ok(OwnedParam::from_bytes(key, OSSL_PARAM_INTEGER, &bytes))
+    }
+}
+
+/// Cloning an [`OSSLParam`] deep copies its key and value into newly
+/// allocated, writable storage.
+///
+/// The clone is independent of the source: setting a value on the clone does
+/// not affect the source, and vice versa.
+/// This also holds when the source is read-only (e.g., a [`CONST_OSSL_PARAM`]),
+/// in which case the clone is still safely mutable.
+///
+/// The clone owns its storage, which is freed when it is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use openssl_provider_forge::osslparams::*;
+///
+/// const BITS: i64 = 2048;
+/// let p = OSSLParam::new_const_int(c"bits", Some(&BITS));
+/// let source = OSSLParam::try_from(&p).unwrap();
+///
+/// let mut clone = source.clone();
+/// assert!(clone.set(4096i64).is_ok());
+/// assert_eq!(clone.get::<i64>(), Some(4096));
+/// assert_eq!(source.get::<i64>(), Some(2048));
+/// ```
+impl Clone for OSSLParam<'_> {
+    fn clone(&self) -> Self {
+        let mut storage = ParamStorage::default();
+        let param = self.deep_copy(&mut storage);
+
+        OSSLParam::from_owned(param, storage)
+            .expect("the clone of a valid OSSLParam should be valid")
+    }
+}
+
+impl OSSLParam<'_> {
+    /// Deep copies the key and value of this [`OSSLParam`] into `storage`,
+    /// returning the [`OSSL_PARAM`] referencing the copies.
+    fn deep_copy(&self, storage: &mut ParamStorage) -> OSSL_PARAM {
+        // SAFETY: an `OSSLParam` always wraps a valid, non-NULL `OSSL_PARAM`.
+        let src = unsafe { &*self.get_c_struct() };
+
+        let key = match self.get_key() {
+            Some(key) => storage.store_key(key),
+            None => std::ptr::null(),
+        };
+        // SAFETY: the data of a valid `OSSLParam` is described by its
+        // `data_type` and `data_size`.
+        let data = unsafe { storage.store_data(src) };
+        OSSL_PARAM {
+            key,
+            data_type: src.data_type,
+            data,
+            data_size: src.data_size,
+            return_size: src.return_size,
+        }
+    }
+
+    /// Deep copies the key and value of this [`OSSLParam`] into a new
+    /// [`OwnedParam`], which does not borrow the underlying [`OSSL_PARAM`].
+    ///
+    /// This is meant for keeping a param past the lifetime of the array it
+    /// belongs to, e.g. in upcalls and callbacks, where the array passed by
+    /// the core is only valid for the duration of the call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let mut owned = {
+    ///     let mut bits = 2048i64;
+    ///     let mut raw = OSSL_PARAM {
+    ///         key: c"bits".as_ptr(),
+    ///         data_type: OSSL_PARAM_INTEGER,
+    ///         data: std::ptr::from_mut(&mut bits).cast(),
+    ///         data_size: size_of::<i64>(),
+    ///         return_size: OSSL_PARAM_UNMODIFIED,
+    ///     };
+    ///     OSSLParam::try_from(&mut raw).unwrap().clone_into_owned()
+    /// };
+    ///
+    /// let param = owned.as_param();
+    /// assert_eq!(param.get_key(), Some(c"bits"));
+    /// assert_eq!(param.get::<i64>(), Some(2048));
+    /// ```
+    pub fn clone_into_owned(&self) -> OwnedParam {
+        let mut storage = ParamStorage::default();
+        let param = self.deep_copy(&mut storage);
+        OwnedParam {
+            params: vec![param, OSSL_PARAM::END],
+            _storage: storage,
+        }
+    }
+}
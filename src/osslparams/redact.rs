@@ -0,0 +1,136 @@
+//! This submodule provides a redacted, displayable form of [`OSSLParam`]s,
+//! meant for logging params which might contain secrets.
+//!
+//! The values of params whose key is in the set of _sensitive keys_ are always
+//! redacted.
+//! The set is initialized with [`DEFAULT_SENSITIVE_KEYS`], and can be
+//! configured at runtime with [`add_sensitive_key`] and
+//! [`remove_sensitive_key`].
+
+use std::collections::BTreeSet;
+use std::ffi::{CStr, CString};
+use std::sync::{OnceLock, RwLock};
+
+use crate::bindings::{
+    OSSL_KDF_PARAM_KEY, OSSL_KDF_PARAM_PASSWORD, OSSL_KDF_PARAM_SECRET, OSSL_KDF_PARAM_SEED,
+    OSSL_PKEY_PARAM_PRIV_KEY,
+};
+use crate::osslparams::{KeyType, OSSLParam};
+
+/// The keys which are considered sensitive by default.
+pub const DEFAULT_SENSITIVE_KEYS: &[&KeyType] = &[
+    OSSL_PKEY_PARAM_PRIV_KEY,
+    OSSL_KDF_PARAM_SECRET,
+    OSSL_KDF_PARAM_KEY,
+    OSSL_KDF_PARAM_PASSWORD,
+    OSSL_KDF_PARAM_SEED,
+];
+
+fn sensitive_keys() -> &'static RwLock<BTreeSet<CString>> {
+    static SENSITIVE_KEYS: OnceLock<RwLock<BTreeSet<CString>>> = OnceLock::new();
+    SENSITIVE_KEYS.get_or_init(|| {
+        RwLock::new(
+            DEFAULT_SENSITIVE_KEYS
+                .iter()
+                .map(|k| (*k).to_owned())
+                .collect(),
+        )
+    })
+}
+
+/// Adds `key` to the set of sensitive keys.
+pub fn add_sensitive_key(key: &KeyType) {
+    let mut keys = sensitive_keys().write().unwrap_or_else(|e| e.into_inner());
+    keys.insert(key.to_owned());
+}
+
+/// Removes `key` from the set of sensitive keys.
+pub fn remove_sensitive_key(key: &KeyType) {
+    let mut keys = sensitive_keys().write().unwrap_or_else(|e| e.into_inner());
+    keys.remove(key);
+}
+
+/// Returns `true` if `key` is in the set of sensitive keys.
+pub fn is_sensitive_key(key: &KeyType) -> bool {
+    let keys = sensitive_keys().read().unwrap_or_else(|e| e.into_inner());
+    keys.contains(key)
+}
+
+impl OSSLParam<'_> {
+    /// Returns a displayable form of this [`OSSLParam`], which is safe to be
+    /// logged even if the param contains a secret.
+    ///
+    /// The key and the type of the param are always shown, while the value is:
+    ///
+    /// * replaced with a length-annotated placeholder (e.g.
+    ///   `priv=<octet; 32 bytes>`) for octet strings, UTF-8 pointers, and for
+    ///   any param whose key is a [sensitive key][`is_sensitive_key`];
+    /// * shown in full for integers and UTF-8 strings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openssl_provider_forge::osslparams::*;
+    ///
+    /// let secret = [0u8; 32].map(|b| b as std::ffi::c_char);
+    /// let p = OSSLParam::new_const_octetstring(c"priv", Some(&secret));
+    /// let param = OSSLParam::try_from(&p).unwrap();
+    /// assert_eq!(param.display_redacted(), "priv=<octet; 32 bytes>");
+    ///
+    /// let p = OSSLParam::new_const_int(c"bits", Some(&2048i32));
+    /// let param = OSSLParam::try_from(&p).unwrap();
+    /// assert_eq!(param.display_redacted(), "bits=2048 (int)");
+    /// ```
+    pub fn display_redacted(&self) -> String {
+        let Some(key) = self.get_key() else {
+            return "END".to_string();
+        };
+        let key_str = key.to_string_lossy();
+        // SAFETY: `get_c_struct()` returns the pointer wrapped by `self`,
+        // which is not NULL since `get_key()` succeeded.
+        let (data_null, data_size) = {
+            let p = unsafe { &*self.get_c_struct() };
+            (p.data.is_null(), p.data_size)
+        };
+        let type_name = match self {
+            OSSLParam::Utf8Ptr(_) => "utf8ptr",
+            OSSLParam::Utf8String(_) => "utf8",
+            OSSLParam::Int(_) => "int",
+            OSSLParam::UInt(_) => "uint",
+            OSSLParam::OctetString(_) => "octet",
+            OSSLParam::OctetPtr(_) => "octetptr",
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(_) => "real",
+        };
+
+        let redacted = || format!("{key_str}=<{type_name}; {data_size} bytes>");
+        if data_null {
+            return format!("{key_str}=<{type_name}; NULL>");
+        }
+        if is_sensitive_key(key) {
+            return redacted();
+        }
+        match self {
+            OSSLParam::Int(_) => match self.get::<i64>() {
+                Some(v) => format!("{key_str}={v} ({type_name})"),
+                None => redacted(),
+            },
+            OSSLParam::UInt(_) => match self.get::<u64>() {
+                Some(v) => format!("{key_str}={v} ({type_name})"),
+                None => redacted(),
+            },
+            #[cfg(ossl_param_type = "real")]
+            OSSLParam::Real(_) => match self.get::<f64>() {
+                Some(v) => format!("{key_str}={v} ({type_name})"),
+                None => redacted(),
+            },
+            OSSLParam::Utf8String(_) => match self.get::<&CStr>() {
+                Some(v) => format!("{key_str}={v:?} ({type_name})"),
+                None => redacted(),
+            },
+            OSSLParam::Utf8Ptr(_) | OSSLParam::OctetString(_) | OSSLParam::OctetPtr(_) => {
+                redacted()
+            }
+        }
+    }
+}
@@ -2,10 +2,22 @@ use super::*;
 use crate::tests::common;
 use common::OurError;
 
+mod clone; // Clone tests
+mod coerce; // get_coerced tests
+mod convert; // FromOsslParams tests
+mod eq; // PartialEq tests
+mod getter; // get tests
 mod iterator;
+mod legacy; // legacy shim tests
+mod miri; // tests meant to be run under Miri
 mod null; // new_null tests
+mod owned; // owned lists tests
+#[cfg(ossl_param_type = "real")]
+mod real; // OSSL_PARAM_REAL tests
+mod redact; // display_redacted tests
 mod setter; // set tests
 mod tryfrom; // try_from tests
+mod zero_size; // zero-size data tests
 
 fn setup() -> Result<(), OurError> {
     common::setup()
@@ -90,7 +102,7 @@ mod generic {
             CONST_OSSL_PARAM::END,
         ];
 
-        let params = OSSLParam::try_from(&params_list[0]).unwrap();
+        let params = OSSLParam::try_from(params_list.as_slice()).unwrap();
 
         let mut counter = 0;
         for p in params {
@@ -117,4 +129,50 @@ mod generic {
         assert_eq!(counter, 3);
         assert_eq!(counter, params_list.len() - 1);
     }
+
+    #[test]
+    fn test_expect_key() {
+        setup().expect("setup() failed");
+
+        let p = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+        let param = OSSLParam::try_from(&p).unwrap();
+
+        let ret = param.expect_key(c"bits");
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap().get::<i64>(), Some(2048));
+    }
+
+    #[test]
+    fn test_expect_key_mismatch() {
+        setup().expect("setup() failed");
+
+        let p = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+        let param = OSSLParam::try_from(&p).unwrap();
+
+        let err = param.expect_key(c"size").unwrap_err();
+        assert!(err.to_string().contains("size"), "{err}");
+        assert!(err.to_string().contains("bits"), "{err}");
+    }
+
+    #[test]
+    fn test_with_int_updates_value() {
+        setup().expect("setup() failed");
+
+        let mut value = -1i64;
+        {
+            let mut param = OSSLParam::with_int(c"value", &mut value);
+            assert_eq!(param.get_key(), Some(c"value"));
+            assert_eq!(param.get::<i64>(), Some(-1));
+            assert!(param.set(i64::MIN).is_ok());
+            assert!(param.modified());
+        }
+        assert_eq!(value, i64::MIN);
+
+        let mut value = 0u64;
+        {
+            let mut param = OSSLParam::with_uint(c"value", &mut value);
+            assert!(param.set(u64::MAX).is_ok());
+        }
+        assert_eq!(value, u64::MAX);
+    }
 }
@@ -0,0 +1,99 @@
+use super::*;
+
+// Tests for the Clone implementation of OSSLParam
+
+#[test]
+fn test_clone_is_independent() {
+    setup().expect("setup() failed");
+
+    let mut value: i64 = -1;
+    let mut raw = OSSL_PARAM {
+        key: c"value".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::from_mut(&mut value).cast(),
+        data_size: size_of::<i64>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut source = OSSLParam::try_from(&mut raw).unwrap();
+
+    let mut clone = source.clone();
+    assert_eq!(clone.get_key(), Some(c"value"));
+    assert_eq!(clone.get::<i64>(), Some(-1));
+    assert_ne!(clone.get_c_struct(), source.get_c_struct());
+
+    assert!(clone.set(7i64).is_ok());
+    assert_eq!(clone.get::<i64>(), Some(7));
+    assert_eq!(source.get::<i64>(), Some(-1));
+
+    assert!(source.set(42i64).is_ok());
+    assert_eq!(clone.get::<i64>(), Some(7));
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn test_clone_of_read_only_is_writable() {
+    setup().expect("setup() failed");
+
+    static NAME: &CStr = c"x25519";
+    let p = OSSLParam::new_const_utf8string(c"group", Some(NAME));
+    let source = OSSLParam::try_from(&p).unwrap();
+
+    let mut clone = source.clone();
+    assert_eq!(clone.get::<&CStr>(), Some(NAME));
+
+    // "x448" fits in the buffer copied from "x25519"
+    assert!(clone.set(c"x448").is_ok());
+    assert_eq!(clone.get::<&CStr>(), Some(c"x448"));
+    assert_eq!(source.get::<&CStr>(), Some(NAME));
+}
+
+#[test]
+fn test_clone_octet_string() {
+    setup().expect("setup() failed");
+
+    let octets: [std::ffi::c_char; 3] = [1, 2, 3];
+    let p = OSSLParam::new_const_octetstring(c"octets", Some(&octets));
+    let source = OSSLParam::try_from(&p).unwrap();
+
+    let clone = source.clone();
+    assert_eq!(clone.get::<&[u8]>(), Some([1u8, 2, 3].as_slice()));
+    assert_eq!(clone.get_data_type(), Some(OSSL_PARAM_OCTET_STRING));
+}
+
+#[test]
+fn test_clone_null() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_uint::<u64>(c"null", None);
+    let source = OSSLParam::try_from(&p).unwrap();
+
+    let clone = source.clone();
+    assert_eq!(clone.get_key(), Some(c"null"));
+    assert_eq!(clone.get::<u64>(), None);
+}
+
+#[test]
+fn test_clone_is_freed_on_drop() {
+    setup().expect("setup() failed");
+
+    let octets: [std::ffi::c_char; 4] = [1, 2, 3, 4];
+    let params = [
+        OSSLParam::new_const_int(c"int", Some(&-1i64)),
+        OSSLParam::new_const_utf8string(c"utf8", Some(c"a string")),
+        OSSLParam::new_const_octetstring(c"octets", Some(&octets)),
+    ];
+    let sources: Vec<OSSLParam> = params
+        .iter()
+        .map(|p| OSSLParam::try_from(p).unwrap())
+        .collect();
+
+    let ((), stats) = common::track_allocations(|| {
+        for _ in 0..100 {
+            for source in &sources {
+                let clone = source.clone();
+                assert_eq!(clone.get_key(), source.get_key());
+            }
+        }
+    });
+    assert_eq!(stats.live_bytes, 0, "{stats:?}");
+}
@@ -0,0 +1,114 @@
+use super::*;
+use std::ffi::c_char;
+
+fn param(p: &CONST_OSSL_PARAM) -> OSSLParam<'_> {
+    OSSLParam::try_from(std::ptr::from_ref(p) as *const OSSL_PARAM).unwrap()
+}
+
+#[test]
+fn test_uint_from_octet_string() {
+    setup().expect("setup() failed");
+
+    let bytes = 0x0102_0304u64.to_ne_bytes().map(|b| b as c_char);
+    let p = OSSLParam::new_const_octetstring(c"n", Some(&bytes));
+    let p = param(&p);
+
+    assert_eq!(p.get::<u64>(), None);
+    assert_eq!(p.get_coerced::<u64>(), Some(0x0102_0304));
+}
+
+#[test]
+fn test_uint_from_octet_string_sizes() {
+    setup().expect("setup() failed");
+
+    // shorter than a u64
+    let bytes = 0xABCDu16.to_ne_bytes().map(|b| b as c_char);
+    let p = OSSLParam::new_const_octetstring(c"n", Some(&bytes));
+    assert_eq!(param(&p).get_coerced::<u64>(), Some(0xABCD));
+
+    // longer than a u64, but zero-padded
+    let bytes = 42u128.to_ne_bytes().map(|b| b as c_char);
+    let p = OSSLParam::new_const_octetstring(c"n", Some(&bytes));
+    assert_eq!(param(&p).get_coerced::<u64>(), Some(42));
+
+    // too large for a u64
+    let bytes = (u128::from(u64::MAX) + 1)
+        .to_ne_bytes()
+        .map(|b| b as c_char);
+    let p = OSSLParam::new_const_octetstring(c"n", Some(&bytes));
+    assert_eq!(param(&p).get_coerced::<u64>(), None);
+
+    // empty
+    let p = OSSLParam::new_const_octetstring(c"n", Some(&[]));
+    assert_eq!(param(&p).get_coerced::<u64>(), None);
+}
+
+#[test]
+fn test_octet_string_from_uint() {
+    setup().expect("setup() failed");
+
+    let value = 0x0102_0304u64;
+    let p = OSSLParam::new_const_uint(c"n", Some(&value));
+    let p = param(&p);
+
+    assert_eq!(p.get::<&[u8]>(), None);
+    let bytes = p.get_coerced::<Vec<u8>>().unwrap();
+    assert_eq!(bytes, value.to_ne_bytes());
+
+    // and back
+    let bytes = bytes.iter().map(|&b| b as c_char).collect::<Vec<_>>();
+    let p = OSSLParam::new_const_octetstring(c"n", Some(&bytes));
+    assert_eq!(param(&p).get_coerced::<u64>(), Some(value));
+}
+
+#[test]
+fn test_int_from_decimal_string() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_utf8string(c"n", Some(c"-2048"));
+    let p = param(&p);
+    assert_eq!(p.get::<i64>(), None);
+    assert_eq!(p.get_coerced::<i64>(), Some(-2048));
+    // a negative value is not an unsigned integer
+    assert_eq!(p.get_coerced::<u64>(), None);
+
+    let p = OSSLParam::new_const_utf8string(c"n", Some(c"2048"));
+    assert_eq!(param(&p).get_coerced::<u64>(), Some(2048));
+
+    for invalid in [c"", c" 1", c"0x10", c"ten"] {
+        let p = OSSLParam::new_const_utf8string(c"n", Some(invalid));
+        assert_eq!(param(&p).get_coerced::<i64>(), None, "{invalid:?}");
+    }
+}
+
+#[test]
+fn test_decimal_string_from_int() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_int(c"n", Some(&-42i32));
+    let p = param(&p);
+    assert_eq!(p.get::<&CStr>(), None);
+    assert_eq!(p.get_coerced::<String>().as_deref(), Some("-42"));
+
+    let p = OSSLParam::new_const_uint(c"n", Some(&u64::MAX));
+    assert_eq!(
+        param(&p).get_coerced::<String>(),
+        Some(u64::MAX.to_string())
+    );
+}
+
+#[test]
+fn test_direct_types() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_int(c"n", Some(&7i64));
+    assert_eq!(param(&p).get_coerced::<i64>(), Some(7));
+
+    let p = OSSLParam::new_const_utf8string(c"n", Some(c"seven"));
+    assert_eq!(param(&p).get_coerced::<String>().as_deref(), Some("seven"));
+
+    // cross-signedness is not a supported coercion
+    let p = OSSLParam::new_const_int(c"n", Some(&7i64));
+    assert_eq!(param(&p).get_coerced::<u64>(), None);
+    assert_eq!(param(&p).get_coerced::<Vec<u8>>(), None);
+}
@@ -0,0 +1,197 @@
+use super::*;
+use crate::{impl_from_ossl_params, impl_to_ossl_params};
+use std::ffi::{c_char, CString};
+
+#[derive(Debug, Default, PartialEq)]
+struct Settings {
+    bits: i64,
+    group: String,
+    seed: Vec<u8>,
+}
+
+impl_from_ossl_params!(Settings {
+    bits: c"bits",
+    group: c"group",
+    seed: c"seed",
+});
+
+impl_to_ossl_params!(Settings {
+    bits: c"bits",
+    group: c"group",
+    seed: c"seed",
+});
+
+const SEED: [c_char; 4] = [1, 2, 3, 4];
+
+#[test]
+fn test_from_params() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_utf8string(c"group", Some(c"x25519")),
+        OSSLParam::new_const_int(c"unrelated", Some(&1i32)),
+        OSSLParam::new_const_octetstring(c"seed", Some(&SEED)),
+        OSSLParam::new_const_int(c"bits", Some(&2048i64)),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let settings = Settings::from_params(params_list.as_ptr() as *const OSSL_PARAM);
+    assert_eq!(
+        settings,
+        Ok(Settings {
+            bits: 2048,
+            group: "x25519".to_string(),
+            seed: vec![1, 2, 3, 4],
+        })
+    );
+}
+
+#[test]
+fn test_from_params_absent_fields() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_int(c"bits", Some(&128i32)),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let settings = Settings::from_params(params_list.as_ptr() as *const OSSL_PARAM);
+    assert_eq!(
+        settings,
+        Ok(Settings {
+            bits: 128,
+            ..Default::default()
+        })
+    );
+
+    let settings = Settings::from_params(std::ptr::null());
+    assert_eq!(settings, Ok(Settings::default()));
+}
+
+#[test]
+fn test_update_from_params() {
+    setup().expect("setup() failed");
+
+    let mut settings = Settings {
+        bits: 256,
+        group: "x448".to_string(),
+        seed: vec![9],
+    };
+    let params_list = [
+        OSSLParam::new_const_utf8string(c"group", Some(c"x25519")),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let ret = settings.update_from_params(params_list.as_ptr() as *const OSSL_PARAM);
+    assert_eq!(ret, Ok(()));
+    assert_eq!(
+        settings,
+        Settings {
+            bits: 256,
+            group: "x25519".to_string(),
+            seed: vec![9],
+        }
+    );
+}
+
+#[test]
+fn test_from_params_wrong_type() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_utf8string(c"bits", Some(c"2048")),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let ret = Settings::from_params(params_list.as_ptr() as *const OSSL_PARAM);
+    assert!(ret.is_err());
+    let err = ret.unwrap_err();
+    assert!(err.to_string().contains("bits"), "{err}");
+}
+
+#[test]
+fn test_to_params() {
+    setup().expect("setup() failed");
+
+    let settings = Settings {
+        bits: -1,
+        group: "x25519".to_string(),
+        seed: vec![1, 2, 3],
+    };
+    let list = settings.to_params().expect("to_params() failed");
+    assert_eq!(list.len(), 3);
+    assert_eq!(list_keys(list.as_ptr()), [c"bits", c"group", c"seed"]);
+
+    let data_types: Vec<_> = list.as_slice()[..3].iter().map(|p| p.data_type).collect();
+    assert_eq!(
+        data_types,
+        [
+            OSSL_PARAM_INTEGER,
+            OSSL_PARAM_UTF8_STRING,
+            OSSL_PARAM_OCTET_STRING
+        ]
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    setup().expect("setup() failed");
+
+    let settings = Settings {
+        bits: 3072,
+        group: "ffdhe3072".to_string(),
+        seed: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+    let list = settings.to_params().expect("to_params() failed");
+    assert_eq!(Settings::from_params(list.as_ptr()), Ok(settings));
+
+    // the defaults survive the round trip too
+    let list = Settings::default().to_params().expect("to_params() failed");
+    assert_eq!(
+        Settings::from_params(list.as_ptr()),
+        Ok(Settings::default())
+    );
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct NarrowSettings {
+    id: u32,
+    level: i32,
+    name: CString,
+}
+
+impl_from_ossl_params!(NarrowSettings {
+    id: c"id",
+    level: c"level",
+    name: c"name",
+});
+
+impl_to_ossl_params!(NarrowSettings {
+    id: c"id",
+    level: c"level",
+    name: c"name",
+});
+
+#[test]
+fn test_round_trip_narrow_types() {
+    setup().expect("setup() failed");
+
+    let settings = NarrowSettings {
+        id: u32::MAX,
+        level: i32::MIN,
+        name: c"narrow".to_owned(),
+    };
+    let list = settings.to_params().expect("to_params() failed");
+    assert_eq!(NarrowSettings::from_params(list.as_ptr()), Ok(settings));
+}
+
+#[test]
+fn test_to_params_interior_nul() {
+    setup().expect("setup() failed");
+
+    let settings = Settings {
+        group: "x25519\0".to_string(),
+        ..Default::default()
+    };
+    assert!(settings.to_params().is_err());
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+// Tests for the PartialEq implementation of OSSLParam
+
+#[test]
+fn test_eq_same_value_different_width() {
+    setup().expect("setup() failed");
+
+    let narrow = OSSLParam::new_const_int(c"bits", Some(&-2048i32));
+    let wide = OSSLParam::new_const_int(c"bits", Some(&-2048i64));
+    let narrow = OSSLParam::try_from(&narrow).unwrap();
+    let wide = OSSLParam::try_from(&wide).unwrap();
+    assert!(narrow == wide);
+    assert!(wide == narrow);
+
+    let narrow = OSSLParam::new_const_uint(c"size", Some(&32u32));
+    let wide = OSSLParam::new_const_uint(c"size", Some(&32u64));
+    assert!(OSSLParam::try_from(&narrow).unwrap() == OSSLParam::try_from(&wide).unwrap());
+
+    let other = OSSLParam::new_const_uint(c"size", Some(&64u64));
+    assert!(OSSLParam::try_from(&narrow).unwrap() != OSSLParam::try_from(&other).unwrap());
+}
+
+#[test]
+fn test_eq_different_key() {
+    setup().expect("setup() failed");
+
+    let bits = OSSLParam::new_const_int(c"bits", Some(&2048i32));
+    let size = OSSLParam::new_const_int(c"size", Some(&2048i32));
+    assert!(OSSLParam::try_from(&bits).unwrap() != OSSLParam::try_from(&size).unwrap());
+
+    let a = OSSLParam::new_const_utf8string(c"group", Some(c"x25519"));
+    let b = OSSLParam::new_const_utf8string(c"name", Some(c"x25519"));
+    assert!(OSSLParam::try_from(&a).unwrap() != OSSLParam::try_from(&b).unwrap());
+}
+
+#[test]
+fn test_eq_strings_and_octets() {
+    setup().expect("setup() failed");
+
+    static NAME: &CStr = c"x25519";
+    let a = OSSLParam::new_const_utf8string(c"group", Some(NAME));
+    let b = OSSLParam::new_const_utf8string(c"group", Some(c"x25519"));
+    let c = OSSLParam::new_const_utf8string(c"group", Some(c"x448"));
+    let a = OSSLParam::try_from(&a).unwrap();
+    assert!(a == OSSLParam::try_from(&b).unwrap());
+    assert!(a != OSSLParam::try_from(&c).unwrap());
+    // a clone has its own buffers, but the same value
+    assert!(a == a.clone());
+
+    let octets: [std::ffi::c_char; 3] = [1, 2, 3];
+    let other: [std::ffi::c_char; 3] = [1, 2, 4];
+    let a = OSSLParam::new_const_octetstring(c"octets", Some(&octets));
+    let b = OSSLParam::new_const_octetstring(c"octets", Some(&other));
+    let a = OSSLParam::try_from(&a).unwrap();
+    assert!(a == a.clone());
+    assert!(a != OSSLParam::try_from(&b).unwrap());
+}
+
+#[test]
+fn test_eq_cross_variant() {
+    setup().expect("setup() failed");
+
+    // the same number, but a different variant (and data type)
+    let int = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+    let uint = OSSLParam::new_const_uint(c"bits", Some(&2048u64));
+    let int = OSSLParam::try_from(&int).unwrap();
+    let uint = OSSLParam::try_from(&uint).unwrap();
+    assert!(int != uint);
+    assert!(uint != int);
+
+    // the same string, as a UTF8_STRING and as a UTF8_PTR
+    let s = c"x25519";
+    let mut ptr = s.as_ptr();
+    let mut raw = OSSL_PARAM {
+        key: c"group".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_PTR,
+        data: std::ptr::from_mut(&mut ptr).cast(),
+        data_size: s.count_bytes(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let utf8_ptr = OSSLParam::try_from(&mut raw).unwrap();
+    let utf8_string = OSSLParam::new_const_utf8string(c"group", Some(s));
+    let utf8_string = OSSLParam::try_from(&utf8_string).unwrap();
+    assert_eq!(utf8_ptr.get::<&CStr>(), utf8_string.get::<&CStr>());
+    assert!(utf8_ptr != utf8_string);
+}
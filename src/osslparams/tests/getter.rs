@@ -0,0 +1,451 @@
+use super::*;
+
+// Tests for the get methods
+
+fn int_param<T>(value: &mut T) -> OSSL_PARAM {
+    OSSL_PARAM {
+        key: c"int".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::from_mut(value).cast(),
+        data_size: size_of::<T>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    }
+}
+
+fn uint_param<T>(value: &mut T) -> OSSL_PARAM {
+    OSSL_PARAM {
+        key: c"uint".as_ptr(),
+        data_type: OSSL_PARAM_UNSIGNED_INTEGER,
+        data: std::ptr::from_mut(value).cast(),
+        data_size: size_of::<T>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    }
+}
+
+#[test]
+fn test_get_narrow_uint() {
+    setup().expect("setup() failed");
+
+    let mut data = 200u64;
+    let mut raw = uint_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<u32>(), Some(200));
+    assert_eq!(p.get::<u16>(), Some(200));
+    assert_eq!(p.get::<u8>(), Some(200));
+
+    let mut data = u32::MAX;
+    let mut raw = uint_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<u32>(), Some(u32::MAX));
+    assert_eq!(p.get::<u16>(), None);
+    assert_eq!(p.get::<u8>(), None);
+}
+
+#[test]
+fn test_get_narrow_uint_overflow() {
+    setup().expect("setup() failed");
+
+    let mut data = u32::MAX as u64 + 1;
+    let mut raw = uint_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<u64>(), Some(u32::MAX as u64 + 1));
+    assert_eq!(p.get::<u32>(), None);
+    assert_eq!(p.get::<u16>(), None);
+    assert_eq!(p.get::<u8>(), None);
+
+    let mut data = u16::MAX as u64 + 1;
+    let mut raw = uint_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<u32>(), Some(u16::MAX as u32 + 1));
+    assert_eq!(p.get::<u16>(), None);
+}
+
+#[test]
+fn test_get_narrow_int() {
+    setup().expect("setup() failed");
+
+    // exact fit, from both buffer sizes
+    let mut data = i8::MIN as i64;
+    let mut raw = int_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i8>(), Some(i8::MIN));
+    assert_eq!(p.get::<i16>(), Some(i8::MIN as i16));
+
+    let mut data = i16::MAX as i32;
+    let mut raw = int_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i16>(), Some(i16::MAX));
+    assert_eq!(p.get::<i8>(), None);
+
+    // negative values
+    for value in [-1i64, 0, 1] {
+        let mut data = value;
+        let mut raw = int_param(&mut data);
+        let p = OSSLParam::try_from(&mut raw).unwrap();
+        assert_eq!(p.get::<i8>(), Some(value as i8));
+        assert_eq!(p.get::<i16>(), Some(value as i16));
+    }
+}
+
+#[test]
+fn test_get_narrow_int_overflow() {
+    setup().expect("setup() failed");
+
+    let mut data = i8::MAX as i64 + 1;
+    let mut raw = int_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i8>(), None);
+    assert_eq!(p.get::<i16>(), Some(i8::MAX as i16 + 1));
+
+    let mut data = i16::MIN as i32 - 1;
+    let mut raw = int_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i8>(), None);
+    assert_eq!(p.get::<i16>(), None);
+    assert_eq!(p.get::<i32>(), Some(i16::MIN as i32 - 1));
+}
+
+#[test]
+fn test_get_i128_negative() {
+    setup().expect("setup() failed");
+
+    let value: i128 = -170141183460469231731687303715884105000;
+    let mut data = value;
+    let mut raw = int_param(&mut data);
+
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i128>(), Some(value));
+
+    let mut data = -1i128;
+    let mut raw = int_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i128>(), Some(-1));
+}
+
+#[test]
+fn test_get_i128_positive() {
+    setup().expect("setup() failed");
+
+    let value: i128 = i128::MAX - 42;
+    let mut data = value;
+    let mut raw = int_param(&mut data);
+
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i128>(), Some(value));
+    // doesn't fit in the narrower getters
+    assert_eq!(p.get::<i64>(), None);
+}
+
+#[test]
+fn test_get_i128_unaligned() {
+    setup().expect("setup() failed");
+
+    let value: i128 = i128::MIN + 7;
+    let mut buf = [0u8; 17];
+    buf[1..].copy_from_slice(&value.to_ne_bytes());
+    let mut raw = OSSL_PARAM {
+        key: c"int".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: buf[1..].as_mut_ptr().cast(),
+        data_size: size_of::<i128>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i128>(), Some(value));
+}
+
+#[test]
+fn test_get_i128_from_narrower() {
+    setup().expect("setup() failed");
+
+    let mut data = -5i32;
+    let mut raw = int_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i128>(), Some(-5));
+
+    let mut data = i64::MIN;
+    let mut raw = int_param(&mut data);
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<i128>(), Some(i64::MIN as i128));
+}
+
+#[test]
+fn test_utf8_capacity_vs_used() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 64];
+    let mut p = OSSL_PARAM {
+        key: c"group".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_STRING,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    assert_eq!(param.utf8_capacity(), Some(64));
+    assert_eq!(param.utf8_used(), None);
+
+    assert_eq!(param.set(c"x25519"), Ok(()));
+    assert_eq!(param.utf8_capacity(), Some(64));
+    assert_eq!(param.utf8_used(), Some(6));
+    assert_eq!(buf[..7], *b"x25519\0");
+}
+
+#[test]
+fn test_utf8_used_size_query() {
+    setup().expect("setup() failed");
+
+    // With NULL data, the responder only reports the needed size
+    let mut p = OSSL_PARAM {
+        key: c"group".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_STRING,
+        data: std::ptr::null_mut(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    assert_eq!(param.set(c"X25519MLKEM768"), Ok(()));
+    assert_eq!(param.utf8_capacity(), Some(0));
+    assert_eq!(param.utf8_used(), Some(14));
+}
+
+#[test]
+fn test_utf8_capacity_other_types() {
+    setup().expect("setup() failed");
+
+    let mut value = 1i32;
+    let mut p = int_param(&mut value);
+    let param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.utf8_capacity(), None);
+    assert_eq!(param.utf8_used(), None);
+}
+
+#[test]
+fn test_get_present_with_value() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.get_present::<i64>(), Ok(Some(2048)));
+
+    let p = OSSLParam::new_const_utf8string(c"group", Some(c"x25519"));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.get_present::<&CStr>(), Ok(Some(c"x25519")));
+}
+
+#[test]
+fn test_get_present_null_data() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_int::<i64>(c"bits", None);
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.get::<i64>(), None);
+    assert_eq!(param.get_present::<i64>(), Ok(None));
+
+    // a NULL data is not a type mismatch
+    assert_eq!(param.get_present::<u64>(), Ok(None));
+
+    let p = OSSLParam::new_const_octetstring(c"seed", None);
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.get_present::<&[u8]>(), Ok(None));
+}
+
+#[test]
+fn test_get_present_wrong_type() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_int(c"bits", Some(&2048i64));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.get::<u64>(), None);
+    assert_eq!(
+        param.get_present::<u64>(),
+        Err(OSSLParamError::TypeMismatch {
+            expected: "Int".to_string(),
+            got: "u64".to_string(),
+        })
+    );
+
+    let p = OSSLParam::new_const_utf8string(c"group", Some(c"x25519"));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert!(matches!(
+        param.get_present::<i32>(),
+        Err(OSSLParamError::TypeMismatch { expected, .. }) if expected == "Utf8String"
+    ));
+}
+
+#[test]
+fn test_get_const_octet_string() {
+    setup().expect("setup() failed");
+
+    const OCTETS: [std::ffi::c_char; 3] = [1, 2, -1];
+    const P: CONST_OSSL_PARAM = OSSLParam::new_const_octetstring(c"octets", Some(&OCTETS));
+
+    assert_eq!(P.data_type, OSSL_PARAM_OCTET_STRING);
+    assert_eq!(P.data_size, OCTETS.len());
+    assert_eq!(P.return_size, OSSL_PARAM_UNMODIFIED);
+
+    let param = OSSLParam::try_from(&P).unwrap();
+    assert_eq!(param.get::<&[u8]>(), Some([1u8, 2, 0xff].as_slice()));
+}
+
+#[test]
+fn test_get_const_octet_string_empty() {
+    setup().expect("setup() failed");
+
+    const P: CONST_OSSL_PARAM = OSSLParam::new_const_octetstring(c"empty", Some(&[]));
+
+    // an empty value is not a NULL param
+    assert!(!P.data.is_null());
+    assert_eq!(P.data_size, 0);
+    assert_eq!(P.return_size, OSSL_PARAM_UNMODIFIED);
+
+    let param = OSSLParam::try_from(&P).unwrap();
+    assert_eq!(param.get::<&[u8]>(), Some([].as_slice()));
+}
+
+#[test]
+fn test_get_octet_ptr() {
+    setup().expect("setup() failed");
+
+    let buf = [10u8, 20, 30, 40, 50, 60, 70, 80];
+    let mut target: *const u8 = buf.as_ptr();
+    let mut raw = OSSL_PARAM {
+        key: c"ptr".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_PTR,
+        data: std::ptr::from_mut(&mut target).cast(),
+        data_size: 5,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    // only `data_size` bytes are read, borrowing the pointed buffer
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    let bytes = p.get::<&[u8]>().unwrap();
+    assert_eq!(bytes, &buf[..5]);
+    assert_eq!(bytes.as_ptr(), buf.as_ptr());
+
+    // moving the pointer is reflected by the getter
+    let target_ptr = raw.data as *mut *const u8;
+    unsafe { *target_ptr = buf[3..].as_ptr() };
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<&[u8]>(), Some(&buf[3..]));
+
+    // a NULL target pointer is not a value
+    unsafe { *target_ptr = std::ptr::null() };
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<&[u8]>(), None);
+
+    // neither is a NULL `data`
+    raw.data = std::ptr::null_mut();
+    let p = OSSLParam::try_from(&mut raw).unwrap();
+    assert_eq!(p.get::<&[u8]>(), None);
+}
+
+#[test]
+#[cfg_attr(miri, ignore = "dirty frees are not counted under Miri")]
+fn test_get_secret_octets() {
+    setup().expect("setup() failed");
+
+    let secret: [std::ffi::c_char; 4] = [1, 2, 3, 4];
+    let p = OSSLParam::new_const_octetstring(c"priv", Some(&secret));
+    let param = OSSLParam::try_from(&p).unwrap();
+
+    // the copy is zeroized before being freed on drop
+    let ((), stats) = common::track_allocations(|| {
+        let copy = param.get_secret_octets().expect("should be an octet param");
+        assert_eq!(copy.as_slice(), [1, 2, 3, 4]);
+    });
+    assert_eq!(
+        stats,
+        common::AllocationStats {
+            live_bytes: 0,
+            dirty_frees: 0
+        }
+    );
+
+    // unlike a plain copy
+    let ((), stats) = common::track_allocations(|| {
+        let copy = param.get::<&[u8]>().unwrap().to_vec();
+        assert_eq!(copy, [1, 2, 3, 4]);
+    });
+    assert_eq!(stats.dirty_frees, 1, "{stats:?}");
+
+    // the param itself is untouched
+    assert_eq!(param.get::<&[u8]>(), Some(&[1u8, 2, 3, 4][..]));
+
+    let p = OSSLParam::new_const_int(c"bits", Some(&2048i32));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert!(param.get_secret_octets().is_none());
+}
+
+#[test]
+fn test_get_data_type_up_to_end() {
+    setup().expect("setup() failed");
+
+    let mut bits = 2048i64;
+    let mut size = 32u64;
+    let mut a = [int_param(&mut bits), uint_param(&mut size), OSSL_PARAM_END];
+    let expected = [Some(OSSL_PARAM_INTEGER), Some(OSSL_PARAM_UNSIGNED_INTEGER)];
+
+    for (i, raw) in a.iter_mut().enumerate() {
+        match OSSLParam::try_from(std::ptr::from_mut(raw)) {
+            Ok(p) => {
+                assert_eq!(p.get_data_type(), expected[i]);
+                assert_eq!(p.param_type().is_some(), expected[i].is_some());
+            }
+            // the END item itself has no data type, so it can't be wrapped
+            Err(_) => assert_eq!(i, expected.len()),
+        }
+    }
+}
+
+#[test]
+fn test_get_data_type_null_key() {
+    setup().expect("setup() failed");
+
+    // a typed param with a NULL key is still an END item
+    let mut bits = 2048i64;
+    let mut a = [int_param(&mut bits), OSSL_PARAM_END];
+    a[0].key = std::ptr::null();
+
+    let p = OSSLParam::try_from(&mut a[0]).unwrap();
+    assert_eq!(p.get_key(), None);
+    assert_eq!(p.get_data_type(), None);
+    assert_eq!(p.param_type(), None);
+}
+
+#[test]
+fn test_data_try_from_wrong_type() {
+    setup().expect("setup() failed");
+
+    let mut p = OSSL_PARAM {
+        key: c"bits".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::null_mut(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let expected = |expected| OSSLParamError::DataTypeMismatch {
+        key: "bits".to_string(),
+        expected,
+        got: OSSL_PARAM_INTEGER,
+    };
+    assert_eq!(
+        OctetStringData::try_from(&raw mut p).unwrap_err(),
+        expected(OSSL_PARAM_OCTET_STRING)
+    );
+    assert_eq!(
+        OctetPtrData::try_from(&raw mut p).unwrap_err(),
+        expected(OSSL_PARAM_OCTET_PTR)
+    );
+    assert_eq!(
+        Utf8StringData::try_from(&raw mut p).unwrap_err(),
+        expected(OSSL_PARAM_UTF8_STRING)
+    );
+    assert_eq!(
+        Utf8PtrData::try_from(&raw mut p).unwrap_err(),
+        expected(OSSL_PARAM_UTF8_PTR)
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use std::ffi::CString;
 
 // Tests for the Iterator use of OSSLParams
 
@@ -31,7 +32,7 @@ fn test_params_iterator() {
         },
         OSSL_PARAM_END,
     ];
-    let params_iter = OSSLParamIterator::new(&a[0]);
+    let params_iter = OSSLParamIterator::new(a.as_ptr());
 
     let mut i = 0;
     for p in params_iter {
@@ -73,8 +74,7 @@ fn test_params_intoiterator() {
         OSSL_PARAM_END,
     ];
 
-    let first = std::ptr::from_ref(a.first().unwrap());
-    let params = OSSLParam::try_from(first).unwrap();
+    let params = OSSLParam::try_from(a.as_ptr()).unwrap();
 
     let mut i = 0;
     for p in params {
@@ -84,3 +84,532 @@ fn test_params_intoiterator() {
 
     assert_eq!(i, a.len() - 1);
 }
+
+#[test]
+fn test_empty_const_params_iterator() {
+    setup().expect("setup() failed");
+
+    let params_iter = OSSLParamIterator::new(EMPTY_CONST_PARAMS.as_ptr() as *const OSSL_PARAM);
+    assert_eq!(params_iter.count(), 0);
+
+    assert_eq!(EMPTY_CONST_PARAMS.len(), 1);
+    assert!(EMPTY_CONST_PARAMS[0].key.is_null());
+}
+
+#[test]
+fn test_list_keys() {
+    setup().expect("setup() failed");
+
+    let value = 1u32;
+    let params_list = [
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        // a data type not supported by OSSLParam
+        CONST_OSSL_PARAM {
+            key: c"bar".as_ptr(),
+            data_type: 0xdead,
+            data: std::ptr::from_ref(&value) as *mut std::ffi::c_void,
+            data_size: size_of::<u32>(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSLParam::new_const_utf8string(c"baz", Some(c"a string")),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let keys = list_keys(params_list.as_ptr() as *const OSSL_PARAM);
+    assert_eq!(keys, [c"foo", c"bar", c"baz"]);
+}
+
+#[test]
+fn test_list_keys_empty() {
+    setup().expect("setup() failed");
+
+    assert!(list_keys(EMPTY_CONST_PARAMS.as_ptr() as *const OSSL_PARAM).is_empty());
+    assert!(list_keys(std::ptr::null()).is_empty());
+}
+
+#[test]
+fn test_shape_of() {
+    setup().expect("setup() failed");
+
+    let value = 1u32;
+    let params_list = [
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        OSSLParam::new_const_octetstring(c"seed", Some(&[1, 2, 3])),
+        // a data type not supported by OSSLParam
+        CONST_OSSL_PARAM {
+            key: c"bar".as_ptr(),
+            data_type: 0xdead,
+            data: std::ptr::from_ref(&value) as *mut std::ffi::c_void,
+            data_size: size_of::<u32>(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSLParam::new_const_uint(c"bits", Some(&2048u64)),
+        OSSLParam::new_const_utf8string(c"baz", Some(c"a string")),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let shape = shape_of(params_list.as_ptr() as *const OSSL_PARAM);
+    assert_eq!(
+        shape,
+        [
+            (CString::from(c"foo"), OSSL_PARAM_INTEGER),
+            (CString::from(c"seed"), OSSL_PARAM_OCTET_STRING),
+            (CString::from(c"bar"), 0xdead),
+            (CString::from(c"bits"), OSSL_PARAM_UNSIGNED_INTEGER),
+            (CString::from(c"baz"), OSSL_PARAM_UTF8_STRING),
+        ]
+    );
+
+    assert!(shape_of(EMPTY_CONST_PARAMS.as_ptr() as *const OSSL_PARAM).is_empty());
+    assert!(shape_of(std::ptr::null()).is_empty());
+}
+
+#[test]
+fn test_find_duplicate_keys() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+        OSSLParam::new_const_int(c"foo", Some(&2i32)),
+        OSSLParam::new_const_utf8string(c"bar", Some(c"another string")),
+        OSSLParam::new_const_int(c"foo", Some(&3i32)),
+        OSSLParam::new_const_uint(c"baz", Some(&4u32)),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let dups = find_duplicate_keys(params_list.as_ptr() as *const OSSL_PARAM);
+    assert_eq!(dups, [c"foo", c"bar"]);
+}
+
+#[test]
+fn test_find_duplicate_keys_clean() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+        OSSLParam::new_const_uint(c"baz", Some(&4u32)),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    assert!(find_duplicate_keys(params_list.as_ptr() as *const OSSL_PARAM).is_empty());
+    assert!(find_duplicate_keys(EMPTY_CONST_PARAMS.as_ptr() as *const OSSL_PARAM).is_empty());
+    assert!(find_duplicate_keys(std::ptr::null()).is_empty());
+}
+
+#[test]
+fn test_has_duplicate_keys() {
+    setup().expect("setup() failed");
+
+    const PARAMS: &[CONST_OSSL_PARAM] = &[
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+        OSSLParam::new_const_int(c"foo", Some(&2i32)),
+        CONST_OSSL_PARAM::END,
+        // items past the END one are not considered
+        OSSLParam::new_const_utf8string(c"bar", Some(c"another string")),
+    ];
+    const _: () = assert!(has_duplicate_keys(PARAMS, None));
+    const _: () = assert!(!has_duplicate_keys(PARAMS, Some(c"foo")));
+
+    // the result matches the runtime check
+    assert_eq!(
+        has_duplicate_keys(PARAMS, None),
+        !find_duplicate_keys(PARAMS.as_ptr() as *const OSSL_PARAM).is_empty()
+    );
+    assert!(!has_duplicate_keys(&PARAMS[1..], None));
+    assert!(!has_duplicate_keys(&EMPTY_CONST_PARAMS, None));
+    assert!(!has_duplicate_keys(&[], None));
+}
+
+#[test]
+fn test_for_each_param_count() {
+    setup().expect("setup() failed");
+
+    let value = 1u32;
+    let params_list = [
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        // a data type not supported by OSSLParam is skipped
+        CONST_OSSL_PARAM {
+            key: c"bar".as_ptr(),
+            data_type: 0xdead,
+            data: std::ptr::from_ref(&value) as *mut std::ffi::c_void,
+            data_size: size_of::<u32>(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSLParam::new_const_utf8string(c"baz", Some(c"a string")),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let mut keys = Vec::new();
+    for_each_param(params_list.as_ptr() as *const OSSL_PARAM, |p| {
+        keys.push(p.get_key().unwrap().to_owned());
+    });
+    assert_eq!(keys, [c"foo", c"baz"]);
+
+    let mut count = 0;
+    for_each_param(EMPTY_CONST_PARAMS.as_ptr() as *const OSSL_PARAM, |_| {
+        count += 1
+    });
+    for_each_param(std::ptr::null(), |_| count += 1);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_for_each_param_accumulate() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_int(c"a", Some(&1i32)),
+        OSSLParam::new_const_uint(c"b", Some(&20u64)),
+        OSSLParam::new_const_utf8string(c"c", Some(c"ignored")),
+        OSSLParam::new_const_int(c"d", Some(&300i64)),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let mut sum = 0i64;
+    for_each_param(params_list.as_ptr() as *const OSSL_PARAM, |p| {
+        if let Some(v) = p.get::<i64>() {
+            sum += v;
+        } else if let Some(v) = p.get::<u64>() {
+            sum += v as i64;
+        }
+    });
+    assert_eq!(sum, 321);
+}
+
+/// Returns an END-terminated list of `n` integer params.
+fn long_params_list(n: usize) -> Vec<CONST_OSSL_PARAM> {
+    static VALUE: i32 = 1;
+    let mut list: Vec<_> = (0..n)
+        .map(|_| OSSLParam::new_const_int(c"foo", Some(&VALUE)))
+        .collect();
+    list.push(CONST_OSSL_PARAM::END);
+    list
+}
+
+#[test]
+fn test_iterator_default_cap() {
+    setup().expect("setup() failed");
+
+    let list = long_params_list(max_params() + 1);
+    let params = OSSLParam::try_from(list.as_slice()).unwrap();
+    let mut iter = params.into_iter();
+    assert_eq!(iter.by_ref().count(), max_params());
+    assert!(iter.truncated());
+}
+
+#[test]
+fn test_iterator_higher_cap() {
+    setup().expect("setup() failed");
+
+    let n = max_params() + 10;
+    let list = long_params_list(n);
+    let params = OSSLParam::try_from(list.as_slice()).unwrap();
+    let mut iter = params.into_iter().with_max_params(n + 1);
+    assert_eq!(iter.by_ref().count(), n);
+    assert!(!iter.truncated());
+}
+
+#[test]
+fn test_iterator_lower_cap() {
+    setup().expect("setup() failed");
+
+    let list = long_params_list(10);
+    let params = OSSLParam::try_from(list.as_slice()).unwrap();
+    let mut iter = params.into_iter().with_max_params(4);
+    assert_eq!(iter.by_ref().count(), 4);
+    assert!(iter.truncated());
+    // once truncated, the iterator stays exhausted
+    assert!(iter.next().is_none());
+
+    // a cap matching the length exactly does not truncate
+    let params = OSSLParam::try_from(list.as_slice()).unwrap();
+    let mut iter = params.into_iter().with_max_params(10);
+    assert_eq!(iter.by_ref().count(), 10);
+    assert!(!iter.truncated());
+}
+
+#[test]
+fn test_raw_params_default_cap() {
+    setup().expect("setup() failed");
+
+    let list = long_params_list(max_params() + 1);
+    let head = list.as_ptr() as *const OSSL_PARAM;
+
+    // the helpers built on `raw_params()` are bounded by the same cap
+    assert_eq!(raw_params(head).count(), max_params());
+    assert_eq!(list_keys(head).len(), max_params());
+    assert_eq!(find_duplicate_keys(head), [c"foo"]);
+}
+
+#[test]
+fn test_raw_params_lower_cap() {
+    setup().expect("setup() failed");
+
+    let list = long_params_list(10);
+    let head = list.as_ptr() as *const OSSL_PARAM;
+
+    let mut iter = raw_params_with_max(head, 4);
+    assert_eq!(iter.by_ref().count(), 4);
+    // once truncated, the iterator stays exhausted
+    assert!(iter.next().is_none());
+
+    // a cap matching the length exactly does not truncate
+    assert_eq!(raw_params_with_max(head, 10).count(), 10);
+}
+
+#[test]
+fn test_iterator_max_len_unterminated() {
+    setup().expect("setup() failed");
+
+    // the item past the length we pass in stands for memory past the end of
+    // an unterminated buffer: it must never be read
+    let mut list = long_params_list(4);
+    list[3] = OSSLParam::new_const_int(c"past_the_end", Some(&0i32));
+    let head = list.as_ptr() as *const OSSL_PARAM;
+
+    let (keys, logs) = common::capture_logs(|| {
+        let mut iter = OSSLParamIterator::with_max_len(head, 3);
+        let keys: Vec<_> = iter
+            .by_ref()
+            .map(|p| p.get_key().unwrap().to_owned())
+            .collect();
+        assert!(iter.truncated());
+        assert!(iter.next().is_none());
+        keys
+    });
+    assert_eq!(
+        keys,
+        [c"foo".to_owned(), c"foo".to_owned(), c"foo".to_owned()]
+    );
+    assert!(logs
+        .iter()
+        .any(|(level, msg)| *level == log::Level::Error && msg.contains("no END item")));
+
+    let params = OSSLParam::try_from(head).unwrap();
+    let mut iter = params.into_iter_with_max_len(2);
+    assert_eq!(iter.by_ref().count(), 2);
+    assert!(iter.truncated());
+
+    let mut iter = OSSLParamIterator::with_max_len(head, 0);
+    assert!(iter.next().is_none());
+    assert!(iter.truncated());
+}
+
+#[test]
+fn test_iterator_max_len_terminated() {
+    setup().expect("setup() failed");
+
+    // the END item fits in the buffer, so the list is not truncated
+    let list = long_params_list(3);
+    let params = OSSLParam::try_from(list.as_slice()).unwrap();
+    let mut iter = params.into_iter_with_max_len(list.len());
+    assert_eq!(iter.by_ref().count(), 3);
+    assert!(!iter.truncated());
+
+    // the cap on the number of items still applies
+    let params = OSSLParam::try_from(list.as_slice()).unwrap();
+    let mut iter = params.into_iter_with_max_len(list.len()).with_max_params(1);
+    assert_eq!(iter.by_ref().count(), 1);
+    assert!(iter.truncated());
+}
+
+#[test]
+fn test_iterator_head_is_end() {
+    setup().expect("setup() failed");
+
+    let (count, logs) = common::capture_logs(|| {
+        let mut iter = OSSLParamIterator::new(EMPTY_PARAMS.as_ptr());
+        let count = iter.by_ref().count();
+        assert!(!iter.truncated());
+        count
+    });
+    assert_eq!(count, 0);
+    assert!(
+        logs.iter().all(|(level, _)| *level > log::Level::Warn),
+        "{logs:?}"
+    );
+
+    let head = EMPTY_CONST_PARAMS.as_ptr() as *const OSSL_PARAM;
+    assert_eq!(OSSLParamIterator::new(head).count(), 0);
+    assert_eq!(OSSLParamIterator::with_max_len(head, 1).count(), 0);
+    assert_eq!(OSSLParamIterator::new(std::ptr::null()).count(), 0);
+}
+
+#[test]
+fn test_parse_max_params() {
+    setup().expect("setup() failed");
+
+    assert_eq!(parse_max_params(None), DEFAULT_MAX_PARAMS);
+    assert_eq!(parse_max_params(Some("4096")), 4096);
+    assert_eq!(parse_max_params(Some(" 16 ")), 16);
+    assert_eq!(parse_max_params(Some("0")), DEFAULT_MAX_PARAMS);
+    assert_eq!(parse_max_params(Some("-1")), DEFAULT_MAX_PARAMS);
+    assert_eq!(parse_max_params(Some("lots")), DEFAULT_MAX_PARAMS);
+}
+
+static LOCATE_PARAMS: [CONST_OSSL_PARAM; 5] = [
+    OSSLParam::new_const_int(c"foo", Some(&1i32)),
+    OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+    OSSLParam::new_const_uint(c"baz", Some(&2u32)),
+    CONST_OSSL_PARAM::END,
+    OSSLParam::new_const_int(c"hidden", Some(&3i32)),
+];
+
+#[test]
+fn test_ossl_param_locate() {
+    setup().expect("setup() failed");
+
+    // the params are only read, so handing out a `*mut` is fine
+    let head = LOCATE_PARAMS.as_ptr() as *mut OSSL_PARAM;
+
+    let p = ossl_param_locate(head, c"bar").expect("\"bar\" should be found");
+    assert_eq!(p.get_key(), Some(c"bar"));
+    assert_eq!(p.get::<&CStr>(), Some(c"a string"));
+
+    let p = ossl_param_locate(head, c"baz").expect("\"baz\" should be found");
+    assert_eq!(p.get::<u32>(), Some(2));
+}
+
+#[test]
+fn test_ossl_param_locate_miss() {
+    setup().expect("setup() failed");
+
+    let head = LOCATE_PARAMS.as_ptr() as *mut OSSL_PARAM;
+
+    assert!(ossl_param_locate(head, c"missing").is_none());
+    // the items past the END one are never looked at
+    assert!(ossl_param_locate(head, c"hidden").is_none());
+    assert!(ossl_param_locate(EMPTY_CONST_PARAMS.as_ptr() as *mut OSSL_PARAM, c"foo").is_none());
+    assert!(ossl_param_locate(std::ptr::null_mut(), c"foo").is_none());
+}
+
+#[test]
+fn test_ossl_param_locate_matches_naive() {
+    setup().expect("setup() failed");
+
+    // keys sharing prefixes with each other, to exercise partial matches
+    let keys: Vec<CString> = (0..200)
+        .map(|i| CString::new(format!("key{i}")).unwrap())
+        .chain([c"".to_owned(), c"k".to_owned(), c"key".to_owned()])
+        .collect();
+    let list: Vec<OSSL_PARAM> = keys
+        .iter()
+        .map(|key| OSSL_PARAM {
+            key: key.as_ptr(),
+            data_type: OSSL_PARAM_INTEGER,
+            data: std::ptr::null_mut(),
+            data_size: 0,
+            return_size: OSSL_PARAM_UNMODIFIED,
+        })
+        .chain([OSSL_PARAM_END])
+        .collect();
+    let head = list.as_ptr();
+
+    let naive = |key: &CStr| {
+        list.iter()
+            .take_while(|p| !p.key.is_null())
+            .find(|p| unsafe { CStr::from_ptr(p.key) } == key)
+            .map(std::ptr::from_ref)
+    };
+
+    let probes =
+        keys.iter()
+            .map(CString::as_c_str)
+            .chain([c"key2000", c"ke", c"missing", c"key1\x7f"]);
+    for key in probes {
+        let found = ossl_param_locate_const(head, key).map(|p| p.get_c_struct());
+        assert_eq!(found, naive(key), "mismatch for {key:?}");
+    }
+}
+
+#[test]
+fn test_vec_from_params_skips_unsupported() {
+    setup().expect("setup() failed");
+
+    let mut int = 42i32;
+    let mut uint = 7u64;
+    let mut octets = [1u8, 2, 3];
+    let s = c"a string";
+    let mut a = [
+        OSSL_PARAM {
+            key: c"int".as_ptr(),
+            data_type: OSSL_PARAM_INTEGER,
+            data: std::ptr::from_mut(&mut int).cast(),
+            data_size: size_of::<i32>(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM {
+            key: c"unsupported".as_ptr(),
+            data_type: 99,
+            data: std::ptr::null_mut(),
+            data_size: 0,
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM {
+            key: c"uint".as_ptr(),
+            data_type: OSSL_PARAM_UNSIGNED_INTEGER,
+            data: std::ptr::from_mut(&mut uint).cast(),
+            data_size: size_of::<u64>(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM {
+            key: c"octets".as_ptr(),
+            data_type: OSSL_PARAM_OCTET_STRING,
+            data: octets.as_mut_ptr().cast(),
+            data_size: octets.len(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM {
+            key: c"string".as_ptr(),
+            data_type: OSSL_PARAM_UTF8_STRING,
+            data: s.as_ptr() as *mut std::ffi::c_void,
+            data_size: s.count_bytes() + 1,
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM_END,
+    ];
+
+    let params = collect_params(&mut a);
+    let keys: Vec<_> = params.iter().map(|p| p.get_key().unwrap()).collect();
+    assert_eq!(keys, [c"int", c"uint", c"octets", c"string"]);
+    assert_eq!(params[0].get::<i32>(), Some(42));
+    assert_eq!(params[1].get::<u64>(), Some(7));
+    assert_eq!(params[2].get::<&[u8]>(), Some(&[1u8, 2, 3][..]));
+    assert_eq!(params[3].get::<&CStr>(), Some(s));
+}
+
+#[test]
+fn test_ossl_params_macro() {
+    setup().expect("setup() failed");
+
+    const PARAMS: &[CONST_OSSL_PARAM] = &ossl_params![
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        OSSLParam::new_const_uint(c"bar", Some(&42u64)),
+        OSSLParam::new_const_utf8string(c"baz", Some(c"a string")),
+    ];
+
+    assert_eq!(PARAMS.len(), 4);
+    assert!(PARAMS.last().unwrap().key.is_null());
+
+    let params = OSSLParam::try_from(PARAMS).unwrap();
+    let mut iter = params.into_iter();
+    let collected: Vec<_> = iter.by_ref().collect();
+    assert!(!iter.truncated());
+    assert_eq!(collected.len(), 3);
+    assert_eq!(collected[0].get::<i32>(), Some(1));
+    assert_eq!(collected[1].get::<u64>(), Some(42));
+    assert_eq!(collected[2].get::<&CStr>(), Some(c"a string"));
+}
+
+#[test]
+fn test_ossl_params_macro_empty() {
+    setup().expect("setup() failed");
+
+    let params = ossl_params![];
+    assert_eq!(params.len(), 1);
+    assert!(params[0].key.is_null());
+    assert!(list_keys(params.as_ptr().cast()).is_empty());
+}
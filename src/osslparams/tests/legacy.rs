@@ -0,0 +1,79 @@
+use super::*;
+use crate::osslparams::legacy::OSSLParam as LegacyOSSLParam;
+
+#[test]
+fn test_legacy_get_c_struct() {
+    setup().expect("setup() failed");
+
+    let mut value = 42i32;
+    let mut p = OSSL_PARAM {
+        key: c"foo".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::from_mut(&mut value).cast(),
+        data_size: size_of::<i32>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let ptr: *mut OSSL_PARAM = &mut p;
+
+    let param = LegacyOSSLParam::try_from(ptr).unwrap();
+    let c_struct: *mut OSSL_PARAM = param.get_c_struct();
+    assert_eq!(c_struct, ptr);
+    // same as the current API
+    assert_eq!(c_struct.cast_const(), param.0.get_c_struct());
+}
+
+#[test]
+fn test_legacy_get_key() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        OSSLParam::new_const_utf8string(c"bar", Some(c"a string")),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let param = LegacyOSSLParam::try_from(params_list.as_ptr() as *const OSSL_PARAM).unwrap();
+    let key: &KeyType = param.get_key();
+    assert_eq!(key, c"foo");
+
+    // iterating yields legacy params as well
+    let keys: Vec<_> = param.into_iter().map(|p| p.get_key().to_owned()).collect();
+    assert_eq!(keys, [c"foo", c"bar"]);
+}
+
+#[test]
+#[should_panic(expected = "without a key")]
+fn test_legacy_get_key_end() {
+    setup().expect("setup() failed");
+
+    let mut end = OSSL_PARAM::END;
+    // an END item can only be wrapped by building the inner param manually
+    let param = LegacyOSSLParam(OSSLParam::Int(IntData {
+        param: (&mut end).into(),
+        ownership: Ownership::Borrowed,
+    }));
+    let _ = param.get_key();
+}
+
+#[test]
+fn test_legacy_deref() {
+    setup().expect("setup() failed");
+
+    let mut value = 0i64;
+    let mut p = OSSL_PARAM {
+        key: c"bits".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::from_mut(&mut value).cast(),
+        data_size: size_of::<i64>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let mut param = LegacyOSSLParam::try_from(&mut p as *mut OSSL_PARAM).unwrap();
+    // the rest of the API is reachable through Deref/DerefMut
+    assert_eq!(param.set(2048i64), Ok(()));
+    assert_eq!(param.get::<i64>(), Some(2048));
+    assert!(param.modified());
+
+    let param: OSSLParam = param.into();
+    assert_eq!(param.get_key(), Some(c"bits"));
+}
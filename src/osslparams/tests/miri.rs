@@ -0,0 +1,133 @@
+use super::*;
+
+// Tests meant to be run under Miri (see docs/CONTRIBUTING.md), exercising the
+// raw pointer accesses of get/set/iterate on buffers of exact sizes, so that
+// any out-of-bounds or use-after-free access is reported.
+
+#[test]
+fn test_miri_set_get_caller_buffers() {
+    setup().expect("setup() failed");
+
+    let mut bits = 0i32;
+    let mut security_bits = 0u64;
+    let mut name = [0u8; 6];
+    let mut seed = [0u8; 4];
+    let mut params_list = [
+        OSSL_PARAM {
+            key: c"bits".as_ptr(),
+            data_type: OSSL_PARAM_INTEGER,
+            data: std::ptr::from_mut(&mut bits).cast(),
+            data_size: size_of::<i32>(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM {
+            key: c"security-bits".as_ptr(),
+            data_type: OSSL_PARAM_UNSIGNED_INTEGER,
+            data: std::ptr::from_mut(&mut security_bits).cast(),
+            data_size: size_of::<u64>(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM {
+            key: c"name".as_ptr(),
+            data_type: OSSL_PARAM_UTF8_STRING,
+            data: name.as_mut_ptr().cast(),
+            data_size: name.len(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM {
+            key: c"seed".as_ptr(),
+            data_type: OSSL_PARAM_OCTET_STRING,
+            data: seed.as_mut_ptr().cast(),
+            data_size: seed.len(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM_END,
+    ];
+    let head = params_list.as_mut_ptr();
+
+    let mut p = ossl_param_locate(head, c"bits").unwrap();
+    p.set(2048i32).unwrap();
+    assert_eq!(p.get::<i32>(), Some(2048));
+
+    let mut p = ossl_param_locate(head, c"security-bits").unwrap();
+    p.set(128u64).unwrap();
+    assert_eq!(p.get::<u64>(), Some(128));
+
+    // the string fills the whole buffer, leaving no room for the NUL byte
+    let mut p = ossl_param_locate(head, c"name").unwrap();
+    p.set(c"x25519").unwrap();
+
+    let mut p = ossl_param_locate(head, c"seed").unwrap();
+    p.set(&[1u8, 2, 3, 4][..]).unwrap();
+    assert_eq!(p.get::<&[u8]>(), Some(&[1u8, 2, 3, 4][..]));
+
+    // values larger than the buffers are rejected without writing past them
+    let mut p = ossl_param_locate(head, c"name").unwrap();
+    assert!(p.set(c"x25519-longer").is_err());
+    let mut p = ossl_param_locate(head, c"seed").unwrap();
+    assert!(p.set(&[0u8; 5][..]).is_err());
+    drop(p);
+
+    assert_eq!(bits, 2048);
+    assert_eq!(security_bits, 128);
+    assert_eq!(&name, b"x25519");
+    assert_eq!(seed, [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_miri_iterate_owned_list() {
+    setup().expect("setup() failed");
+
+    let mut builder = OSSLParamBuilder::new();
+    builder
+        .push_int(c"bits", 2048)
+        .push_uint(c"security-bits", 128)
+        .push_utf8_string(c"name", c"x25519")
+        .push_octet_string(c"seed", &[1, 2, 3, 4]);
+    let list = builder.build();
+
+    let params: Vec<_> = OSSLParamIterator::new(list.as_ptr()).collect();
+    assert_eq!(params.len(), 4);
+    assert_eq!(params[0].get::<i64>(), Some(2048));
+    assert_eq!(params[1].get::<u64>(), Some(128));
+    assert_eq!(params[2].get::<&CStr>(), Some(c"x25519"));
+    assert_eq!(params[3].get::<&[u8]>(), Some(&[1u8, 2, 3, 4][..]));
+    drop(params);
+
+    // the params must not outlive the list
+    drop(list);
+}
+
+#[test]
+fn test_miri_iterate_unterminated() {
+    setup().expect("setup() failed");
+
+    // an unterminated list must never be read past its last item
+    let params_list = [
+        OSSLParam::new_const_int(c"foo", Some(&1i32)),
+        OSSLParam::new_const_int(c"bar", Some(&2i32)),
+    ];
+    let head = params_list.as_ptr() as *const OSSL_PARAM;
+
+    let sum: i32 = OSSLParamIterator::with_max_len(head, params_list.len())
+        .map(|p| p.get::<i32>().unwrap())
+        .sum();
+    assert_eq!(sum, 3);
+}
+
+#[test]
+fn test_miri_owned_params_drop() {
+    setup().expect("setup() failed");
+
+    // params owning their storage free all of it on drop
+    let key = c"key";
+    drop(OSSLParam::Int(IntData::new_null(key)));
+    drop(OSSLParam::Utf8String(Utf8StringData::new_null(key)));
+    drop(OSSLParam::OctetString(OctetStringData::new_null(key)));
+
+    let param = OSSLParam::new_const_utf8string(c"name", Some(c"x25519"));
+    let p = OSSLParam::try_from(&param).unwrap();
+    let mut copy = p.clone_into_owned();
+    drop(p);
+    assert_eq!(copy.as_param().get::<&CStr>(), Some(c"x25519"));
+}
@@ -37,3 +37,110 @@ fn test_uint_data_new_null() {
         "Failed to create new null unsigned integer parameter"
     );
 }
+
+#[test]
+fn test_new_null_is_freed_on_drop() {
+    setup().expect("setup() failed");
+
+    let ((), stats) = common::track_allocations(|| {
+        for _ in 0..1000 {
+            let mut param = OSSLParam::Int(IntData::new_null(c"int"));
+            assert!(param.set(42i64).is_ok());
+            assert_eq!(param.get::<i64>(), Some(42));
+        }
+        for _ in 0..100 {
+            drop(UIntData::new_null(c"uint"));
+            drop(Utf8PtrData::new_null(c"utf8_ptr"));
+            drop(Utf8StringData::new_null(c"utf8_string"));
+            drop(OctetStringData::new_null(c"octet_string"));
+            drop(OctetPtrData::new_null(c"octet_ptr"));
+        }
+    });
+    assert_eq!(stats.live_bytes, 0, "{stats:?}");
+}
+
+#[test]
+fn test_borrowed_param_is_not_freed_on_drop() {
+    setup().expect("setup() failed");
+
+    let value = Box::into_raw(Box::new(7i64));
+    let raw = Box::into_raw(Box::new(OSSL_PARAM {
+        key: c"int".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: value.cast(),
+        data_size: size_of::<i64>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    }));
+
+    let ((), stats) = common::track_allocations(|| {
+        for _ in 0..100 {
+            let param = OSSLParam::try_from(raw).unwrap();
+            assert_eq!(param.get::<i64>(), Some(7));
+        }
+    });
+    assert_eq!(stats.live_bytes, 0, "{stats:?}");
+
+    // SAFETY: both have been allocated above, and have not been freed by the
+    // wrappers.
+    unsafe {
+        assert_eq!((*raw).data_type, OSSL_PARAM_INTEGER);
+        drop(Box::from_raw(raw));
+        drop(Box::from_raw(value));
+    }
+}
+
+#[test]
+fn test_with_int_is_freed_on_drop() {
+    setup().expect("setup() failed");
+
+    let mut int = 0i64;
+    let mut uint = 0u64;
+    let ((), stats) = common::track_allocations(|| {
+        for i in 0..100u8 {
+            let mut p = OSSLParam::with_int(c"int", &mut int);
+            assert!(p.set(i64::from(i)).is_ok());
+            let mut p = OSSLParam::with_uint(c"uint", &mut uint);
+            assert!(p.set(u64::from(i)).is_ok());
+        }
+    });
+    assert_eq!(stats.live_bytes, 0, "{stats:?}");
+    // the values are only borrowed
+    assert_eq!((int, uint), (99, 99));
+}
+
+#[test]
+fn test_from_out_buffer_is_freed_on_drop() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 4];
+    let ((), stats) = common::track_allocations(|| {
+        for i in 0..100u8 {
+            let mut p = OSSLParam::OctetString(OctetStringData::from_out_buffer(c"out", &mut buf));
+            assert!(p.set(&[i][..]).is_ok());
+        }
+    });
+    assert_eq!(stats.live_bytes, 0, "{stats:?}");
+    // the buffer is only borrowed
+    assert_eq!(buf[0], 99);
+}
+
+#[test]
+fn test_modified_on_degenerate_params() {
+    setup().expect("setup() failed");
+
+    // the inner reference of the wrappers can never be NULL, as a NULL
+    // struct pointer is rejected
+    assert!(OSSLParam::try_from(std::ptr::null_mut::<OSSL_PARAM>()).is_err());
+    assert!(OSSLParam::try_from(std::ptr::null::<OSSL_PARAM>()).is_err());
+
+    // a param with NULL data is never modified
+    let mut p = OSSL_PARAM {
+        key: c"bits".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::null_mut(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert!(!param.modified());
+}
@@ -0,0 +1,531 @@
+use super::*;
+use std::ffi::CString;
+
+// Tests for the owned lists of params
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_owned_const_params_is_send_sync() {
+    setup().expect("setup() failed");
+
+    // This is checked at compile time: the test fails to build otherwise.
+    assert_send_sync::<OwnedConstParams>();
+    assert_send_sync::<&'static [CONST_OSSL_PARAM]>();
+}
+
+#[test]
+fn test_owned_const_params_deep_copy() {
+    setup().expect("setup() failed");
+
+    let owned = {
+        let int_value = -42i64;
+        let uint_value = 42u32;
+        let octets: [std::ffi::c_char; 4] = [1, 2, 3, 4];
+        let params = [
+            OSSLParam::new_const_int(c"int", Some(&int_value)),
+            OSSLParam::new_const_uint(c"uint", Some(&uint_value)),
+            OSSLParam::new_const_utf8string(c"utf8", Some(c"hello")),
+            OSSLParam::new_const_octetstring(c"octets", Some(&octets)),
+            OSSLParam::new_const_int::<i32>(c"null", None),
+            CONST_OSSL_PARAM::END,
+        ];
+        OwnedConstParams::from_params(&params)
+    };
+    assert_eq!(owned.len(), 6);
+
+    let params: Vec<OSSLParam> = OSSLParam::try_from(owned.as_ptr())
+        .expect("the owned list should be valid")
+        .into_iter()
+        .collect();
+    assert_eq!(params.len(), 5);
+    assert_eq!(params[0].get::<i64>(), Some(-42));
+    assert_eq!(params[1].get::<u64>(), Some(42));
+    assert_eq!(params[2].get::<&CStr>(), Some(c"hello"));
+    assert_eq!(params[3].get::<&[u8]>(), Some([1u8, 2, 3, 4].as_slice()));
+    assert_eq!(params[4].get_key(), Some(c"null"));
+    assert!(owned[4].data.is_null());
+}
+
+#[test]
+fn test_owned_const_params_always_terminated() {
+    setup().expect("setup() failed");
+
+    let unterminated = [OSSLParam::new_const_uint(c"uint", Some(&1u64))];
+    let owned = OwnedConstParams::from_params(&unterminated);
+    assert_eq!(owned.len(), 2);
+    assert!(owned[1].key.is_null());
+
+    let owned = OwnedConstParams::from_params(&[]);
+    assert_eq!(owned.as_slice().len(), 1);
+    assert!(owned[0].key.is_null());
+}
+
+#[test]
+#[cfg_attr(miri, ignore = "into_static() leaks by design")]
+fn test_owned_const_params_shared_across_threads() {
+    setup().expect("setup() failed");
+
+    let params = [
+        OSSLParam::new_const_utf8string(c"name", Some(c"x25519")),
+        CONST_OSSL_PARAM::END,
+    ];
+    let shared = std::sync::Arc::new(OwnedConstParams::from_params(&params));
+    let leaked: &'static [CONST_OSSL_PARAM] = OwnedConstParams::from_params(&params).into_static();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = std::sync::Arc::clone(&shared);
+            std::thread::spawn(move || {
+                for list in [shared.as_slice(), leaked] {
+                    let p = OSSLParam::try_from(list.as_ptr() as *mut OSSL_PARAM).unwrap();
+                    assert_eq!(p.get::<&CStr>(), Some(c"x25519"));
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().expect("thread panicked");
+    }
+}
+
+#[test]
+fn test_owned_param_list_from_entries() {
+    setup().expect("setup() failed");
+
+    let entries = vec![
+        (c"int".to_owned(), OwnedParamValue::Int(-2048)),
+        (c"uint".to_owned(), OwnedParamValue::UInt(u64::MAX)),
+        (c"utf8".to_owned(), OwnedParamValue::from(c"x25519")),
+        (c"octets".to_owned(), OwnedParamValue::from(vec![1u8, 2, 3])),
+    ];
+    let mut list = OwnedParamList::from_entries(entries.clone());
+    assert_eq!(list.len(), entries.len());
+    assert!(list.as_slice().last().unwrap().key.is_null());
+
+    let mut count = 0;
+    for (p, (key, value)) in list.iter_mut().zip(entries.iter()) {
+        assert_eq!(p.get_key(), Some(key.as_c_str()));
+        assert_eq!(p.get_data_type(), Some(value.data_type()));
+        match value {
+            OwnedParamValue::Int(v) => assert_eq!(p.get::<i64>(), Some(*v)),
+            OwnedParamValue::UInt(v) => assert_eq!(p.get::<u64>(), Some(*v)),
+            OwnedParamValue::Utf8String(v) => assert_eq!(p.get::<&CStr>(), Some(v.as_c_str())),
+            OwnedParamValue::OctetString(v) => assert_eq!(p.get::<&[u8]>(), Some(v.as_slice())),
+        }
+        count += 1;
+    }
+    assert_eq!(count, entries.len());
+}
+
+#[test]
+fn test_owned_param_list_set() {
+    setup().expect("setup() failed");
+
+    let mut list = OwnedParamList::from_entries([(c"bits".to_owned(), OwnedParamValue::Int(0))]);
+
+    let mut p = list.iter_mut().next().unwrap();
+    assert!(!p.modified());
+    assert!(p.set(4096i64).is_ok());
+    assert!(p.modified());
+    drop(p);
+
+    let p = OSSLParam::try_from(list.as_ptr()).unwrap();
+    assert_eq!(p.get::<i64>(), Some(4096));
+}
+
+#[test]
+fn test_owned_param_list_empty() {
+    setup().expect("setup() failed");
+
+    let mut list = OwnedParamList::from_entries(std::iter::empty());
+    assert!(list.is_empty());
+    assert_eq!(list.as_slice().len(), 1);
+    assert_eq!(list.iter_mut().count(), 0);
+}
+
+#[test]
+fn test_param_builder_round_trip() {
+    setup().expect("setup() failed");
+
+    let mut builder = OSSLParamBuilder::new();
+    assert!(builder.is_empty());
+    builder
+        .push_int(c"int", i64::MIN)
+        .push_uint(c"uint", u64::MAX)
+        .push_utf8_string(c"utf8", c"x25519")
+        .push_octet_string(c"octets", &[1, 2, 3]);
+    assert_eq!(builder.len(), 4);
+
+    // the list is terminated even before it is built
+    let keys = list_keys(builder.as_ptr());
+    assert_eq!(keys, [c"int", c"uint", c"utf8", c"octets"]);
+
+    // keys and values are copies, owned by the list
+    let mut list = {
+        let key = c"late".to_owned();
+        let value = vec![4u8, 5];
+        builder.push(&key, value.as_slice());
+        builder.build()
+    };
+    assert_eq!(list.len(), 5);
+    assert!(list.as_slice().last().unwrap().key.is_null());
+
+    let params: Vec<OSSLParam> = list.iter_mut().collect();
+    assert_eq!(params[0].get::<i64>(), Some(i64::MIN));
+    assert_eq!(params[1].get::<u64>(), Some(u64::MAX));
+    assert_eq!(params[2].get::<&CStr>(), Some(c"x25519"));
+    assert_eq!(params[3].get::<&[u8]>(), Some(&[1u8, 2, 3][..]));
+    assert_eq!(params[4].get_key(), Some(c"late"));
+    assert_eq!(params[4].get::<&[u8]>(), Some(&[4u8, 5][..]));
+}
+
+#[test]
+fn test_param_builder_drop() {
+    setup().expect("setup() failed");
+
+    // Dropping a builder, or the list it built, frees all the storage: run
+    // under Miri to check that nothing leaks.
+    let mut builder = OSSLParamBuilder::default();
+    builder.push_utf8_string(c"dropped", c"unbuilt");
+    drop(builder);
+
+    let mut builder = OSSLParamBuilder::new();
+    builder.push_octet_string(c"octets", &[0u8; 64]);
+    let list = builder.build();
+    assert_eq!(list.len(), 1);
+    drop(list);
+
+    assert!(OSSLParamBuilder::new().build().is_empty());
+}
+
+#[test]
+fn test_merge_descriptors() {
+    setup().expect("setup() failed");
+
+    let a = [
+        OSSLParam::new_const_int::<i32>(c"bits", None),
+        OSSLParam::new_const_utf8string(c"group", None),
+        CONST_OSSL_PARAM::END,
+    ];
+    let b = [
+        OSSLParam::new_const_uint::<u64>(c"group", None),
+        OSSLParam::new_const_octetstring(c"pub", None),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let merged = merge_descriptors(&a, &b);
+    let keys: Vec<_> = merged
+        .iter()
+        .take_while(|p| !p.key.is_null())
+        .map(|p| unsafe { CStr::from_ptr(p.key) })
+        .collect();
+    assert_eq!(keys, [c"bits", c"group", c"pub"]);
+    assert!(merged.last().unwrap().key.is_null());
+
+    // the first occurrence of "group" is kept
+    assert_eq!(merged[1].data_type, OSSL_PARAM_UTF8_STRING);
+}
+
+#[test]
+fn test_merge_empty_descriptors() {
+    setup().expect("setup() failed");
+
+    let a = [
+        OSSLParam::new_const_int::<i32>(c"bits", None),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let merged = merge_descriptors(&EMPTY_CONST_PARAMS, &a);
+    assert_eq!(merged.len(), 2);
+    let merged = merge_descriptors(&a, &EMPTY_CONST_PARAMS);
+    assert_eq!(merged.len(), 2);
+    let merged = merge_descriptors(&EMPTY_CONST_PARAMS, &EMPTY_CONST_PARAMS);
+    assert_eq!(merged.len(), 1);
+}
+
+#[test]
+fn test_descriptor_from() {
+    setup().expect("setup() failed");
+
+    let descriptor = descriptor_from(&[
+        (c"bits", ParamType::UnsignedInteger),
+        (c"group", ParamType::Utf8String),
+        (c"pub", ParamType::OctetString),
+    ]);
+    assert_eq!(descriptor.len(), 4);
+    assert!(descriptor.last().unwrap().key.is_null());
+
+    let expected = [
+        (c"bits", OSSL_PARAM_UNSIGNED_INTEGER),
+        (c"group", OSSL_PARAM_UTF8_STRING),
+        (c"pub", OSSL_PARAM_OCTET_STRING),
+    ];
+    for (p, (key, data_type)) in descriptor.iter().zip(expected) {
+        assert_eq!(unsafe { CStr::from_ptr(p.key) }, key);
+        assert_eq!(p.data_type, data_type);
+        assert!(p.data.is_null());
+        assert_eq!(p.data_size, 0);
+        assert_eq!(p.return_size, OSSL_PARAM_UNMODIFIED);
+    }
+
+    assert_eq!(descriptor_from(&[]).len(), 1);
+}
+
+#[test]
+fn test_entry() {
+    setup().expect("setup() failed");
+
+    let octets: [std::ffi::c_char; 3] = [1, 2, 3];
+    let utf8_ptr: *const std::ffi::c_char = c"pointed".as_ptr();
+    let params = [
+        OSSLParam::new_const_int(c"int", Some(&-7i32)),
+        OSSLParam::new_const_uint(c"uint", Some(&7u64)),
+        OSSLParam::new_const_utf8string(c"utf8", Some(c"hello")),
+        CONST_OSSL_PARAM {
+            key: c"utf8ptr".as_ptr(),
+            data_type: OSSL_PARAM_UTF8_PTR,
+            data: std::ptr::from_ref(&utf8_ptr) as *mut std::ffi::c_void,
+            data_size: 0,
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSLParam::new_const_octetstring(c"octets", Some(&octets)),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let entries: Vec<_> = OSSLParam::try_from(params.as_ptr() as *const OSSL_PARAM)
+        .unwrap()
+        .into_iter()
+        .map(|p| {
+            let (key, value) = p.entry().expect("entry() failed");
+            (key.to_owned(), value)
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            (c"int".to_owned(), OwnedParamValue::Int(-7)),
+            (c"uint".to_owned(), OwnedParamValue::UInt(7)),
+            (c"utf8".to_owned(), OwnedParamValue::from(c"hello")),
+            (c"utf8ptr".to_owned(), OwnedParamValue::from(c"pointed")),
+            (
+                c"octets".to_owned(),
+                OwnedParamValue::from(&[1u8, 2, 3][..])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_entry_end_and_null() {
+    setup().expect("setup() failed");
+
+    // A terminator has no key, regardless of the data type
+    let mut end = OSSL_PARAM {
+        data_type: OSSL_PARAM_INTEGER,
+        ..OSSL_PARAM::END
+    };
+    let param = OSSLParam::try_from(&mut end).unwrap();
+    assert_eq!(param.entry(), None);
+
+    // A NULL value cannot be decoded
+    let p = OSSLParam::new_const_int::<i32>(c"null", None);
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.entry(), None);
+}
+
+#[test]
+fn test_owned_int128_round_trip() {
+    setup().expect("setup() failed");
+
+    for value in [0i128, 42, -42, i128::MAX, i128::MIN, i64::MIN as i128 - 1] {
+        let mut owned = OSSLParam::new_owned_int128(c"counter", value);
+        let raw = unsafe { &*owned.as_ptr() };
+        assert_eq!(raw.data_type, OSSL_PARAM_INTEGER);
+        assert_eq!(raw.data_size, size_of::<i128>());
+        // the param is END-terminated
+        assert!(unsafe { &*owned.as_ptr().add(1) }.key.is_null());
+
+        let param = owned.as_param();
+        assert_eq!(param.get_key(), Some(c"counter"));
+        assert_eq!(param.get::<i128>(), Some(value));
+        // narrower getters do not apply to a 16-byte INTEGER
+        assert_eq!(param.get::<i64>(), None);
+    }
+}
+
+#[test]
+fn test_owned_int_sized() {
+    setup().expect("setup() failed");
+
+    // fits in 4 bytes
+    let mut owned = OSSLParam::new_owned_int_sized(c"small", -2048, 4).unwrap();
+    assert_eq!(unsafe { &*owned.as_ptr() }.data_size, 4);
+    assert_eq!(owned.as_param().get::<i32>(), Some(-2048));
+    assert_eq!(owned.as_param().get::<i64>(), Some(-2048));
+
+    // requires 8 bytes
+    let value = i64::from(i32::MAX) + 1;
+    let mut owned = OSSLParam::new_owned_int_sized(c"large", value, 8).unwrap();
+    assert_eq!(unsafe { &*owned.as_ptr() }.data_size, 8);
+    assert_eq!(owned.as_param().get::<i64>(), Some(value));
+    assert_eq!(owned.as_param().get::<i32>(), None);
+
+    // sign-extended to 16 bytes
+    let mut owned = OSSLParam::new_owned_int_sized(c"wide", i64::MIN, 16).unwrap();
+    assert_eq!(owned.as_param().get::<i128>(), Some(i128::from(i64::MIN)));
+}
+
+#[test]
+fn test_owned_int_sized_overflow() {
+    setup().expect("setup() failed");
+
+    let value = i64::from(i32::MAX) + 1;
+    assert!(OSSLParam::new_owned_int_sized(c"large", value, 4).is_err());
+    assert!(OSSLParam::new_owned_int_sized(c"large", i64::from(i32::MIN) - 1, 4).is_err());
+    assert!(OSSLParam::new_owned_int_sized(c"edge", i64::from(i32::MIN), 4).is_ok());
+    assert!(OSSLParam::new_owned_int_sized(c"byte", 128, 1).is_err());
+    assert!(OSSLParam::new_owned_int_sized(c"byte", -128, 1).is_ok());
+
+    // unsupported sizes
+    assert!(matches!(
+        OSSLParam::new_owned_int_sized(c"zero", 0, 0),
+        Err(OSSLParamError::UnsupportedSize { key, data_size: 0, .. }) if key == "zero"
+    ));
+    assert!(OSSLParam::new_owned_int_sized(c"huge", 0, 17).is_err());
+}
+
+#[test]
+fn test_clone_into_owned_outlives_source() {
+    setup().expect("setup() failed");
+
+    let mut owned = {
+        let key = CString::new("bits").unwrap();
+        let mut value = Box::new(-2048i32);
+        let mut raw = vec![
+            OSSL_PARAM {
+                key: key.as_ptr(),
+                data_type: OSSL_PARAM_INTEGER,
+                data: std::ptr::from_mut(&mut *value).cast(),
+                data_size: size_of::<i32>(),
+                return_size: OSSL_PARAM_UNMODIFIED,
+            },
+            OSSL_PARAM_END,
+        ];
+        let param = OSSLParam::try_from(raw.as_mut_ptr()).unwrap();
+        let owned = param.clone_into_owned();
+        // the backing array, key and value are all dropped here
+        drop(raw);
+        drop(value);
+        drop(key);
+        owned
+    };
+
+    let param = owned.as_param();
+    assert_eq!(param.get_key(), Some(c"bits"));
+    assert_eq!(param.get::<i32>(), Some(-2048));
+    // the owned copy is END-terminated, and writable
+    assert_eq!(param.into_iter().count(), 1);
+    assert!(owned.as_param().set(4096i32).is_ok());
+    assert_eq!(owned.as_param().get::<i32>(), Some(4096));
+}
+
+#[test]
+fn test_clone_into_owned_pointers() {
+    setup().expect("setup() failed");
+
+    // the pointed-to data of the `*_PTR` types is copied as well
+    let mut owned = {
+        let s = CString::new("x25519").unwrap();
+        let mut ptr = s.as_ptr();
+        let mut raw = OSSL_PARAM {
+            key: c"group".as_ptr(),
+            data_type: OSSL_PARAM_UTF8_PTR,
+            data: std::ptr::from_mut(&mut ptr).cast(),
+            data_size: s.count_bytes(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        };
+        let owned = OSSLParam::try_from(&mut raw).unwrap().clone_into_owned();
+        drop(s);
+        owned
+    };
+    let param = owned.as_param();
+    assert_eq!(param.get_data_type(), Some(OSSL_PARAM_UTF8_PTR));
+    assert_eq!(param.get::<&CStr>(), Some(c"x25519"));
+
+    let mut owned = {
+        let octets: Vec<std::ffi::c_char> = vec![1, 2, 3];
+        let p = OSSLParam::new_const_octetstring(c"octets", Some(&octets));
+        let owned = OSSLParam::try_from(&p).unwrap().clone_into_owned();
+        drop(octets);
+        owned
+    };
+    assert_eq!(
+        owned.as_param().get::<&[u8]>(),
+        Some([1u8, 2, 3].as_slice())
+    );
+}
+
+#[test]
+fn test_octet_hex_round_trip() {
+    setup().expect("setup() failed");
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let value = OwnedParamValue::from(bytes.as_slice());
+    let hex = value.to_hex().unwrap();
+    assert_eq!(hex.len(), 2 * bytes.len());
+    assert!(hex.starts_with("000102"));
+    assert_eq!(OwnedParamValue::octet_from_hex(&hex), Ok(value.clone()));
+    assert_eq!(
+        OwnedParamValue::octet_from_hex(&hex.to_uppercase()),
+        Ok(value)
+    );
+
+    assert_eq!(
+        OwnedParamValue::octet_from_hex(""),
+        Ok(OwnedParamValue::OctetString(vec![]))
+    );
+    assert!(OwnedParamValue::octet_from_hex("abc").is_err());
+    assert!(OwnedParamValue::octet_from_hex("0g").is_err());
+    assert!(OwnedParamValue::octet_from_hex("+1").is_err());
+    assert_eq!(OwnedParamValue::Int(1).to_hex(), None);
+}
+
+#[test]
+fn test_octet_base64_round_trip() {
+    setup().expect("setup() failed");
+
+    // the test vectors of RFC 4648, section 10
+    let vectors = [
+        ("", ""),
+        ("f", "Zg=="),
+        ("fo", "Zm8="),
+        ("foo", "Zm9v"),
+        ("foob", "Zm9vYg=="),
+        ("fooba", "Zm9vYmE="),
+        ("foobar", "Zm9vYmFy"),
+    ];
+    for (bytes, base64) in vectors {
+        let value = OwnedParamValue::from(bytes.as_bytes());
+        assert_eq!(value.to_base64().as_deref(), Some(base64));
+        assert_eq!(
+            OwnedParamValue::octet_from_base64(base64),
+            Ok(value.clone())
+        );
+        // the padding is optional
+        let unpadded = base64.trim_end_matches('=');
+        assert_eq!(OwnedParamValue::octet_from_base64(unpadded), Ok(value));
+    }
+
+    let bytes: Vec<u8> = (0..=255).rev().collect();
+    let value = OwnedParamValue::from(bytes);
+    let base64 = value.to_base64().unwrap();
+    assert!(base64.contains('+') && base64.contains('/'));
+    assert_eq!(OwnedParamValue::octet_from_base64(&base64), Ok(value));
+
+    assert!(OwnedParamValue::octet_from_base64("Zg=").is_err());
+    assert!(OwnedParamValue::octet_from_base64("Zm9vY").is_err());
+    assert!(OwnedParamValue::octet_from_base64("Zm9v===").is_err());
+    assert!(OwnedParamValue::octet_from_base64("Zm-v").is_err());
+    assert_eq!(OwnedParamValue::UInt(1).to_base64(), None);
+}
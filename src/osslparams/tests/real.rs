@@ -0,0 +1,111 @@
+use super::*;
+
+// Tests for OSSL_PARAM_REAL params
+
+/// Builds a param of type `OSSL_PARAM_REAL` pointing at `value`.
+fn real_param(value: &mut f64) -> OSSL_PARAM {
+    OSSL_PARAM {
+        key: c"real".as_ptr(),
+        data_type: crate::bindings::OSSL_PARAM_REAL,
+        data: std::ptr::from_mut(value).cast(),
+        data_size: size_of::<f64>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    }
+}
+
+#[test]
+fn test_real_round_trip() {
+    setup().expect("setup() failed");
+
+    let subnormal = f64::MIN_POSITIVE / 2.0;
+    assert!(subnormal.is_subnormal());
+
+    for value in [
+        0.0,
+        -0.0,
+        1.5,
+        -1e300,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        subnormal,
+        -subnormal,
+    ] {
+        let mut buf = 0f64;
+        let mut p = real_param(&mut buf);
+        let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+        param.set(value).unwrap();
+        let read = param.get::<f64>().unwrap();
+        assert_eq!(read.to_bits(), value.to_bits(), "{value:e}");
+        assert_eq!(p.return_size, size_of::<f64>());
+        assert_eq!(buf.to_bits(), value.to_bits());
+    }
+
+    let mut buf = 0f64;
+    let mut p = real_param(&mut buf);
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.set(f64::NAN).unwrap();
+    assert!(param.get::<f64>().unwrap().is_nan());
+    assert!(param.get::<f32>().unwrap().is_nan());
+    assert!(buf.is_nan());
+}
+
+#[test]
+fn test_real_f32() {
+    setup().expect("setup() failed");
+
+    let mut buf = 0f64;
+    let mut p = real_param(&mut buf);
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    param.set(0.25f32).unwrap();
+    assert_eq!(param.get::<f32>(), Some(0.25));
+    assert_eq!(param.get::<f64>(), Some(0.25));
+
+    param.set(f32::NEG_INFINITY).unwrap();
+    assert_eq!(param.get::<f32>(), Some(f32::NEG_INFINITY));
+
+    // Subnormal f32 values are exactly representable as f64
+    let subnormal = f32::MIN_POSITIVE / 2.0;
+    param.set(subnormal).unwrap();
+    assert_eq!(param.get::<f32>(), Some(subnormal));
+
+    // Finite values out of the f32 range are rejected
+    param.set(f64::MAX).unwrap();
+    assert_eq!(param.get::<f32>(), None);
+    param.set(-(f32::MAX as f64) * 2.0).unwrap();
+    assert_eq!(param.get::<f32>(), None);
+    param.set(f32::MAX as f64).unwrap();
+    assert_eq!(param.get::<f32>(), Some(f32::MAX));
+}
+
+#[test]
+fn test_real_unsupported_size() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; size_of::<f32>()];
+    let mut p = OSSL_PARAM {
+        key: c"real".as_ptr(),
+        data_type: crate::bindings::OSSL_PARAM_REAL,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    assert_eq!(param.get::<f64>(), None);
+    let err = param.set(1.0f32).unwrap_err();
+    assert!(is_unsupported_size(&err), "{err}");
+    assert_eq!(buf, [0u8; size_of::<f32>()]);
+}
+
+#[test]
+fn test_real_data_new_null() {
+    setup().expect("setup() failed");
+
+    let mut param = OSSLParam::Real(RealData::new_null(c"real"));
+    assert_eq!(param.get::<f64>(), Some(0.0));
+    param.set(-2.5f64).unwrap();
+    assert_eq!(param.get::<f64>(), Some(-2.5));
+    assert!(param.set(1i64).is_err());
+}
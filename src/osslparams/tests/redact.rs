@@ -0,0 +1,75 @@
+use super::*;
+
+// Tests for display_redacted() and the sensitive keys
+
+#[test]
+fn test_octet_string_is_redacted() {
+    setup().expect("setup() failed");
+
+    let secret: [std::ffi::c_char; 4] = [1, 2, 3, 4];
+    let p = OSSLParam::new_const_octetstring(c"pub", Some(&secret));
+    let param = OSSLParam::try_from(&p).unwrap();
+
+    assert_eq!(param.display_redacted(), "pub=<octet; 4 bytes>");
+}
+
+#[test]
+fn test_ints_are_shown() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_int(c"bits", Some(&-1i64));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.display_redacted(), "bits=-1 (int)");
+
+    let p = OSSLParam::new_const_uint(c"security-bits", Some(&128u32));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.display_redacted(), "security-bits=128 (uint)");
+}
+
+#[test]
+fn test_sensitive_keys_are_redacted() {
+    setup().expect("setup() failed");
+
+    for key in crate::osslparams::redact::DEFAULT_SENSITIVE_KEYS {
+        assert!(is_sensitive_key(key));
+    }
+
+    let p = OSSLParam::new_const_utf8string(c"pass", Some(c"hunter2"));
+    let param = OSSLParam::try_from(&p).unwrap();
+    let s = param.display_redacted();
+    assert_eq!(s, "pass=<utf8; 7 bytes>");
+    assert!(!s.contains("hunter2"));
+}
+
+#[test]
+fn test_configure_sensitive_keys() {
+    setup().expect("setup() failed");
+
+    // use a key no other test relies on, as the set is global
+    let key = c"test-configure-sensitive-keys";
+    let p = OSSLParam::new_const_uint(key, Some(&42u64));
+    let param = OSSLParam::try_from(&p).unwrap();
+
+    assert!(!is_sensitive_key(key));
+    assert!(param.display_redacted().contains("42"));
+
+    add_sensitive_key(key);
+    assert!(is_sensitive_key(key));
+    assert_eq!(
+        param.display_redacted(),
+        "test-configure-sensitive-keys=<uint; 8 bytes>"
+    );
+
+    remove_sensitive_key(key);
+    assert!(!is_sensitive_key(key));
+    assert!(param.display_redacted().contains("42"));
+}
+
+#[test]
+fn test_utf8_string_is_shown() {
+    setup().expect("setup() failed");
+
+    let p = OSSLParam::new_const_utf8string(c"group", Some(c"x25519"));
+    let param = OSSLParam::try_from(&p).unwrap();
+    assert_eq!(param.display_redacted(), "group=\"x25519\" (utf8)");
+}
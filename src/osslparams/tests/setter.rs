@@ -7,14 +7,16 @@ use std::ptr;
 fn test_int_data() {
     setup().expect("setup() failed");
 
+    let mut ossl_param = OSSL_PARAM {
+        data: ptr::null_mut(),
+        return_size: 0,
+        data_type: OSSL_PARAM_INTEGER,
+        key: ptr::null(),
+        data_size: 0,
+    };
     let mut int_data = IntData {
-        param: &mut OSSL_PARAM {
-            data: ptr::null_mut(),
-            return_size: 0,
-            data_type: OSSL_PARAM_INTEGER,
-            key: ptr::null(),
-            data_size: 0,
-        },
+        param: (&mut ossl_param).into(),
+        ownership: Ownership::Borrowed,
     };
 
     let value: i64 = -2;
@@ -27,14 +29,16 @@ fn test_int_data() {
 fn test_uint_data_() {
     setup().expect("setup() failed");
 
+    let mut ossl_param = OSSL_PARAM {
+        data: ptr::null_mut(),
+        return_size: 0,
+        data_type: OSSL_PARAM_UNSIGNED_INTEGER,
+        key: ptr::null(),
+        data_size: 0,
+    };
     let mut uint_data = UIntData {
-        param: &mut OSSL_PARAM {
-            data: ptr::null_mut(),
-            return_size: 0,
-            data_type: OSSL_PARAM_UNSIGNED_INTEGER,
-            key: ptr::null(),
-            data_size: 0,
-        },
+        param: (&mut ossl_param).into(),
+        ownership: Ownership::Borrowed,
     };
 
     let value: u64 = 50;
@@ -64,7 +68,8 @@ fn test_utf8_ptr_data_set() {
 
     // Create an instance of Utf8PtrData pointing to the dummy OSSL_PARAM
     let mut utf8_data = Utf8PtrData {
-        param: &mut ossl_param,
+        param: (&mut ossl_param).into(),
+        ownership: Ownership::Borrowed,
     };
 
     // Create a valid CStr (must end with a null terminator)
@@ -72,6 +77,7 @@ fn test_utf8_ptr_data_set() {
 
     // Set the value using the set method
     let result = utf8_data.set(value);
+    drop(utf8_data);
 
     assert_eq!(result, Ok(()));
 
@@ -94,3 +100,416 @@ fn test_utf8_ptr_data_set() {
         "Incorrect return_size"
     );
 }
+
+#[test]
+fn test_octet_string_from_out_buffer() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0xffu8; 16];
+    let value = [0xde, 0xad, 0xbe, 0xef];
+    let return_size = {
+        let mut param = OSSLParam::OctetString(OctetStringData::from_out_buffer(c"out", &mut buf));
+        assert_eq!(param.set(&value[..]), Ok(()));
+        unsafe { (*param.get_c_struct()).return_size }
+    };
+
+    assert_eq!(return_size, value.len());
+    assert_eq!(buf[..return_size], value);
+    // the rest of the buffer is untouched
+    assert!(buf[return_size..].iter().all(|&b| b == 0xff));
+}
+
+#[test]
+fn test_octet_string_from_out_buffer_too_small() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 2];
+    {
+        let mut param = OSSLParam::OctetString(OctetStringData::from_out_buffer(c"out", &mut buf));
+        assert!(param.set(&[1u8, 2, 3][..]).is_err());
+    }
+    assert_eq!(buf, [0, 0]);
+}
+
+#[test]
+fn test_octet_string_set_return_size() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 64];
+    let mut p = OSSL_PARAM {
+        key: c"out".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_STRING,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.set(&[1u8, 2, 3, 4, 5][..]), Ok(()));
+    assert_eq!(p.return_size, 5);
+
+    // a shorter value updates the used length
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.set(&[9u8, 9][..]), Ok(()));
+    assert_eq!(p.return_size, 2);
+    assert_eq!(p.data_size, 64);
+    assert_eq!(buf[..2], [9, 9]);
+}
+
+#[test]
+fn test_octet_string_buffer_too_small_return_size() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 4];
+    let mut p = OSSL_PARAM {
+        key: c"out".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_STRING,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    let err = param.set(&[1u8, 2, 3, 4, 5, 6][..]).unwrap_err();
+    assert_eq!(
+        err,
+        OSSLParamError::BufferTooSmall {
+            key: "out".to_string(),
+            needed: 6,
+            available: 4,
+        }
+    );
+    // as in OpenSSL, the caller learns the size needed for the value
+    assert_eq!(p.return_size, 6);
+    assert_eq!(buf, [0; 4]);
+
+    // a value filling exactly the buffer fits
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.set(&[1u8, 2, 3, 4][..]), Ok(()));
+    assert_eq!(param.get::<&[u8]>(), Some([1u8, 2, 3, 4].as_slice()));
+    assert_eq!(p.return_size, 4);
+}
+
+#[test]
+fn test_octet_string_size_query() {
+    setup().expect("setup() failed");
+
+    let mut p = OSSL_PARAM {
+        key: c"out".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_STRING,
+        data: std::ptr::null_mut(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.set(&[0u8; 1312][..]), Ok(()));
+    assert_eq!(p.return_size, 1312);
+    assert!(p.data.is_null());
+}
+
+#[test]
+fn test_octet_ptr_set_and_get() {
+    setup().expect("setup() failed");
+
+    static KEY_MATERIAL: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+    let mut target: *const u8 = ptr::null();
+    let mut p = OSSL_PARAM {
+        key: c"out".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_PTR,
+        data: ptr::from_mut(&mut target).cast(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert!(matches!(param, OSSLParam::OctetPtr(_)));
+    assert_eq!(param.variant_type(), ParamType::OctetPtr);
+    // the pointer has not been set yet
+    assert_eq!(param.get::<&[u8]>(), None);
+
+    assert_eq!(param.set(&KEY_MATERIAL[..]), Ok(()));
+    // the bytes are not copied
+    assert_eq!(param.get::<&[u8]>(), Some(KEY_MATERIAL.as_slice()));
+    assert_eq!(p.return_size, KEY_MATERIAL.len());
+    assert_eq!(target, KEY_MATERIAL.as_ptr());
+}
+
+#[test]
+fn test_octet_ptr_from_requester() {
+    setup().expect("setup() failed");
+
+    let bytes = [1u8, 2, 3];
+    let target: *const u8 = bytes.as_ptr();
+    let mut p = OSSL_PARAM {
+        key: c"in".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_PTR,
+        data: ptr::from_ref(&target) as *mut std::ffi::c_void,
+        data_size: bytes.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.get::<&[u8]>(), Some(bytes.as_slice()));
+    // an octet pointer is not a string of another type
+    assert_eq!(param.get::<&std::ffi::CStr>(), None);
+    assert_eq!(param.get::<u64>(), None);
+}
+
+#[test]
+fn test_octet_ptr_size_query() {
+    setup().expect("setup() failed");
+
+    let mut p = OSSL_PARAM {
+        key: c"out".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_PTR,
+        data: ptr::null_mut(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.set(&[0u8; 32][..]), Ok(()));
+    assert_eq!(param.get::<&[u8]>(), None);
+    assert_eq!(p.return_size, 32);
+
+    // the setter of an octet string does not apply to other types
+    let mut value = 0i64;
+    let mut p = OSSL_PARAM {
+        key: c"int".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: ptr::from_mut(&mut value).cast(),
+        data_size: size_of::<i64>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert!(param.set(&[0u8; 8][..]).is_err());
+}
+
+#[test]
+fn test_int_set_unsupported_size() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 3];
+    let mut p = OSSL_PARAM {
+        key: c"odd".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    let err = param.set(42i32).unwrap_err();
+    assert!(is_unsupported_size(&err), "{err}");
+    // the error names the key, the actual size and the supported ones
+    assert!(err.to_string().contains("\"odd\""), "{err}");
+    assert!(err.to_string().contains("data_size 3"), "{err}");
+    assert!(err.to_string().contains("4, 8 bytes"), "{err}");
+    assert_eq!(buf, [0; 3]);
+
+    // the same applies to unsigned integers
+    p.data_type = OSSL_PARAM_UNSIGNED_INTEGER;
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    let err = param.set(42u32).unwrap_err();
+    assert!(is_unsupported_size(&err), "{err}");
+    assert!(err.to_string().contains("data_size 3"), "{err}");
+}
+
+#[test]
+fn test_setter_error_variants() {
+    setup().expect("setup() failed");
+
+    // storing a value of the wrong type
+    let mut value = 0i64;
+    let mut p = OSSL_PARAM {
+        key: c"bits".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::from_mut(&mut value).cast(),
+        data_size: size_of::<i32>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    let err = param.set(c"foo").unwrap_err();
+    assert!(
+        matches!(&err, OSSLParamError::TypeMismatch { expected, .. } if expected == "Int"),
+        "{err}"
+    );
+    assert_eq!(
+        String::from(err),
+        "Type &core::ffi::c_str::CStr could not be stored in OSSLParam::Int"
+    );
+
+    // a value not fitting the (4 bytes) buffer
+    let err = param.set(i64::MAX).unwrap_err();
+    assert_eq!(err, OSSLParamError::ConversionFailed { target: "i32" });
+    assert_eq!(err.to_string(), "value could not be converted to i32");
+
+    // a NULL string
+    let mut buf = [0u8; 8];
+    let mut p = OSSL_PARAM {
+        key: c"name".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_STRING,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    let null = std::ptr::slice_from_raw_parts(std::ptr::null::<u8>(), 0) as *const CStr;
+    let err = param.set(null).unwrap_err();
+    assert_eq!(err, OSSLParamError::NullData);
+
+    // a string not fitting the buffer
+    let err = param.set(c"a long string").unwrap_err();
+    assert!(is_buffer_too_small(&err), "{err}");
+    assert_eq!(
+        err.to_string(),
+        "OSSL_PARAM \"name\" has data_size 8, but 13 bytes are needed"
+    );
+
+    // errors can be handled as generic errors
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert!(matches!(
+        err.downcast_ref::<OSSLParamError>(),
+        Some(OSSLParamError::BufferTooSmall {
+            needed: 13,
+            available: 8,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_size_info_after_set() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 16];
+    let mut p = OSSL_PARAM {
+        key: c"name".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_STRING,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.set(c"x25519").unwrap();
+    assert_eq!(
+        param.size_info(),
+        Some(SizeInfo {
+            capacity: 16,
+            returned: 6,
+            modified: true
+        })
+    );
+
+    // a size query (NULL data) reports the size needed
+    let mut p = OSSL_PARAM {
+        key: c"name".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_STRING,
+        data: ptr::null_mut(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.set(c"a long string").unwrap();
+    assert_eq!(
+        param.size_info(),
+        Some(SizeInfo {
+            capacity: 0,
+            returned: 13,
+            modified: true
+        })
+    );
+}
+
+#[test]
+fn test_size_info_untouched() {
+    setup().expect("setup() failed");
+
+    let mut bits = 0i32;
+    let mut p = OSSL_PARAM {
+        key: c"bits".as_ptr(),
+        data_type: OSSL_PARAM_INTEGER,
+        data: std::ptr::from_mut(&mut bits).cast(),
+        data_size: size_of::<i32>(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let param = OSSLParam::try_from(&mut p).unwrap();
+    let info = param.size_info().unwrap();
+    assert!(!info.modified);
+    assert_eq!(info.capacity, size_of::<i32>());
+    assert_eq!(info.returned, OSSL_PARAM_UNMODIFIED);
+}
+
+#[test]
+fn test_normalize_return_size() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0u8; 8];
+    let mut p = OSSL_PARAM {
+        key: c"name".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_STRING,
+        data: buf.as_mut_ptr().cast(),
+        data_size: buf.len(),
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+
+    // invalid values are reset, so an untouched param is not modified
+    for garbage in [9, 0xdead_beef, isize::MAX as usize + 1] {
+        p.return_size = garbage;
+        let mut param = OSSLParam::try_from(&mut p).unwrap();
+        assert!(param.modified());
+        param.normalize_return_size();
+        assert!(!param.modified(), "{garbage:#x} was not reset");
+    }
+
+    // values a setter could have stored are kept
+    p.return_size = 6;
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.normalize_return_size();
+    assert_eq!(param.size_info().unwrap().returned, 6);
+
+    // and so is a normalized param once set
+    p.return_size = 0xdead_beef;
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.normalize_return_size();
+    param.set(c"x25519").unwrap();
+    assert!(param.modified());
+    assert_eq!(param.size_info().unwrap().returned, 6);
+}
+
+#[test]
+fn test_normalize_return_size_ptr_and_null_data() {
+    setup().expect("setup() failed");
+
+    // the sizes of pointed-to data or of size queries are not bound by
+    // data_size
+    let mut p = OSSL_PARAM {
+        key: c"name".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_STRING,
+        data: ptr::null_mut(),
+        data_size: 0,
+        return_size: 13,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.normalize_return_size();
+    assert_eq!(param.size_info().unwrap().returned, 13);
+
+    let mut ptr_data: *const std::ffi::c_char = ptr::null();
+    let mut p = OSSL_PARAM {
+        key: c"name".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_PTR,
+        data: std::ptr::from_mut(&mut ptr_data).cast(),
+        data_size: size_of::<*const std::ffi::c_char>(),
+        return_size: 100,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.normalize_return_size();
+    assert_eq!(param.size_info().unwrap().returned, 100);
+
+    // but never by isize::MAX
+    p.return_size = usize::MAX - 1;
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    param.normalize_return_size();
+    assert!(!param.modified());
+}
@@ -57,3 +57,126 @@ fn test_uint_try_from() {
     // Check that the result is Err due to mismatched data type
     assert!(result.is_err());
 }
+
+#[test]
+fn test_unknown_data_type_try_from() {
+    setup().expect("setup() failed");
+
+    let mut ossl_param = OSSL_PARAM {
+        data: std::ptr::null_mut(),
+        data_type: 0xdead,
+        return_size: 0,
+        data_size: 0,
+        key: c"key".as_ptr(),
+    };
+
+    let result = OSSLParam::try_from(&mut ossl_param as *mut OSSL_PARAM);
+    let err = result.expect_err("an unknown data type should not be converted");
+    assert!(err.to_string().contains(&0xdead.to_string()), "{err}");
+    assert!(!is_malformed_param(&err), "{err}");
+}
+
+#[test]
+fn test_malformed_try_from() {
+    setup().expect("setup() failed");
+
+    // data_type 0 is reserved for the END item, which has a NULL key
+    let mut ossl_param = OSSL_PARAM {
+        data: std::ptr::null_mut(),
+        data_type: 0,
+        return_size: 0,
+        data_size: 0,
+        key: c"key".as_ptr(),
+    };
+
+    let result = OSSLParam::try_from(&mut ossl_param as *mut OSSL_PARAM);
+    let err = result.expect_err("a malformed param should not be converted");
+    assert!(is_malformed_param(&err), "{err}");
+    assert!(err.to_string().contains("\"key\""), "{err}");
+
+    // The END item itself is not reported as malformed
+    let mut end = OSSL_PARAM::END;
+    let result = OSSLParam::try_from(&mut end as *mut OSSL_PARAM);
+    let err = result.expect_err("END should not be converted");
+    assert!(!is_malformed_param(&err), "{err}");
+}
+
+#[cfg(ossl_param_type = "real")]
+#[test]
+fn test_real_try_from() {
+    setup().expect("setup() failed");
+
+    let mut value = 1.5f64;
+    let mut ossl_param = OSSL_PARAM {
+        data: std::ptr::from_mut(&mut value).cast(),
+        data_type: crate::bindings::OSSL_PARAM_REAL,
+        return_size: OSSL_PARAM_UNMODIFIED,
+        data_size: size_of::<f64>(),
+        key: c"key".as_ptr(),
+    };
+
+    assert_eq!(
+        data_type_name(crate::bindings::OSSL_PARAM_REAL),
+        Some("OSSL_PARAM_REAL")
+    );
+    let param = OSSLParam::try_from(&mut ossl_param as *mut OSSL_PARAM)
+        .expect("OSSL_PARAM_REAL should be converted");
+    assert!(matches!(param, OSSLParam::Real(_)));
+    assert_eq!(param.variant_name(), "Real");
+    assert_eq!(param.get::<f64>(), Some(1.5));
+}
+
+#[test]
+fn test_variant_type_agrees_with_data_type() {
+    setup().expect("setup() failed");
+
+    let params_list = [
+        OSSLParam::new_const_int(c"int", Some(&-1i32)),
+        OSSLParam::new_const_uint(c"uint", Some(&1u64)),
+        OSSLParam::new_const_utf8string(c"utf8", Some(c"string")),
+        OSSLParam::new_const_octetstring(c"octets", Some(&[1, 2, 3])),
+        CONST_OSSL_PARAM::END,
+    ];
+    let expected = [
+        ParamType::Integer,
+        ParamType::UnsignedInteger,
+        ParamType::Utf8String,
+        ParamType::OctetString,
+    ];
+
+    let head = OSSLParam::try_from(params_list.as_ptr() as *const OSSL_PARAM).unwrap();
+    let types: Vec<_> = head
+        .into_iter()
+        .map(|p| {
+            assert_eq!(p.param_type(), Some(p.variant_type()));
+            p.variant_type()
+        })
+        .collect();
+    assert_eq!(types, expected);
+
+    for t in expected {
+        assert_eq!(ParamType::from_data_type(t.data_type()), Some(t));
+    }
+    assert_eq!(ParamType::from_data_type(0), None);
+}
+
+#[test]
+fn test_variant_type_disagreement() {
+    setup().expect("setup() failed");
+
+    let mut value = 42i32;
+    let mut ossl_param = OSSL_PARAM {
+        data: std::ptr::from_mut(&mut value).cast(),
+        data_type: OSSL_PARAM_INTEGER,
+        return_size: OSSL_PARAM_UNMODIFIED,
+        data_size: size_of::<i32>(),
+        key: c"key".as_ptr(),
+    };
+    let mut param = OSSLParam::try_from(&mut ossl_param).unwrap();
+
+    // the underlying struct is changed behind the back of the wrapper
+    unsafe { (*param.get_c_struct_mut()).data_type = OSSL_PARAM_UTF8_STRING };
+
+    assert_eq!(param.variant_type(), ParamType::Integer);
+    assert_eq!(param.param_type(), Some(ParamType::Utf8String));
+}
@@ -0,0 +1,134 @@
+use super::*;
+use std::ffi::c_char;
+
+// Tests for params with a non-NULL data but a zero data_size
+
+/// Builds a param of `data_type` pointing at `buf`, but with a zero `data_size`.
+fn zero_size_param(data_type: u32, buf: &mut [u8]) -> OSSL_PARAM {
+    OSSL_PARAM {
+        key: c"zero".as_ptr(),
+        data_type,
+        data: buf.as_mut_ptr().cast(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    }
+}
+
+#[test]
+fn test_zero_size_int() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0xffu8; 16];
+    let mut p = zero_size_param(OSSL_PARAM_INTEGER, &mut buf);
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    assert_eq!(param.get::<i32>(), None);
+    assert_eq!(param.get::<i64>(), None);
+    assert_eq!(param.get::<i128>(), None);
+
+    let err = param.set(42i64).unwrap_err();
+    assert!(is_buffer_too_small(&err), "{err}");
+    assert!(buf.iter().all(|&b| b == 0xff));
+}
+
+#[test]
+fn test_zero_size_uint() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0xffu8; 16];
+    let mut p = zero_size_param(OSSL_PARAM_UNSIGNED_INTEGER, &mut buf);
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    assert_eq!(param.get::<u64>(), None);
+    assert_eq!(param.get::<u32>(), None);
+
+    let err = param.set(42u64).unwrap_err();
+    assert!(is_buffer_too_small(&err), "{err}");
+    assert!(buf.iter().all(|&b| b == 0xff));
+}
+
+#[test]
+fn test_zero_size_utf8_string() {
+    setup().expect("setup() failed");
+
+    // no terminating NUL byte: the buffer must not be read
+    let mut buf = [b'x'; 16];
+    let mut p = zero_size_param(OSSL_PARAM_UTF8_STRING, &mut buf);
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    assert_eq!(param.get::<&CStr>(), Some(c""));
+
+    let err = param.set(c"value").unwrap_err();
+    assert!(is_buffer_too_small(&err), "{err}");
+    let err = param.set(c"").unwrap_err();
+    assert!(is_buffer_too_small(&err), "{err}");
+    assert!(buf.iter().all(|&b| b == b'x'));
+}
+
+#[test]
+fn test_zero_size_octet_string() {
+    setup().expect("setup() failed");
+
+    let mut buf = [0xffu8; 16];
+    let mut p = zero_size_param(OSSL_PARAM_OCTET_STRING, &mut buf);
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+
+    assert_eq!(param.get::<&[u8]>(), Some([].as_slice()));
+
+    let err = param.set(&[1u8, 2][..]).unwrap_err();
+    assert!(is_buffer_too_small(&err), "{err}");
+    let err = param.set(&[][..]).unwrap_err();
+    assert!(is_buffer_too_small(&err), "{err}");
+    assert!(buf.iter().all(|&b| b == 0xff));
+}
+
+#[test]
+fn test_zero_size_pointers() {
+    setup().expect("setup() failed");
+
+    // For the pointer types, a zero size is an empty value
+    let empty: *const c_char = c"".as_ptr();
+    let mut p = OSSL_PARAM {
+        key: c"utf8ptr".as_ptr(),
+        data_type: OSSL_PARAM_UTF8_PTR,
+        data: std::ptr::from_ref(&empty) as *mut std::ffi::c_void,
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.get::<&CStr>(), Some(c""));
+
+    let bytes = [1u8, 2, 3];
+    let mut target: *const u8 = bytes.as_ptr();
+    let mut p = OSSL_PARAM {
+        key: c"octetptr".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_PTR,
+        data: std::ptr::from_mut(&mut target).cast(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.get::<&[u8]>(), Some([].as_slice()));
+
+    // and setting them is not rejected
+    static VALUE: [u8; 2] = [4, 5];
+    assert_eq!(param.set(&VALUE[..]), Ok(()));
+    assert_eq!(param.get::<&[u8]>(), Some(VALUE.as_slice()));
+}
+
+#[test]
+fn test_zero_size_query_is_not_rejected() {
+    setup().expect("setup() failed");
+
+    // A NULL data is a size query, not a zero-size buffer
+    let mut p = OSSL_PARAM {
+        key: c"query".as_ptr(),
+        data_type: OSSL_PARAM_OCTET_STRING,
+        data: std::ptr::null_mut(),
+        data_size: 0,
+        return_size: OSSL_PARAM_UNMODIFIED,
+    };
+    let mut param = OSSLParam::try_from(&mut p).unwrap();
+    assert_eq!(param.set(&[1u8, 2, 3][..]), Ok(()));
+    assert_eq!(p.return_size, 3);
+}
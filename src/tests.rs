@@ -1 +1,4 @@
 pub(crate) mod common;
+
+mod ffi_guard;
+mod handle_result;
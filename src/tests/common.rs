@@ -1,19 +1,61 @@
 pub use crate::OurError;
 
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::{Cell, RefCell};
+use std::ffi::{c_int, c_void};
 use std::sync::Once;
 
 static INIT: Once = Once::new();
 
+thread_local! {
+    /// The log records captured by [`capture_logs`] on the current thread.
+    static CAPTURED: RefCell<Option<Vec<(log::Level, String)>>> = const { RefCell::new(None) };
+
+    /// The statistics collected by [`track_allocations`] on the current thread.
+    static TRACKED: Cell<Option<AllocationStats>> = const { Cell::new(None) };
+}
+
+/// Wraps the `env_logger` logger, to also capture the records emitted by the
+/// current thread while inside [`capture_logs`], regardless of the configured
+/// filters.
+struct CapturingLogger {
+    inner: env_logger::Logger,
+}
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        CAPTURED.with_borrow_mut(|captured| {
+            if let Some(captured) = captured {
+                captured.push((record.level(), record.args().to_string()));
+            }
+        });
+        if self.inner.matches(record) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
 fn try_init_logging() -> Result<(), OurError> {
-    env_logger::Builder::from_default_env()
+    let inner = env_logger::Builder::from_default_env()
         //.filter_level(log::LevelFilter::Debug)
         .format_timestamp(None) // Optional: disable timestamps
         .format_module_path(true) // Optional: disable module path
         .format_target(false) // Optional: disable target
         .format_source_path(true)
         .is_test(cfg!(test))
-        .try_init()
-        .map_err(OurError::from)
+        .build();
+    let logger: &'static CapturingLogger = Box::leak(Box::new(CapturingLogger { inner }));
+    log::set_logger(logger).map_err(OurError::from)?;
+    log::set_max_level(log::LevelFilter::Trace);
+    Ok(())
 }
 
 pub(crate) fn setup() -> Result<(), OurError> {
@@ -23,3 +65,124 @@ pub(crate) fn setup() -> Result<(), OurError> {
 
     Ok(())
 }
+
+/// Runs `f`, returning its result along with the (level, message) of every
+/// log record emitted by the current thread in the meantime.
+///
+/// [`setup()`] must have been called before.
+pub(crate) fn capture_logs<R>(f: impl FnOnce() -> R) -> (R, Vec<(log::Level, String)>) {
+    CAPTURED.with_borrow_mut(|captured| *captured = Some(Vec::new()));
+    let ret = f();
+    let logs = CAPTURED.with_borrow_mut(Option::take).unwrap_or_default();
+    (ret, logs)
+}
+
+/// The state of [`seen_ids_callback`]: the `"id"` of each params array it
+/// has seen, and how many arrays it accepts before stopping.
+pub(crate) struct SeenIds {
+    pub(crate) ids: Vec<u64>,
+    accept: usize,
+}
+
+impl SeenIds {
+    pub(crate) fn new(accept: usize) -> Self {
+        Self {
+            ids: Vec::new(),
+            accept,
+        }
+    }
+
+    /// Returns the `arg` to pass along with [`seen_ids_callback`].
+    pub(crate) fn as_arg(&mut self) -> *mut c_void {
+        std::ptr::from_mut(self).cast()
+    }
+}
+
+/// A capability callback recording the `"id"` param of each array into the
+/// [`SeenIds`] passed as `arg`.
+pub(crate) unsafe extern "C" fn seen_ids_callback(
+    params: *const crate::osslparams::OSSL_PARAM,
+    arg: *mut c_void,
+) -> c_int {
+    // SAFETY: `arg` comes from `SeenIds::as_arg()`.
+    let seen = unsafe { &mut *(arg as *mut SeenIds) };
+    let id = crate::osslparams::ossl_param_locate_const(params, c"id")
+        .and_then(|p| p.get::<u64>())
+        .expect("unexpected params");
+    seen.ids.push(id);
+    c_int::from(seen.ids.len() < seen.accept)
+}
+
+/// The heap activity of a thread while inside [`track_allocations`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AllocationStats {
+    /// The number of bytes allocated and not freed yet (negative if more
+    /// bytes have been freed than allocated).
+    pub(crate) live_bytes: isize,
+    /// The number of freed blocks which still contained non-zero bytes.
+    pub(crate) dirty_frees: usize,
+}
+
+/// Updates the statistics of the current thread, if it is being tracked.
+fn track(f: impl FnOnce(&mut AllocationStats)) {
+    // The thread-local may be gone while the thread is being torn down.
+    let _ = TRACKED.try_with(|tracked| {
+        if let Some(mut stats) = tracked.get() {
+            f(&mut stats);
+            tracked.set(Some(stats));
+        }
+    });
+}
+
+/// Wraps the system allocator, to collect the [`AllocationStats`] of the
+/// current thread while inside [`track_allocations`].
+struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            track(|stats| stats.live_bytes += layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        track(|stats| {
+            stats.live_bytes -= layout.size() as isize;
+            // Under Miri, reading the (possibly uninitialized) bytes of the
+            // block is reported as UB, so dirty frees are not counted.
+            if cfg!(miri) {
+                return;
+            }
+            // SAFETY: `ptr` is a live block of `layout.size()` bytes.
+            let block = unsafe { std::slice::from_raw_parts(ptr, layout.size()) };
+            if block.iter().any(|&b| b != 0) {
+                stats.dirty_frees += 1;
+            }
+        });
+        unsafe { System.dealloc(ptr, layout) };
+    }
+
+    // Reallocations move the data, so the old block is not counted as a
+    // dirty free.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            track(|stats| stats.live_bytes += new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+/// Runs `f`, returning its result along with the [`AllocationStats`] of the
+/// current thread in the meantime (e.g., to check that `f` does not leak).
+pub(crate) fn track_allocations<R>(f: impl FnOnce() -> R) -> (R, AllocationStats) {
+    TRACKED.set(Some(AllocationStats::default()));
+    let ret = f();
+    let stats = TRACKED.take().unwrap_or_default();
+    (ret, stats)
+}
@@ -0,0 +1,44 @@
+use super::common::{self, OurError};
+use std::ffi::c_int;
+
+fn setup() -> Result<(), OurError> {
+    common::setup()
+}
+
+const ERROR_RET: c_int = 0;
+
+extern "C" fn guarded(should_panic: c_int) -> c_int {
+    crate::ffi_guard! {
+        if should_panic != 0 {
+            panic!("deliberate panic with code {should_panic}");
+        }
+        1
+    }
+}
+
+extern "C" fn guarded_result(fail: c_int) -> c_int {
+    crate::ffi_guard! {
+        let r: Result<c_int, OurError> = if fail != 0 {
+            Err(anyhow::anyhow!("deliberate error"))
+        } else {
+            Ok(42)
+        };
+        crate::handleResult!(r)
+    }
+}
+
+#[test]
+fn test_ffi_guard_catches_panic() {
+    setup().expect("setup() failed");
+
+    assert_eq!(guarded(0), 1);
+    assert_eq!(guarded(1), ERROR_RET);
+}
+
+#[test]
+fn test_ffi_guard_with_handle_result() {
+    setup().expect("setup() failed");
+
+    assert_eq!(guarded_result(0), 42);
+    assert_eq!(guarded_result(1), ERROR_RET);
+}
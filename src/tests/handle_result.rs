@@ -0,0 +1,66 @@
+use super::common::{self, OurError};
+use crate::operations::keymgmt::{ERROR_RET_INT, ERROR_RET_PTR};
+use std::ffi::{c_int, c_void};
+
+fn setup() -> Result<(), OurError> {
+    common::setup()
+}
+
+fn result(fail: c_int) -> Result<c_int, OurError> {
+    if fail != 0 {
+        Err(anyhow::anyhow!("deliberate error"))
+    } else {
+        Ok(42)
+    }
+}
+
+extern "C" fn returns_int(fail: c_int) -> c_int {
+    crate::handleResultRet!(ERROR_RET_INT, result(fail))
+}
+
+extern "C" fn returns_custom_int(fail: c_int) -> c_int {
+    crate::handleResultRet!(-1, result(fail))
+}
+
+static mut DATA: c_int = 0;
+
+extern "C" fn returns_ptr(fail: c_int) -> *mut c_void {
+    let _ = crate::handleResultRet!(ERROR_RET_PTR, result(fail));
+    std::ptr::addr_of_mut!(DATA).cast()
+}
+
+#[test]
+fn test_handle_result_ret_int() {
+    setup().expect("setup() failed");
+
+    assert_eq!(returns_int(0), 42);
+    assert_eq!(returns_int(1), ERROR_RET_INT);
+}
+
+#[test]
+fn test_handle_result_ret_custom_value() {
+    setup().expect("setup() failed");
+
+    assert_eq!(returns_custom_int(0), 42);
+    assert_eq!(returns_custom_int(1), -1);
+}
+
+#[test]
+fn test_handle_result_ret_ptr() {
+    setup().expect("setup() failed");
+
+    assert!(!returns_ptr(0).is_null());
+    assert_eq!(returns_ptr(1), ERROR_RET_PTR);
+    assert!(returns_ptr(1).is_null());
+}
+
+#[test]
+fn test_handle_result_ret_logs_error() {
+    setup().expect("setup() failed");
+
+    let (ret, logs) = common::capture_logs(|| returns_custom_int(1));
+    assert_eq!(ret, -1);
+    assert!(logs
+        .iter()
+        .any(|(level, msg)| *level == log::Level::Error && msg.contains("deliberate error")));
+}
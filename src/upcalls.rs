@@ -24,14 +24,226 @@ pub struct OSSL_CORE_HANDLE {
 pub mod traits {
     use super::*;
     use crate::bindings::{
-        OSSL_CORE_BIO, OSSL_FUNC_BIO_READ_EX, OSSL_FUNC_BIO_WRITE_EX, OSSL_FUNC_CORE_OBJ_ADD_SIGID,
-        OSSL_FUNC_CORE_OBJ_CREATE,
+        OSSL_CORE_BIO, OSSL_FUNC_BIO_FREE, OSSL_FUNC_BIO_GETS, OSSL_FUNC_BIO_READ_EX,
+        OSSL_FUNC_BIO_WRITE_EX, OSSL_FUNC_CORE_NEW_ERROR, OSSL_FUNC_CORE_OBJ_ADD_SIGID,
+        OSSL_FUNC_CORE_OBJ_CREATE, OSSL_FUNC_CORE_SET_ERROR_DEBUG, OSSL_FUNC_CORE_VSET_ERROR,
     };
     pub(crate) use ::function_name::named;
     use anyhow::anyhow;
-    use std::ffi::{c_char, c_int, c_void, CStr};
+    use std::ffi::{c_char, c_int, c_void, CStr, CString};
     use std::sync::OnceLock;
     use zeroize::{Zeroize, Zeroizing};
+
+    /// The size of the buffer used by [`CoreUpcaller::BIO_read_ex`] for each
+    /// underlying upcall.
+    const READ_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+    /// The maximum number of underlying upcalls made by
+    /// [`CoreUpcaller::BIO_read_ex`], as it collects all the data in memory.
+    const MAX_READ_ITERATIONS: usize = 10;
+
+    /// The default size of the chunks read by
+    /// [`CoreUpcaller::BIO_read_to_writer`] with each underlying upcall.
+    pub const DEFAULT_READ_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// The configuration of [`CoreUpcaller::BIO_read_ex_with_config`].
+    ///
+    /// The [`Default`] is the configuration used by
+    /// [`CoreUpcaller::BIO_read_ex`]: chunks of 8 MiB, and at most 10
+    /// underlying upcalls.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct BioReadConfig {
+        /// The size of the buffer used for each underlying upcall (which
+        /// must not be 0).
+        pub chunk_size: usize,
+        /// The maximum number of underlying upcalls, including the one
+        /// hitting EOF, after which the read fails.
+        pub max_iterations: usize,
+    }
+
+    impl Default for BioReadConfig {
+        fn default() -> Self {
+            Self {
+                chunk_size: READ_BUFFER_SIZE,
+                max_iterations: MAX_READ_ITERATIONS,
+            }
+        }
+    }
+
+    thread_local! {
+        /// The buffer reused by [`CoreUpcaller::BIO_read_ex_pooled`].
+        static READ_BUFFER_POOL: std::cell::RefCell<Option<Zeroizing<Vec<u8>>>> =
+            const { std::cell::RefCell::new(None) };
+    }
+
+    pub(super) type FfiBioReadEx = unsafe extern "C" fn(
+        bio: *mut OSSL_CORE_BIO,
+        data: *mut c_void,
+        data_len: usize,
+        bytes_read: *mut usize,
+    ) -> c_int;
+
+    /// Returns the `BIO_read_ex()` upcall from the core dispatch table of
+    /// `upcaller`, shared by [`CoreUpcaller::BIO_read_ex`] (and its variants)
+    /// and [`CoreBio::read`][`super::CoreBio::read`].
+    pub(super) fn ffi_bio_read_ex<U: CoreUpcaller + ?Sized>(
+        upcaller: &U,
+    ) -> Result<FfiBioReadEx, Error> {
+        static CELL: OnceLock<Option<unsafe extern "C" fn()>> = OnceLock::new();
+        let fn_ptr = CELL
+            .get_or_init(|| upcaller.fn_from_core_dispatch(OSSL_FUNC_BIO_READ_EX))
+            .ok_or_else(|| anyhow!("No upcall pointer"))?;
+        // SAFETY: the core dispatches OSSL_FUNC_BIO_READ_EX with the
+        // signature of OSSL_FUNC_BIO_read_ex_fn.
+        Ok(unsafe { std::mem::transmute::<unsafe extern "C" fn(), FfiBioReadEx>(fn_ptr) })
+    }
+
+    /// Reads `bio` until EOF through `ffi_BIO_read_ex`, using `buffer` for
+    /// each underlying upcall and writing the data read to `out`.
+    ///
+    /// Returns the number of bytes read, failing after `max_iterations`
+    /// upcalls (if given), after too many consecutive upcalls not reading
+    /// anything, or if writing to `out` fails.
+    #[expect(non_snake_case)]
+    #[named]
+    fn bio_read_loop<W: std::io::Write + ?Sized>(
+        ffi_BIO_read_ex: FfiBioReadEx,
+        bio: *mut OSSL_CORE_BIO,
+        buffer: &mut [u8],
+        out: &mut W,
+        max_iterations: Option<usize>,
+    ) -> Result<usize, crate::OurError> {
+        let mut bytes_read: usize = 0;
+        let mut total_bytes_read: usize = 0;
+
+        /// The number of consecutive upcalls reading nothing (without
+        /// hitting EOF) after which we give up, so that we never spin forever.
+        const MAX_EMPTY_READS: usize = 10;
+        let mut empty_reads: usize = 0;
+        let mut cnt: usize = 0;
+        loop {
+            cnt += 1;
+            let ret = unsafe {
+                ffi_BIO_read_ex(
+                    bio,
+                    buffer.as_mut_ptr() as *mut c_void,
+                    buffer.len(),
+                    &mut bytes_read,
+                )
+            };
+            match (ret, bytes_read) {
+                (0, 0) => {
+                    trace!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes => stopping for EOF");
+                    break;
+                }
+                (0, _n) => {
+                    warn!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes");
+                }
+                (1, 0) => {
+                    warn!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes");
+                }
+                (1, _n) => {
+                    trace!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes => 👍");
+                }
+                (_r, _n) => {
+                    error!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes");
+                }
+            };
+            if let Some(max) = max_iterations.filter(|&max| cnt > max) {
+                error!(
+                    target: log_target!(),
+                    "Reached {cnt:} upcalls to BIO_read_ex => stopping due to too many attempts"
+                );
+                return Err(anyhow::anyhow!(
+                    "Underlying upcall to BIO_read_ex called too many times (limit: {max})"
+                ));
+            }
+            if bytes_read == 0 {
+                empty_reads += 1;
+                if empty_reads > MAX_EMPTY_READS {
+                    error!(
+                        target: log_target!(),
+                        "Reached {empty_reads:} consecutive upcalls to BIO_read_ex reading nothing => stopping"
+                    );
+                    return Err(anyhow::anyhow!(
+                        "Underlying upcall to BIO_read_ex is not making progress"
+                    ));
+                }
+            } else {
+                empty_reads = 0;
+            }
+            out.write_all(&buffer[0..bytes_read])
+                .map_err(|e| anyhow!("Failed to write the data read from the BIO: {e}"))?;
+            total_bytes_read += bytes_read;
+        }
+        debug!(
+            target: log_target!(),
+            "BIO_read_ex read {total_bytes_read} bytes in total, over {cnt:} upcalls"
+        );
+        Ok(total_bytes_read)
+    }
+
+    type FfiBioGets =
+        unsafe extern "C" fn(bio: *mut OSSL_CORE_BIO, buf: *mut c_char, size: c_int) -> c_int;
+
+    /// Reads a line of up to `max` bytes from `bio` through `ffi_BIO_gets`,
+    /// for [`CoreUpcaller::BIO_gets`].
+    #[expect(non_snake_case)]
+    #[named]
+    fn bio_gets_line(
+        ffi_BIO_gets: FfiBioGets,
+        bio: *mut OSSL_CORE_BIO,
+        max: usize,
+    ) -> Result<Option<Box<[u8]>>, crate::OurError> {
+        if max == 0 {
+            return Err(anyhow!("BIO_gets() needs room for at least one byte"));
+        }
+        // `size` includes the terminating NUL byte written by BIO_gets()
+        let size = max
+            .checked_add(1)
+            .and_then(|size| c_int::try_from(size).ok())
+            .ok_or_else(|| anyhow!("BIO_gets() cannot read {max} bytes at once"))?;
+        let mut buffer = vec![0u8; max + 1];
+
+        let ret = unsafe { ffi_BIO_gets(bio, buffer.as_mut_ptr().cast(), size) };
+        match usize::try_from(ret) {
+            Ok(0) => {
+                trace!(target: log_target!(), "Underlying upcall to BIO_gets returned {ret:} => EOF");
+                Ok(None)
+            }
+            Ok(n) if n <= max => {
+                buffer.truncate(n);
+                Ok(Some(buffer.into_boxed_slice()))
+            }
+            _ => {
+                error!(target: log_target!(), "Underlying upcall to BIO_gets returned {ret:}");
+                Err(anyhow!("BIO_gets() upcall failed"))
+            }
+        }
+    }
+
+    /// Same as [`bio_read_loop()`], collecting the data read into a boxed
+    /// slice, and failing after `max_iterations` upcalls.
+    ///
+    /// The data is zeroized if the read fails.
+    #[expect(non_snake_case)]
+    fn bio_read_to_vec(
+        ffi_BIO_read_ex: FfiBioReadEx,
+        bio: *mut OSSL_CORE_BIO,
+        buffer: &mut [u8],
+        max_iterations: usize,
+    ) -> Result<Box<[u8]>, crate::OurError> {
+        let mut ret_buffer: Zeroizing<Vec<u8>> = Zeroizing::new(Vec::new());
+        bio_read_loop(
+            ffi_BIO_read_ex,
+            bio,
+            buffer,
+            &mut *ret_buffer,
+            Some(max_iterations),
+        )?;
+        Ok(std::mem::take(&mut *ret_buffer).into_boxed_slice())
+    }
+
     pub trait CoreUpcaller {
         fn fn_from_core_dispatch(&self, id: u32) -> Option<unsafe extern "C" fn()>;
 
@@ -39,85 +251,119 @@ pub mod traits {
         #[named]
         /// Makes a BIO_read_ex() core upcall.
         ///
+        /// This is [`CoreUpcaller::BIO_read_ex_with_config`] with the
+        /// [`Default`] [`BioReadConfig`].
+        ///
         /// Refer to [BIO_read_ex(3ossl)](https://docs.openssl.org/3.5/man3/BIO_read/).
         fn BIO_read_ex(&self, bio: *mut OSSL_CORE_BIO) -> Result<Box<[u8]>, crate::OurError> {
             trace!(target: log_target!(), "Called");
-            static CELL: OnceLock<Option<unsafe extern "C" fn()>> = OnceLock::new();
-            let fn_ptr = CELL.get_or_init(|| {
-                let f = self.fn_from_core_dispatch(OSSL_FUNC_BIO_READ_EX);
-                f
-            });
-            let fn_ptr = match fn_ptr {
-                Some(f) => f,
-                None => {
-                    return Err(anyhow::anyhow!("No upcall pointer"));
-                }
-            };
+            self.BIO_read_ex_with_config(bio, BioReadConfig::default())
+        }
 
-            // FIXME: is there a way to just specify the type using the type alias OSSL_FUNC_BIO_read_ex_fn
-            // instead of writing it all out again?
-            let ffi_BIO_read_ex = unsafe {
-                std::mem::transmute::<
-                    *const (),
-                    unsafe extern "C" fn(
-                        bio: *mut OSSL_CORE_BIO,
-                        data: *mut c_void,
-                        data_len: usize,
-                        bytes_read: *mut usize,
-                    ) -> c_int,
-                >(*fn_ptr as _)
-            };
+        #[expect(non_snake_case)]
+        #[named]
+        /// Reads `bio` until EOF through BIO_read_ex() core upcalls, reading
+        /// up to `config.chunk_size` bytes with each of them.
+        ///
+        /// Fails if more than `config.max_iterations` upcalls are needed
+        /// (including the one hitting EOF), as all the data is collected in
+        /// memory: see [`CoreUpcaller::BIO_read_to_writer`] to stream it
+        /// instead.
+        ///
+        /// Refer to [BIO_read_ex(3ossl)](https://docs.openssl.org/3.5/man3/BIO_read/).
+        fn BIO_read_ex_with_config(
+            &self,
+            bio: *mut OSSL_CORE_BIO,
+            config: BioReadConfig,
+        ) -> Result<Box<[u8]>, crate::OurError> {
+            trace!(target: log_target!(), "Called with {config:?}");
+            if config.chunk_size == 0 {
+                return Err(anyhow!("The chunk size must not be 0"));
+            }
+            let ffi_BIO_read_ex = ffi_bio_read_ex(self)?;
 
             // We use a mutable Vec to buffer reads, so we can do big reads on the heap and minimize calls
             // we might want to tweak the capacity depending on what size data we're usually using it for
-            let mut buffer: Zeroizing<Vec<u8>> = Zeroizing::new(vec![42; 8 * 1024 * 1024]);
-            let mut bytes_read: usize = 0;
+            let mut buffer: Zeroizing<Vec<u8>> = Zeroizing::new(vec![42; config.chunk_size]);
+            bio_read_to_vec(ffi_BIO_read_ex, bio, &mut buffer, config.max_iterations)
+        }
 
-            let mut ret_buffer: Vec<u8> = Vec::new();
+        #[expect(non_snake_case)]
+        #[named]
+        /// Reads `bio` until EOF through BIO_read_ex() core upcalls, writing
+        /// the data to `out` as it is read, in chunks of
+        /// [`DEFAULT_READ_CHUNK_SIZE`] bytes.
+        ///
+        /// Unlike [`CoreUpcaller::BIO_read_ex`], this never holds more than
+        /// one chunk in memory, so there is no limit on the number of
+        /// underlying upcalls: it only fails if the upcall is not available,
+        /// if too many consecutive upcalls read nothing, or if writing to
+        /// `out` fails.
+        ///
+        /// Returns the number of bytes read (and written to `out`).
+        ///
+        /// Refer to [BIO_read_ex(3ossl)](https://docs.openssl.org/3.5/man3/BIO_read/).
+        fn BIO_read_to_writer<W: std::io::Write + ?Sized>(
+            &self,
+            bio: *mut OSSL_CORE_BIO,
+            out: &mut W,
+        ) -> Result<usize, crate::OurError>
+        where
+            Self: Sized,
+        {
+            trace!(target: log_target!(), "Called");
+            self.BIO_read_to_writer_with_chunk_size(bio, out, DEFAULT_READ_CHUNK_SIZE)
+        }
 
-            const MAX_ITERATIONS: usize = 10;
-            let mut cnt: usize = 0;
-            loop {
-                cnt += 1;
-                let ret = unsafe {
-                    ffi_BIO_read_ex(
-                        bio,
-                        buffer.as_mut_ptr() as *mut c_void,
-                        buffer.capacity(),
-                        &mut bytes_read,
-                    )
-                };
-                match (ret, bytes_read) {
-                    (0, 0) => {
-                        trace!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes => stopping for EOF");
-                        break;
-                    }
-                    (0, _n) => {
-                        warn!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes");
-                    }
-                    (1, 0) => {
-                        warn!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes");
-                    }
-                    (1, _n) => {
-                        trace!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes => 👍");
-                    }
-                    (_r, _n) => {
-                        error!(target: log_target!(), "Underlying upcall #{cnt:} to BIO_read_ex returned {ret:} after {bytes_read:} bytes");
-                    }
-                };
-                if cnt > MAX_ITERATIONS {
-                    error!(
-                        target: log_target!(),
-                        "Reached {cnt:} upcalls to BIO_read_ex => stopping due to too many attempts"
-                    );
-                    ret_buffer.zeroize();
-                    return Err(anyhow::anyhow!(
-                        "Underlying upcall to BIO_read_ex called too many times"
-                    ));
-                }
-                ret_buffer.extend_from_slice(&buffer[0..bytes_read]);
+        #[expect(non_snake_case)]
+        #[named]
+        /// Same as [`CoreUpcaller::BIO_read_to_writer`], reading chunks of
+        /// `chunk_size` bytes (which must not be 0) with each underlying
+        /// upcall.
+        ///
+        /// The chunk buffer is zeroized before returning.
+        fn BIO_read_to_writer_with_chunk_size<W: std::io::Write + ?Sized>(
+            &self,
+            bio: *mut OSSL_CORE_BIO,
+            out: &mut W,
+            chunk_size: usize,
+        ) -> Result<usize, crate::OurError>
+        where
+            Self: Sized,
+        {
+            trace!(target: log_target!(), "Called");
+            if chunk_size == 0 {
+                return Err(anyhow!("The chunk size must not be 0"));
             }
-            Ok(ret_buffer.into_boxed_slice())
+            let ffi_BIO_read_ex = ffi_bio_read_ex(self)?;
+
+            let mut buffer: Zeroizing<Vec<u8>> = Zeroizing::new(vec![0; chunk_size]);
+            bio_read_loop(ffi_BIO_read_ex, bio, &mut buffer, out, None)
+        }
+
+        #[expect(non_snake_case)]
+        #[named]
+        /// Like [`CoreUpcaller::BIO_read_ex`], but reuses a per-thread buffer
+        /// across calls, instead of allocating a new one each time.
+        ///
+        /// The buffer is zeroized before being returned to the pool, so no
+        /// data is left behind between calls.
+        /// This trades the memory of one buffer per thread for less
+        /// allocator churn, which matters to providers reading many BIOs.
+        fn BIO_read_ex_pooled(
+            &self,
+            bio: *mut OSSL_CORE_BIO,
+        ) -> Result<Box<[u8]>, crate::OurError> {
+            trace!(target: log_target!(), "Called");
+            let ffi_BIO_read_ex = ffi_bio_read_ex(self)?;
+
+            let mut buffer = READ_BUFFER_POOL
+                .with_borrow_mut(Option::take)
+                .unwrap_or_else(|| Zeroizing::new(vec![0; READ_BUFFER_SIZE]));
+            let ret = bio_read_to_vec(ffi_BIO_read_ex, bio, &mut buffer, MAX_READ_ITERATIONS);
+            buffer.as_mut_slice().zeroize();
+            READ_BUFFER_POOL.with_borrow_mut(|pool| *pool = Some(buffer));
+            ret
         }
 
         #[expect(non_snake_case)]
@@ -209,8 +455,84 @@ pub mod traits {
                     ));
                 }
             }
+            debug!(
+                target: log_target!(),
+                "BIO_write_ex wrote {total_bytes_written:} bytes in total, over {cnt:} upcalls"
+            );
             Ok(total_bytes_written)
         }
+
+        #[expect(non_snake_case)]
+        #[named]
+        /// Makes a BIO_gets() core upcall, reading a single line from `bio`.
+        ///
+        /// It reads up to `max` bytes, stopping after the first newline
+        /// (which is included in the returned data, as with `fgets()`).
+        /// Returns [`None`] at EOF, and fails if `max` is 0 (or does not fit
+        /// in an `int`), or if the underlying upcall reports an error.
+        ///
+        /// Refer to [BIO_gets(3ossl)](https://docs.openssl.org/3.2/man3/BIO_read/).
+        fn BIO_gets(
+            &self,
+            bio: *mut OSSL_CORE_BIO,
+            max: usize,
+        ) -> Result<Option<Box<[u8]>>, crate::OurError> {
+            trace!(target: log_target!(), "Called");
+            static CELL: OnceLock<Option<unsafe extern "C" fn()>> = OnceLock::new();
+            let fn_ptr = CELL.get_or_init(|| self.fn_from_core_dispatch(OSSL_FUNC_BIO_GETS));
+            let fn_ptr = match fn_ptr {
+                Some(f) => f,
+                None => {
+                    error!(target: log_target!(), "Unable to retrieve BIO_gets() upcall pointer");
+                    return Err(anyhow::anyhow!("No BIO_gets() upcall pointer"));
+                }
+            };
+
+            let ffi_BIO_gets =
+                unsafe { std::mem::transmute::<unsafe extern "C" fn(), FfiBioGets>(*fn_ptr) };
+            bio_gets_line(ffi_BIO_gets, bio, max)
+        }
+
+        #[expect(non_snake_case)]
+        #[named]
+        /// Makes a BIO_free() core upcall.
+        ///
+        /// After a successful call `bio` must not be used anymore.
+        /// See [`CoreBio`][`super::CoreBio`] for a wrapper which frees the
+        /// BIO automatically when dropped.
+        ///
+        /// Refer to [BIO_free(3ossl)](https://docs.openssl.org/3.2/man3/BIO_new/).
+        fn BIO_free(&self, bio: *mut OSSL_CORE_BIO) -> Result<(), crate::OurError> {
+            trace!(target: log_target!(), "Called");
+            static CELL: OnceLock<Option<unsafe extern "C" fn()>> = OnceLock::new();
+            let fn_ptr = CELL.get_or_init(|| self.fn_from_core_dispatch(OSSL_FUNC_BIO_FREE));
+            let fn_ptr = match fn_ptr {
+                Some(f) => f,
+                None => {
+                    error!(target: log_target!(), "Unable to retrieve BIO_free() upcall pointer");
+                    return Err(anyhow::anyhow!("No BIO_free() upcall pointer"));
+                }
+            };
+
+            let ffi_BIO_free = unsafe {
+                std::mem::transmute::<
+                    *const (),
+                    unsafe extern "C" fn(bio: *mut OSSL_CORE_BIO) -> c_int,
+                >(*fn_ptr as _)
+            };
+
+            /// Refer to [BIO_free(3ossl)](https://docs.openssl.org/3.2/man3/BIO_new/#return-values)
+            const RET_SUCCESS: c_int = 1;
+
+            let ret = unsafe { ffi_BIO_free(bio) };
+            match ret {
+                RET_SUCCESS => Ok(()),
+                r => {
+                    error!(target: log_target!(), "Underlying upcall to BIO_free returned {r:}");
+                    Err(anyhow!("BIO_free() upcall failed"))
+                }
+            }
+        }
     }
 
     pub trait CoreUpcallerWithCoreHandle: CoreUpcaller {
@@ -352,12 +674,179 @@ pub mod traits {
                 _ => unreachable!(),
             }
         }
+
+        #[named]
+        /// Makes a `core_new_error()` core upcall.
+        ///
+        /// It pushes a new, empty error record onto the error stack of the
+        /// calling thread, to be filled in with [`set_error_debug()`] and
+        /// [`set_error()`].
+        /// This is the provider equivalent of `ERR_new()`.
+        ///
+        /// Refer to [provider-base(7ossl)](https://docs.openssl.org/3.2/man7/provider-base/#core-functions).
+        ///
+        /// [`set_error_debug()`]: CoreUpcallerWithCoreHandle::set_error_debug
+        /// [`set_error()`]: CoreUpcallerWithCoreHandle::set_error
+        fn new_error(&self) -> Result<(), crate::OurError> {
+            trace!(target: log_target!(), "Called");
+            let handle = self.get_core_handle();
+
+            static CELL: OnceLock<Option<unsafe extern "C" fn()>> = OnceLock::new();
+            let fn_ptr = CELL.get_or_init(|| self.fn_from_core_dispatch(OSSL_FUNC_CORE_NEW_ERROR));
+            let fn_ptr = match fn_ptr {
+                Some(f) => f,
+                None => {
+                    return Err(anyhow::anyhow!("No upcall pointer"));
+                }
+            };
+
+            let ffi_core_new_error = unsafe {
+                std::mem::transmute::<*const (), unsafe extern "C" fn(prov: *const OSSL_CORE_HANDLE)>(
+                    *fn_ptr as _,
+                )
+            };
+
+            unsafe { ffi_core_new_error(handle) };
+            Ok(())
+        }
+
+        #[named]
+        /// Makes a `core_set_error_debug()` core upcall.
+        ///
+        /// It sets the source `file`, `line` and function name (`func`) of
+        /// the error record most recently pushed with [`new_error()`].
+        /// This is the provider equivalent of `ERR_set_debug()`.
+        ///
+        /// `file` and `func` only need to be valid for the duration of the
+        /// call, as the core copies them into the error record.
+        ///
+        /// Refer to [provider-base(7ossl)](https://docs.openssl.org/3.2/man7/provider-base/#core-functions).
+        ///
+        /// [`new_error()`]: CoreUpcallerWithCoreHandle::new_error
+        fn set_error_debug(
+            &self,
+            file: &CStr,
+            line: c_int,
+            func: &CStr,
+        ) -> Result<(), crate::OurError> {
+            trace!(target: log_target!(), "Called");
+            let handle = self.get_core_handle();
+
+            static CELL: OnceLock<Option<unsafe extern "C" fn()>> = OnceLock::new();
+            let fn_ptr =
+                CELL.get_or_init(|| self.fn_from_core_dispatch(OSSL_FUNC_CORE_SET_ERROR_DEBUG));
+            let fn_ptr = match fn_ptr {
+                Some(f) => f,
+                None => {
+                    return Err(anyhow::anyhow!("No upcall pointer"));
+                }
+            };
+
+            let ffi_core_set_error_debug = unsafe {
+                std::mem::transmute::<
+                    *const (),
+                    unsafe extern "C" fn(
+                        prov: *const OSSL_CORE_HANDLE,
+                        file: *const c_char,
+                        line: c_int,
+                        func: *const c_char,
+                    ),
+                >(*fn_ptr as _)
+            };
+
+            unsafe { ffi_core_set_error_debug(handle, file.as_ptr(), line, func.as_ptr()) };
+            Ok(())
+        }
+
+        #[named]
+        /// Makes a `core_vset_error()` core upcall.
+        ///
+        /// It sets the `reason_code` and the message (formatted from
+        /// `fmt_args`, e.g. with [`format_args!()`]) of the error record most
+        /// recently pushed with [`new_error()`].
+        /// This is the provider equivalent of `ERR_set_error()`.
+        /// If the library part of `reason_code` is 0, the core fills it in
+        /// with the library number assigned to the provider.
+        ///
+        /// The message is formatted in Rust, and passed to the core as a
+        /// format string without conversion specifications (i.e., with any
+        /// `%` escaped as `%%`), so that the `va_list` of arguments is never
+        /// read: as it cannot be built in stable Rust, a zeroed stand-in is
+        /// passed instead.
+        /// The message only needs to be valid for the duration of the call,
+        /// as the core formats it into a buffer of the error record.
+        ///
+        /// > ⚠️ The stand-in is only sound where a `va_list` argument is
+        /// > passed as a pointer (either as is, or as a pointer to the
+        /// > caller's copy), so this method is only available on `x86_64`
+        /// > and `aarch64` targets.
+        ///
+        /// Fails if the upcall is not available, or if the formatted message
+        /// contains a NUL byte.
+        ///
+        /// Refer to [provider-base(7ossl)](https://docs.openssl.org/3.2/man7/provider-base/#core-functions)
+        /// and [ERR_new(3ossl)](https://docs.openssl.org/3.2/man3/ERR_new/).
+        ///
+        /// [`new_error()`]: CoreUpcallerWithCoreHandle::new_error
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        fn set_error(
+            &self,
+            reason_code: u32,
+            fmt_args: std::fmt::Arguments<'_>,
+        ) -> Result<(), crate::OurError> {
+            trace!(target: log_target!(), "Called");
+            let handle = self.get_core_handle();
+
+            static CELL: OnceLock<Option<unsafe extern "C" fn()>> = OnceLock::new();
+            let fn_ptr = CELL.get_or_init(|| self.fn_from_core_dispatch(OSSL_FUNC_CORE_VSET_ERROR));
+            let fn_ptr = match fn_ptr {
+                Some(f) => f,
+                None => {
+                    return Err(anyhow::anyhow!("No upcall pointer"));
+                }
+            };
+
+            // `va_list` is passed as a pointer on the x86_64 and AArch64 ABIs
+            // (either as is, or as a pointer to the caller's copy).
+            let ffi_core_vset_error = unsafe {
+                std::mem::transmute::<
+                    *const (),
+                    unsafe extern "C" fn(
+                        prov: *const OSSL_CORE_HANDLE,
+                        reason: u32,
+                        fmt: *const c_char,
+                        args: *mut c_void,
+                    ),
+                >(*fn_ptr as _)
+            };
+
+            let message = std::fmt::format(fmt_args).replace('%', "%%");
+            let message = CString::new(message)
+                .map_err(|e| anyhow!("Error message contains a NUL byte: {e}"))?;
+
+            // Large enough for the `va_list` of the x86_64 and AArch64 ABIs
+            // (at most 32 bytes), and never read (see above).
+            let mut no_args = [0usize; 4];
+
+            unsafe {
+                ffi_core_vset_error(
+                    handle,
+                    reason_code,
+                    message.as_ptr(),
+                    no_args.as_mut_ptr().cast(),
+                )
+            };
+            Ok(())
+        }
     }
 }
 
 use crate::bindings::OSSL_DISPATCH;
 use traits::*;
 
+mod bio;
+pub use bio::CoreBio;
+
 use std::collections::HashMap;
 
 #[derive(Debug)]
@@ -485,3 +974,9 @@ impl<'a> From<CoreDispatchWithCoreHandle<'a>> for (CoreDispatch<'a>, *const OSSL
         (core_dispatch, core_handle)
     }
 }
+
+#[cfg(test)]
+pub(crate) mod mock;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,166 @@
+//! A RAII wrapper for the BIOs handed out by the OpenSSL core.
+//!
+//! Besides freeing the BIO when dropped, [`CoreBio`] implements
+//! [`std::io::Read`] and [`std::io::Write`] on top of the BIO upcalls, so that
+//! provider code can use the standard I/O combinators against a core BIO.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use openssl_provider_forge::bindings::OSSL_CORE_BIO;
+//! use openssl_provider_forge::upcalls::traits::CoreUpcaller;
+//! use openssl_provider_forge::upcalls::CoreBio;
+//! use std::io::{BufRead, BufReader};
+//!
+//! /// Returns the PEM label of the first line of `bio` (e.g., `PRIVATE KEY`).
+//! fn pem_label(core: &impl CoreUpcaller, bio: *mut OSSL_CORE_BIO) -> std::io::Result<String> {
+//!     // SAFETY: we own `bio`, and nobody else is going to free it.
+//!     let bio = unsafe { CoreBio::from_raw(core, bio) };
+//!     let mut line = String::new();
+//!     BufReader::new(bio).read_line(&mut line)?;
+//!     let label = line
+//!         .trim_end()
+//!         .strip_prefix("-----BEGIN ")
+//!         .and_then(|l| l.strip_suffix("-----"));
+//!     Ok(label.unwrap_or_default().to_owned())
+//!     // `bio` is freed here
+//! }
+//! ```
+
+use super::*;
+use crate::bindings::OSSL_CORE_BIO;
+
+/// An owned [`OSSL_CORE_BIO`], which is freed through the `BIO_free()` core
+/// upcall when dropped.
+///
+/// Use this for BIOs which the provider is responsible for freeing (e.g., those
+/// opened through the core): BIOs merely borrowed from the core (e.g., the ones
+/// passed to a decoder) must **not** be wrapped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use openssl_provider_forge::bindings::OSSL_CORE_BIO;
+/// use openssl_provider_forge::upcalls::traits::CoreUpcaller;
+/// use openssl_provider_forge::upcalls::CoreBio;
+///
+/// fn read_all(core: &impl CoreUpcaller, bio: *mut OSSL_CORE_BIO) -> Box<[u8]> {
+///     // SAFETY: we own `bio`, and nobody else is going to free it.
+///     let bio = unsafe { CoreBio::from_raw(core, bio) };
+///     core.BIO_read_ex(bio.as_ptr()).unwrap_or_default()
+///     // `bio` is freed here
+/// }
+/// ```
+pub struct CoreBio<'a, U: CoreUpcaller + ?Sized> {
+    bio: *mut OSSL_CORE_BIO,
+    upcaller: &'a U,
+}
+
+impl<'a, U: CoreUpcaller + ?Sized> CoreBio<'a, U> {
+    /// Takes ownership of `bio`, which will be freed using the `BIO_free()`
+    /// upcall of `upcaller`.
+    ///
+    /// # Safety
+    ///
+    /// `bio` must be a valid [`OSSL_CORE_BIO`] (or `NULL`) owned by the
+    /// caller, and it must not be freed by anyone else.
+    pub unsafe fn from_raw(upcaller: &'a U, bio: *mut OSSL_CORE_BIO) -> Self {
+        Self { bio, upcaller }
+    }
+
+    /// Returns the wrapped [`OSSL_CORE_BIO`], which remains owned by `self`.
+    pub fn as_ptr(&self) -> *mut OSSL_CORE_BIO {
+        self.bio
+    }
+
+    /// Releases the ownership of the wrapped [`OSSL_CORE_BIO`], which is
+    /// returned without being freed.
+    pub fn into_raw(self) -> *mut OSSL_CORE_BIO {
+        let bio = self.bio;
+        std::mem::forget(self);
+        bio
+    }
+
+    /// Reads up to `buf.len()` bytes from the wrapped BIO with a single
+    /// `BIO_read_ex()` upcall, returning the number of bytes read: 0 means
+    /// EOF (or an empty `buf`).
+    ///
+    /// Unlike [`CoreUpcaller::BIO_read_ex`], this does not read until EOF.
+    #[expect(non_snake_case)]
+    #[named]
+    pub fn read(&mut self, buf: &mut [u8]) -> Result<usize, crate::OurError> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.bio.is_null() {
+            return Err(anyhow::anyhow!("Cannot read from a NULL BIO"));
+        }
+        let ffi_BIO_read_ex = traits::ffi_bio_read_ex(self.upcaller)?;
+
+        let mut bytes_read: usize = 0;
+        let ret = unsafe {
+            ffi_BIO_read_ex(
+                self.bio,
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                &mut bytes_read,
+            )
+        };
+        match (ret, bytes_read) {
+            (0 | 1, n) if n <= buf.len() => Ok(n),
+            (r, n) => {
+                error!(target: log_target!(), "Underlying upcall to BIO_read_ex returned {r:} after {n:} bytes");
+                Err(anyhow::anyhow!("BIO_read_ex() upcall failed"))
+            }
+        }
+    }
+
+    /// Writes `data` to the wrapped BIO through
+    /// [`CoreUpcaller::BIO_write_ex`], returning the number of bytes
+    /// written.
+    pub fn write(&mut self, data: &[u8]) -> Result<usize, crate::OurError> {
+        if self.bio.is_null() {
+            return Err(anyhow::anyhow!("Cannot write to a NULL BIO"));
+        }
+        self.upcaller.BIO_write_ex(self.bio, data)
+    }
+}
+
+/// Reads through [`CoreBio::read`], i.e. with a single `BIO_read_ex()`
+/// upcall for each call.
+impl<U: CoreUpcaller + ?Sized> std::io::Read for CoreBio<'_, U> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        CoreBio::read(self, buf).map_err(std::io::Error::other)
+    }
+}
+
+/// Writes through [`CoreBio::write`].
+///
+/// Flushing is a no-op, as there is no core upcall for it.
+impl<U: CoreUpcaller + ?Sized> std::io::Write for CoreBio<'_, U> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        CoreBio::write(self, buf).map_err(std::io::Error::other)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<U: CoreUpcaller + ?Sized> Drop for CoreBio<'_, U> {
+    #[named]
+    fn drop(&mut self) {
+        if self.bio.is_null() {
+            return;
+        }
+        if let Err(e) = self.upcaller.BIO_free(self.bio) {
+            error!(target: log_target!(), "Failed to free the wrapped BIO: {e:?}");
+        }
+    }
+}
+
+impl<U: CoreUpcaller + ?Sized> std::fmt::Debug for CoreBio<'_, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoreBio").field("bio", &self.bio).finish()
+    }
+}
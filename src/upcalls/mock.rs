@@ -0,0 +1,321 @@
+//! A mock of the OpenSSL core, so that upcalls can be unit-tested without
+//! being loaded as a provider by `libcrypto`.
+//!
+//! The mock exposes a fake [`OSSL_CORE_HANDLE`] and a `core_dispatch` table
+//! whose entries record the arguments they are called with, and return a
+//! configurable value.
+//!
+//! > ⚠️ The upcall methods in [`traits`] cache the resolved function pointers
+//! > in `static` cells, which are shared by **all** implementors.
+//! > For this reason every test must use the same table, as returned by
+//! > [`mock_core_dispatch_with_handle()`], rather than building its own.
+//!
+//! Recorded calls and configured return values are stored in thread-local
+//! storage, so tests running in parallel do not interfere with each other.
+
+use super::*;
+use crate::bindings::{
+    OSSL_CORE_BIO, OSSL_FUNC_BIO_FREE, OSSL_FUNC_BIO_GETS, OSSL_FUNC_BIO_READ_EX,
+    OSSL_FUNC_BIO_WRITE_EX, OSSL_FUNC_CORE_NEW_ERROR, OSSL_FUNC_CORE_OBJ_ADD_SIGID,
+    OSSL_FUNC_CORE_OBJ_CREATE, OSSL_FUNC_CORE_SET_ERROR_DEBUG, OSSL_FUNC_CORE_VSET_ERROR,
+};
+use std::cell::{Cell, RefCell};
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::sync::OnceLock;
+
+/// The fake core handle: it only exists so that we have a stable, non-NULL
+/// address to hand out as `*const OSSL_CORE_HANDLE`.
+struct MockCoreHandle {
+    _tag: u64,
+}
+
+static MOCK_CORE_HANDLE: MockCoreHandle = MockCoreHandle { _tag: 0x0b5e_55ed };
+
+/// Returns the address of the fake [`OSSL_CORE_HANDLE`].
+pub(crate) fn mock_core_handle() -> *const OSSL_CORE_HANDLE {
+    std::ptr::from_ref(&MOCK_CORE_HANDLE).cast()
+}
+
+/// A call received by one of the mocked core functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum MockCall {
+    ObjCreate {
+        handle: *const OSSL_CORE_HANDLE,
+        oid: CString,
+        sn: CString,
+        ln: CString,
+    },
+    ObjAddSigid {
+        handle: *const OSSL_CORE_HANDLE,
+        sign_name: CString,
+        digest_name: Option<CString>,
+        pkey_name: CString,
+    },
+    BioFree {
+        bio: *mut OSSL_CORE_BIO,
+    },
+    NewError {
+        handle: *const OSSL_CORE_HANDLE,
+    },
+    SetErrorDebug {
+        handle: *const OSSL_CORE_HANDLE,
+        file: CString,
+        line: c_int,
+        func: CString,
+    },
+    /// The `va_list` of arguments is not recorded, as it is never read.
+    SetError {
+        handle: *const OSSL_CORE_HANDLE,
+        reason: u32,
+        fmt: CString,
+    },
+}
+
+thread_local! {
+    static CALLS: RefCell<Vec<MockCall>> = const { RefCell::new(Vec::new()) };
+    static RETURN_VALUE: Cell<c_int> = const { Cell::new(1) };
+}
+
+/// Returns (and clears) the calls recorded so far on the current thread.
+pub(crate) fn take_calls() -> Vec<MockCall> {
+    CALLS.with(|c| c.take())
+}
+
+/// Sets the value returned by the mocked core functions on the current thread.
+pub(crate) fn set_return_value(ret: c_int) {
+    RETURN_VALUE.with(|r| r.set(ret));
+}
+
+fn record(call: MockCall) -> c_int {
+    trace!("Recording {call:?}");
+    CALLS.with(|c| c.borrow_mut().push(call));
+    RETURN_VALUE.with(|r| r.get())
+}
+
+/// # Safety
+///
+/// `ptr` must be NULL or point to a valid NUL-terminated string.
+unsafe fn to_cstring(ptr: *const c_char) -> Option<CString> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(ptr) }.to_owned())
+    }
+}
+
+unsafe extern "C" fn mock_core_obj_create(
+    prov: *const OSSL_CORE_HANDLE,
+    oid: *const c_char,
+    sn: *const c_char,
+    ln: *const c_char,
+) -> c_int {
+    let (oid, sn, ln) = unsafe { (to_cstring(oid), to_cstring(sn), to_cstring(ln)) };
+    record(MockCall::ObjCreate {
+        handle: prov,
+        oid: oid.expect("oid must not be NULL"),
+        sn: sn.expect("sn must not be NULL"),
+        ln: ln.expect("ln must not be NULL"),
+    })
+}
+
+unsafe extern "C" fn mock_core_obj_add_sigid(
+    prov: *const OSSL_CORE_HANDLE,
+    sign_name: *const c_char,
+    digest_name: *const c_char,
+    pkey_name: *const c_char,
+) -> c_int {
+    let (sign_name, digest_name, pkey_name) = unsafe {
+        (
+            to_cstring(sign_name),
+            to_cstring(digest_name),
+            to_cstring(pkey_name),
+        )
+    };
+    record(MockCall::ObjAddSigid {
+        handle: prov,
+        sign_name: sign_name.expect("sign_name must not be NULL"),
+        digest_name,
+        pkey_name: pkey_name.expect("pkey_name must not be NULL"),
+    })
+}
+
+unsafe extern "C" fn mock_bio_free(bio: *mut OSSL_CORE_BIO) -> c_int {
+    record(MockCall::BioFree { bio })
+}
+
+unsafe extern "C" fn mock_core_new_error(prov: *const OSSL_CORE_HANDLE) {
+    record(MockCall::NewError { handle: prov });
+}
+
+unsafe extern "C" fn mock_core_set_error_debug(
+    prov: *const OSSL_CORE_HANDLE,
+    file: *const c_char,
+    line: c_int,
+    func: *const c_char,
+) {
+    let (file, func) = unsafe { (to_cstring(file), to_cstring(func)) };
+    record(MockCall::SetErrorDebug {
+        handle: prov,
+        file: file.expect("file must not be NULL"),
+        line,
+        func: func.expect("func must not be NULL"),
+    });
+}
+
+unsafe extern "C" fn mock_core_vset_error(
+    prov: *const OSSL_CORE_HANDLE,
+    reason: u32,
+    fmt: *const c_char,
+    _args: *mut c_void,
+) {
+    let fmt = unsafe { to_cstring(fmt) };
+    record(MockCall::SetError {
+        handle: prov,
+        reason,
+        fmt: fmt.expect("fmt must not be NULL"),
+    });
+}
+
+/// An in-memory BIO, to be handed to the mocked `BIO_read_ex()` and
+/// `BIO_write_ex()` upcalls through [`MockCoreBio::as_ptr()`].
+///
+/// Writes append to the store, while reads consume it from the beginning.
+/// Each upcall transfers at most `max_chunk` bytes, to exercise the handling
+/// of partial reads and writes.
+///
+/// Unlike the other mocked functions, reads (including `BIO_gets()`) and
+/// writes are not recorded, as the store itself can be inspected.
+#[derive(Debug)]
+pub(crate) struct MockCoreBio {
+    pub(crate) store: Vec<u8>,
+    read_pos: usize,
+    max_chunk: usize,
+}
+
+impl MockCoreBio {
+    /// Creates an empty BIO, transferring at most `max_chunk` bytes per upcall.
+    pub(crate) fn new(max_chunk: usize) -> Self {
+        Self {
+            store: Vec::new(),
+            read_pos: 0,
+            max_chunk,
+        }
+    }
+
+    /// Returns the pointer to pass to the upcalls.
+    ///
+    /// It must not outlive `self`, nor be used while `self` is borrowed.
+    pub(crate) fn as_ptr(&mut self) -> *mut OSSL_CORE_BIO {
+        std::ptr::from_mut(self).cast()
+    }
+}
+
+unsafe extern "C" fn mock_bio_read_ex(
+    bio: *mut OSSL_CORE_BIO,
+    data: *mut c_void,
+    data_len: usize,
+    bytes_read: *mut usize,
+) -> c_int {
+    let bio = unsafe { &mut *bio.cast::<MockCoreBio>() };
+    let available = &bio.store[bio.read_pos..];
+    let n = available.len().min(data_len).min(bio.max_chunk);
+    unsafe {
+        std::ptr::copy_nonoverlapping(available.as_ptr(), data.cast(), n);
+        *bytes_read = n;
+    }
+    bio.read_pos += n;
+    // like BIO_read_ex(), fail when there is nothing to read
+    c_int::from(n > 0)
+}
+
+unsafe extern "C" fn mock_bio_write_ex(
+    bio: *mut OSSL_CORE_BIO,
+    data: *const c_void,
+    data_len: usize,
+    written: *mut usize,
+) -> c_int {
+    let bio = unsafe { &mut *bio.cast::<MockCoreBio>() };
+    let n = data_len.min(bio.max_chunk);
+    let data = unsafe { std::slice::from_raw_parts(data.cast::<u8>(), n) };
+    bio.store.extend_from_slice(data);
+    unsafe { *written = n };
+    c_int::from(n > 0)
+}
+
+unsafe extern "C" fn mock_bio_gets(
+    bio: *mut OSSL_CORE_BIO,
+    buf: *mut c_char,
+    size: c_int,
+) -> c_int {
+    let bio = unsafe { &mut *bio.cast::<MockCoreBio>() };
+    let Some(max) = usize::try_from(size).ok().and_then(|s| s.checked_sub(1)) else {
+        return -1;
+    };
+    let available = &bio.store[bio.read_pos..];
+    let mut n = available.len().min(max);
+    if let Some(newline) = available[..n].iter().position(|&b| b == b'\n') {
+        n = newline + 1;
+    }
+    unsafe {
+        std::ptr::copy_nonoverlapping(available.as_ptr(), buf.cast(), n);
+        *buf.add(n) = 0;
+    }
+    bio.read_pos += n;
+    // like BIO_gets() on a memory BIO, return 0 at EOF
+    n as c_int
+}
+
+/// Returns the (END-terminated) mocked `core_dispatch` table.
+pub(crate) fn mock_core_dispatch_table() -> &'static [OSSL_DISPATCH] {
+    static TABLE: OnceLock<Vec<OSSL_DISPATCH>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        vec![
+            OSSL_DISPATCH::new(OSSL_FUNC_CORE_OBJ_CREATE as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(
+                    mock_core_obj_create
+                ))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_CORE_OBJ_ADD_SIGID as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(
+                    mock_core_obj_add_sigid
+                ))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_CORE_NEW_ERROR as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(
+                    mock_core_new_error
+                ))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_CORE_SET_ERROR_DEBUG as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(
+                    mock_core_set_error_debug
+                ))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_CORE_VSET_ERROR as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(
+                    mock_core_vset_error
+                ))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_BIO_FREE as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(mock_bio_free))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_BIO_READ_EX as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(mock_bio_read_ex))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_BIO_WRITE_EX as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(mock_bio_write_ex))
+            }),
+            OSSL_DISPATCH::new(OSSL_FUNC_BIO_GETS as c_int, unsafe {
+                Some(crate::bindings::generic_non_null_fn_ptr!(mock_bio_gets))
+            }),
+            OSSL_DISPATCH::END,
+        ]
+    })
+}
+
+/// Returns a [`CoreDispatchWithCoreHandle`] wired to the mocked
+/// `core_dispatch` table and the fake [`OSSL_CORE_HANDLE`].
+pub(crate) fn mock_core_dispatch_with_handle() -> CoreDispatchWithCoreHandle<'static> {
+    let core_dispatch = CoreDispatch::try_from(mock_core_dispatch_table().as_ptr())
+        .expect("the mocked core_dispatch table should be valid");
+    CoreDispatchWithCoreHandle::from((core_dispatch, mock_core_handle()))
+}
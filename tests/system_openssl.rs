@@ -0,0 +1,125 @@
+//! Interop tests against the system OpenSSL (`libcrypto`), checking that the
+//! params built by this crate are read back correctly by OpenSSL's own
+//! `OSSL_PARAM_*` functions, and vice versa, i.e. that the FFI layout of
+//! [`OSSL_PARAM`] matches the one of the real library.
+//!
+//! These are only built with the `system-openssl` feature:
+//!
+//! ```sh
+//! cargo test --features system-openssl --test system_openssl
+//! ```
+#![cfg(feature = "system-openssl")]
+
+use openssl_provider_forge::osslparams::*;
+use std::ffi::{c_char, c_int, CStr};
+
+#[link(name = "crypto")]
+extern "C" {
+    fn OSSL_PARAM_locate_const(p: *const OSSL_PARAM, key: *const c_char) -> *const OSSL_PARAM;
+    fn OSSL_PARAM_get_int(p: *const OSSL_PARAM, val: *mut c_int) -> c_int;
+    fn OSSL_PARAM_get_uint64(p: *const OSSL_PARAM, val: *mut u64) -> c_int;
+    fn OSSL_PARAM_get_utf8_string_ptr(p: *const OSSL_PARAM, val: *mut *const c_char) -> c_int;
+    fn OSSL_PARAM_set_int(p: *mut OSSL_PARAM, val: c_int) -> c_int;
+    fn OSSL_PARAM_set_uint64(p: *mut OSSL_PARAM, val: u64) -> c_int;
+    fn OSSL_PARAM_set_utf8_string(p: *mut OSSL_PARAM, val: *const c_char) -> c_int;
+}
+
+/// Returns the param with the given `key`, as located by OpenSSL.
+fn locate(params: &[CONST_OSSL_PARAM], key: &CStr) -> *const OSSL_PARAM {
+    let p = unsafe { OSSL_PARAM_locate_const(params.as_ptr().cast(), key.as_ptr()) };
+    assert!(!p.is_null(), "OpenSSL could not locate {key:?}");
+    p
+}
+
+#[test]
+fn test_const_params_read_by_openssl() {
+    static BITS: i32 = -2048;
+    static SECURITY_BITS: u64 = u64::MAX;
+    let params = [
+        OSSLParam::new_const_int(c"bits", Some(&BITS)),
+        OSSLParam::new_const_uint(c"security-bits", Some(&SECURITY_BITS)),
+        OSSLParam::new_const_utf8string(c"group", Some(c"x25519")),
+        CONST_OSSL_PARAM::END,
+    ];
+
+    let mut bits: c_int = 0;
+    assert_eq!(
+        unsafe { OSSL_PARAM_get_int(locate(&params, c"bits"), &mut bits) },
+        1
+    );
+    assert_eq!(bits, BITS);
+
+    let mut security_bits = 0u64;
+    assert_eq!(
+        unsafe { OSSL_PARAM_get_uint64(locate(&params, c"security-bits"), &mut security_bits) },
+        1
+    );
+    assert_eq!(security_bits, SECURITY_BITS);
+
+    let mut group: *const c_char = std::ptr::null();
+    assert_eq!(
+        unsafe { OSSL_PARAM_get_utf8_string_ptr(locate(&params, c"group"), &mut group) },
+        1
+    );
+    assert_eq!(unsafe { CStr::from_ptr(group) }, c"x25519");
+
+    // and the END item terminates the list for OpenSSL as well
+    let missing = unsafe { OSSL_PARAM_locate_const(params.as_ptr().cast(), c"missing".as_ptr()) };
+    assert!(missing.is_null());
+}
+
+#[test]
+fn test_params_set_by_openssl() {
+    let mut builder = OSSLParamBuilder::new();
+    builder.push_int(c"bits", 0).push_uint(c"security-bits", 0);
+    let mut list = builder.build();
+    let mut bits = 0i64;
+    let mut name = [0u8; 16];
+    let mut raw = [
+        OSSL_PARAM {
+            key: c"name".as_ptr(),
+            data_type: OSSL_PARAM_UTF8_STRING,
+            data: name.as_mut_ptr().cast(),
+            data_size: name.len(),
+            return_size: OSSL_PARAM_UNMODIFIED,
+        },
+        OSSL_PARAM_END,
+    ];
+
+    let head = list.as_mut_ptr();
+    let mut p = ossl_param_locate(head, c"bits").unwrap();
+    assert_eq!(
+        unsafe { OSSL_PARAM_set_int(p.get_c_struct_mut(), -2048) },
+        1
+    );
+    let mut p = ossl_param_locate(head, c"security-bits").unwrap();
+    assert_eq!(
+        unsafe { OSSL_PARAM_set_uint64(p.get_c_struct_mut(), 128) },
+        1
+    );
+
+    assert_eq!(
+        ossl_param_locate(head, c"bits").unwrap().get::<i64>(),
+        Some(-2048)
+    );
+    assert_eq!(
+        ossl_param_locate(head, c"security-bits")
+            .unwrap()
+            .get::<u64>(),
+        Some(128)
+    );
+
+    let mut p = OSSLParam::with_int(c"bits", &mut bits);
+    assert_eq!(unsafe { OSSL_PARAM_set_int(p.get_c_struct_mut(), 4096) }, 1);
+    assert!(p.modified());
+    drop(p);
+    assert_eq!(bits, 4096);
+
+    assert_eq!(
+        unsafe { OSSL_PARAM_set_utf8_string(raw.as_mut_ptr(), c"x25519".as_ptr()) },
+        1
+    );
+    let p = ossl_param_locate(raw.as_mut_ptr(), c"name").unwrap();
+    assert_eq!(p.get::<&CStr>(), Some(c"x25519"));
+    assert_eq!(p.size_info().unwrap().returned, 6);
+}